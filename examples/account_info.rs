@@ -11,9 +11,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv::dotenv().ok();
 
     // Get credentials from environment variables
-    let api_key = env::var("ORDERLY_API_KEY").expect("ORDERLY_API_KEY must be set");
-    let secret = env::var("ORDERLY_SECRET").expect("ORDERLY_SECRET must be set");
-    let account_id = env::var("ORDERLY_ACCOUNT_ID").expect("ORDERLY_ACCOUNT_ID must be set");
+    let owned_creds = Credentials::from_env()?;
     let is_testnet = env::var("ORDERLY_TESTNET")
         .unwrap_or_else(|_| "true".to_string())
         .parse()
@@ -25,11 +23,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let client = OrderlyService::new(is_testnet, None).expect("Failed to create REST client");
 
     // Create credentials
-    let creds = Credentials {
-        orderly_key: Box::leak(api_key.into_boxed_str()),
-        orderly_secret: Box::leak(secret.into_boxed_str()),
-        orderly_account_id: Box::leak(account_id.into_boxed_str()),
-    };
+    let creds = owned_creds.as_credentials();
 
     // Get account information
     println!("\nFetching account information...");
@@ -1,6 +1,9 @@
 use orderly_connector_rs::{
     error::OrderlyError,
-    rest::{client::Credentials, OrderlyService},
+    rest::{
+        client::{Credentials, OwnedCredentials},
+        OrderlyService,
+    },
     types::{AlgoOrderType, CreateAlgoOrderRequest, Side},
 };
 use std::env;
@@ -18,32 +21,16 @@ fn setup_logging() {
 }
 
 /// Loads environment variables and creates credentials
-fn load_credentials() -> Result<(Credentials<'static>, bool), OrderlyError> {
+fn load_credentials() -> Result<(OwnedCredentials, bool), OrderlyError> {
     dotenv::dotenv().ok();
 
-    let api_key = env::var("ORDERLY_API_KEY")
-        .map_err(|_| OrderlyError::ValidationError("ORDERLY_API_KEY not set".into()))?;
-    let secret = env::var("ORDERLY_SECRET")
-        .map_err(|_| OrderlyError::ValidationError("ORDERLY_SECRET not set".into()))?;
-    let account_id = env::var("ORDERLY_ACCOUNT_ID")
-        .map_err(|_| OrderlyError::ValidationError("ORDERLY_ACCOUNT_ID not set".into()))?;
-
-    // Convert environment variables to 'static strings
-    let api_key = Box::leak(api_key.into_boxed_str());
-    let secret = Box::leak(secret.into_boxed_str());
-    let account_id = Box::leak(account_id.into_boxed_str());
+    let creds = Credentials::from_env()?;
 
     let is_testnet: bool = env::var("ORDERLY_TESTNET")
         .unwrap_or_else(|_| "true".to_string())
         .parse()
         .map_err(|_| OrderlyError::ValidationError("Invalid ORDERLY_TESTNET value".into()))?;
 
-    let creds = Credentials {
-        orderly_key: api_key,
-        orderly_secret: secret,
-        orderly_account_id: account_id,
-    };
-
     Ok((creds, is_testnet))
 }
 
@@ -68,6 +55,8 @@ async fn place_stop_loss(
         trigger_price,
         limit_price: None,
         trailing_delta: None,
+        trailing_type: None,
+        activation_price: None,
         client_order_id: Some(format!(
             "stop_loss_{}",
             chrono::Utc::now().timestamp_millis()
@@ -118,6 +107,8 @@ async fn place_take_profit(
         trigger_price,
         limit_price: Some(limit_price),
         trailing_delta: None,
+        trailing_type: None,
+        activation_price: None,
         client_order_id: Some(format!(
             "take_profit_{}",
             chrono::Utc::now().timestamp_millis()
@@ -167,6 +158,8 @@ async fn place_trailing_stop(
         trigger_price: 0.0, // Not used for trailing stops
         limit_price: None,
         trailing_delta: Some(trailing_delta),
+        trailing_type: None,
+        activation_price: None,
         client_order_id: Some(format!(
             "trailing_stop_{}",
             chrono::Utc::now().timestamp_millis()
@@ -229,7 +222,8 @@ async fn main() -> Result<(), OrderlyError> {
     info!("Starting advanced orders example");
 
     // Load credentials and create client
-    let (creds, is_testnet) = load_credentials()?;
+    let (owned_creds, is_testnet) = load_credentials()?;
+    let creds = owned_creds.as_credentials();
     info!("Using testnet: {}", is_testnet);
 
     let client = OrderlyService::new(is_testnet, None)
@@ -1,5 +1,8 @@
 use orderly_connector_rs::{
-    rest::{client::Credentials, OrderlyService},
+    rest::{
+        client::{Credentials, OwnedCredentials},
+        OrderlyService,
+    },
     types::{CreateOrderRequest, OrderType, Side},
 };
 use std::env;
@@ -18,28 +21,15 @@ fn setup_logging() {
 }
 
 /// Loads environment variables and creates credentials
-fn load_credentials() -> Result<(Credentials<'static>, bool), Box<dyn std::error::Error>> {
+fn load_credentials() -> Result<(OwnedCredentials, bool), Box<dyn std::error::Error>> {
     dotenv::dotenv().ok();
 
-    let api_key = env::var("ORDERLY_API_KEY")?;
-    let secret = env::var("ORDERLY_SECRET")?;
-    let account_id = env::var("ORDERLY_ACCOUNT_ID")?;
-
-    // Convert environment variables to 'static strings
-    let api_key = Box::leak(api_key.into_boxed_str());
-    let secret = Box::leak(secret.into_boxed_str());
-    let account_id = Box::leak(account_id.into_boxed_str());
+    let creds = Credentials::from_env()?;
 
     let is_testnet: bool = env::var("ORDERLY_TESTNET")
         .unwrap_or_else(|_| "true".to_string())
         .parse()?;
 
-    let creds = Credentials {
-        orderly_key: api_key,
-        orderly_secret: secret,
-        orderly_account_id: account_id,
-    };
-
     Ok((creds, is_testnet))
 }
 
@@ -50,7 +40,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Starting cancel order example");
 
     // Load credentials and create client
-    let (creds, is_testnet) = load_credentials()?;
+    let (owned_creds, is_testnet) = load_credentials()?;
+    let creds = owned_creds.as_credentials();
     info!("Using testnet: {}", is_testnet);
 
     let client = OrderlyService::new(is_testnet, None)?;
@@ -77,6 +68,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         visible_quantity: None,
         client_order_id: Some("my_order_id".to_string()),
         reduce_only: None,
+        time_in_force: None,
+        expire_time: None,
     };
 
     // Place the order
@@ -57,8 +57,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     });
 
     // Create close handler
-    let close_handler = Arc::new(|| {
-        println!("Connection closed");
+    let close_handler = Arc::new(|reason| {
+        println!("Connection closed: {:?}", reason);
     });
 
     println!("Connecting to WebSocket...");
@@ -100,7 +100,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     match tokio::signal::ctrl_c().await {
         Ok(_) => {
             println!("Received Ctrl+C. Stopping...");
-            client.stop().await;
+            client.stop().await?;
             Ok(())
         }
         Err(e) => {
@@ -43,6 +43,8 @@ async fn main() {
         client_order_id: Some("test_order_001".to_string()),
         visible_quantity: None,
         reduce_only: None, // Not a reduce-only order
+        time_in_force: None,
+        expire_time: None,
     };
 
     let creds = Credentials {
@@ -1,6 +1,9 @@
 use orderly_connector_rs::{
     error::OrderlyError,
-    rest::{client::Credentials, OrderlyService},
+    rest::{
+        client::{Credentials, OwnedCredentials},
+        OrderlyService,
+    },
     types::{
         AlgoOrderType, CreateAlgoOrderRequest, CreateOrderRequest, OrderStatus, OrderType, Side,
     },
@@ -21,31 +24,16 @@ fn setup_logging() {
 }
 
 /// Loads environment variables and creates credentials
-fn load_credentials() -> Result<(Credentials<'static>, bool), OrderlyError> {
+fn load_credentials() -> Result<(OwnedCredentials, bool), OrderlyError> {
     dotenv::dotenv().ok();
 
-    let api_key = env::var("ORDERLY_API_KEY")
-        .map_err(|_| OrderlyError::ValidationError("ORDERLY_API_KEY not set".into()))?;
-    let secret = env::var("ORDERLY_SECRET")
-        .map_err(|_| OrderlyError::ValidationError("ORDERLY_SECRET not set".into()))?;
-    let account_id = env::var("ORDERLY_ACCOUNT_ID")
-        .map_err(|_| OrderlyError::ValidationError("ORDERLY_ACCOUNT_ID not set".into()))?;
-
-    let api_key = Box::leak(api_key.into_boxed_str());
-    let secret = Box::leak(secret.into_boxed_str());
-    let account_id = Box::leak(account_id.into_boxed_str());
+    let creds = Credentials::from_env()?;
 
     let is_testnet: bool = env::var("ORDERLY_TESTNET")
         .unwrap_or_else(|_| "true".to_string())
         .parse()
         .map_err(|_| OrderlyError::ValidationError("Invalid ORDERLY_TESTNET value".into()))?;
 
-    let creds = Credentials {
-        orderly_key: api_key,
-        orderly_secret: secret,
-        orderly_account_id: account_id,
-    };
-
     Ok((creds, is_testnet))
 }
 
@@ -77,6 +65,8 @@ async fn place_take_profit_order(
         trigger_price,
         limit_price,
         trailing_delta: None,
+        trailing_type: None,
+        activation_price: None,
         client_order_id: Some("tp_order".to_string()),
         reduce_only: Some(true),
     };
@@ -130,6 +120,8 @@ async fn place_stop_loss_order(
         trigger_price,
         limit_price,
         trailing_delta: None,
+        trailing_type: None,
+        activation_price: None,
         client_order_id: Some("sl_order".to_string()),
         reduce_only: Some(true),
     };
@@ -163,7 +155,7 @@ async fn get_position(
 ) -> Result<f64, OrderlyError> {
     match client.get_positions(creds).await {
         Ok(resp) => {
-            if let Some(position) = resp.data.rows.iter().find(|p| p.symbol == symbol) {
+            if let Some(position) = resp.data.by_symbol(symbol) {
                 info!(
                     "Current position for {}: {} (PnL: {})",
                     symbol, position.position_qty, position.unsettled_pnl
@@ -301,7 +293,8 @@ async fn main() -> Result<(), OrderlyError> {
     info!("Starting TP/SL orders example");
 
     // Load credentials and create client
-    let (creds, is_testnet) = load_credentials()?;
+    let (owned_creds, is_testnet) = load_credentials()?;
+    let creds = owned_creds.as_credentials();
     info!("Using testnet: {}", is_testnet);
 
     let client = OrderlyService::new(is_testnet, None)
@@ -332,6 +325,8 @@ async fn main() -> Result<(), OrderlyError> {
             client_order_id: Some("market_entry_order".to_string()),
             visible_quantity: None,
             reduce_only: None, // Not a reduce-only order for opening position
+            time_in_force: None,
+            expire_time: None,
         };
 
         let entry_order_id = client
@@ -1,5 +1,5 @@
 // examples/ws_public.rs
-use orderly_connector_rs::websocket::WebsocketPublicClient;
+use orderly_connector_rs::websocket::{CloseReason, WebsocketPublicClient};
 use std::env;
 use std::sync::Arc;
 use tokio::time::{sleep, Duration};
@@ -10,8 +10,8 @@ fn message_handler(message: String) {
 }
 
 // Simple close handler
-fn close_handler() {
-    println!("Connection Closed.");
+fn close_handler(reason: CloseReason) {
+    println!("Connection Closed: {:?}", reason);
 }
 
 #[tokio::main]
@@ -84,7 +84,9 @@ async fn main() {
 
     // Stop the client
     println!("Stopping client...");
-    client.stop().await;
+    if let Err(e) = client.stop().await {
+        eprintln!("Failed to stop client cleanly: {}", e);
+    }
 
     println!("Example finished.");
 }
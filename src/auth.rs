@@ -1,8 +1,73 @@
 use crate::error::{OrderlyError, Result};
 use base64::Engine;
 use chrono::Utc;
-use ed25519_dalek::SecretKey;
-use ed25519_dalek::{Keypair, Signer};
+use ed25519_dalek::{Keypair, PublicKey, SecretKey, Signer};
+use reqwest::header::HeaderName;
+
+/// Selects which kind of secret is used to sign private REST requests.
+///
+/// Orderly request signatures are always Ed25519 over
+/// `timestamp + method + path + body`; the schemes differ in the key material
+/// they accept and in where the `orderly-key` header comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SigningScheme {
+    /// An Orderly-generated API secret: a 32-byte base58 seed, optionally
+    /// prefixed with `ed25519:`. The `orderly-key` header is taken from the credentials.
+    #[default]
+    OrderlyKey,
+    /// A Solana wallet keypair: the 64-byte base58 secret exported by Solana wallets
+    /// and the Solana CLI. The `orderly-key` header is derived from its public key.
+    SolanaEd25519,
+}
+
+/// Names of the authentication headers attached to signed REST requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthHeaderNames {
+    /// Request timestamp in milliseconds
+    pub timestamp: HeaderName,
+    /// Public key of the signing key pair
+    pub key: HeaderName,
+    /// Base64 Ed25519 signature
+    pub signature: HeaderName,
+    /// Orderly account ID
+    pub account_id: HeaderName,
+    /// Optional validity window in milliseconds
+    pub recv_window: HeaderName,
+}
+
+/// Selects the authentication header set emitted for signed REST requests.
+///
+/// Only the header names vary; the signed message is always
+/// `timestamp + method + path + body`. Use [`AuthVersion::Custom`] to target a gateway
+/// that expects renamed headers without waiting for a new release.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum AuthVersion {
+    /// `orderly-timestamp`, `orderly-key`, `orderly-signature`, `orderly-account-id` and
+    /// `orderly-recv-window`, as accepted by the current mainnet and testnet gateways.
+    #[default]
+    V1,
+    /// Caller-supplied header names.
+    Custom(AuthHeaderNames),
+}
+
+impl AuthVersion {
+    /// Returns the header names emitted for this version.
+    pub fn header_names(&self) -> AuthHeaderNames {
+        match self {
+            AuthVersion::V1 => AuthHeaderNames {
+                timestamp: HeaderName::from_static("orderly-timestamp"),
+                key: HeaderName::from_static("orderly-key"),
+                signature: HeaderName::from_static("orderly-signature"),
+                account_id: HeaderName::from_static("orderly-account-id"),
+                recv_window: HeaderName::from_static("orderly-recv-window"),
+            },
+            AuthVersion::Custom(names) => names.clone(),
+        }
+    }
+}
+
+/// Placeholder printed instead of keys and secrets in `Debug` output.
+pub const REDACTED: &str = "***";
 
 /// Gets the current UTC timestamp in milliseconds since the Unix epoch.
 ///
@@ -70,6 +135,46 @@ fn parse_secret_key(secret_key_str: &str) -> Result<[u8; 32]> {
     Ok(key_bytes)
 }
 
+/// Parses a 64-byte base58 Solana keypair secret, checking that its public half
+/// matches the secret half.
+fn parse_solana_keypair(secret_key_str: &str) -> Result<Keypair> {
+    let decoded_bytes = bs58::decode(secret_key_str).into_vec().map_err(|e| {
+        OrderlyError::AuthenticationError(format!("Failed to decode base58 keypair: {}", e))
+    })?;
+
+    if decoded_bytes.len() != 64 {
+        return Err(OrderlyError::AuthenticationError(format!(
+            "Invalid Solana keypair length: Expected 64 bytes, got {}",
+            decoded_bytes.len()
+        )));
+    }
+
+    let keypair = Keypair::from_bytes(&decoded_bytes)?;
+    let derived: PublicKey = (&keypair.secret).into();
+    if derived != keypair.public {
+        return Err(OrderlyError::AuthenticationError(
+            "Solana keypair public key does not match its secret key".to_string(),
+        ));
+    }
+    Ok(keypair)
+}
+
+/// Builds the signing keypair for the given scheme.
+fn keypair_for_scheme(scheme: SigningScheme, secret: &str) -> Result<Keypair> {
+    match scheme {
+        SigningScheme::OrderlyKey => {
+            let key_bytes = parse_secret_key(secret)?;
+            let secret_key = SecretKey::from_bytes(&key_bytes)?;
+            let public_key = (&secret_key).into();
+            Ok(Keypair {
+                secret: secret_key,
+                public: public_key,
+            })
+        }
+        SigningScheme::SolanaEd25519 => parse_solana_keypair(secret),
+    }
+}
+
 /// Generates an Ed25519 signature for a given message using the Orderly secret key.
 ///
 /// This function is used to sign API requests for authentication.
@@ -94,17 +199,40 @@ fn parse_secret_key(secret_key_str: &str) -> Result<[u8; 32]> {
 /// println!("Signature: {}", signature);
 /// ```
 pub fn generate_signature(orderly_secret: &str, message: &str) -> Result<String> {
-    let key_bytes = parse_secret_key(orderly_secret)?;
-    let secret_key = SecretKey::from_bytes(&key_bytes)?;
-    let public_key = (&secret_key).into();
-    let keypair = Keypair {
-        secret: secret_key,
-        public: public_key,
-    };
+    generate_signature_with_scheme(SigningScheme::OrderlyKey, orderly_secret, message)
+}
+
+/// Generates a Base64 encoded Ed25519 signature using the secret format of `scheme`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use orderly_connector_rs::auth::{generate_signature_with_scheme, SigningScheme};
+///
+/// let keypair = "your_base58_solana_keypair";
+/// let signature =
+///     generate_signature_with_scheme(SigningScheme::SolanaEd25519, keypair, "message")
+///         .expect("Failed to generate signature");
+/// ```
+pub fn generate_signature_with_scheme(
+    scheme: SigningScheme,
+    secret: &str,
+    message: &str,
+) -> Result<String> {
+    let keypair = keypair_for_scheme(scheme, secret)?;
     let signature = keypair.sign(message.as_bytes());
     Ok(base64::engine::general_purpose::STANDARD.encode(signature.to_bytes()))
 }
 
+/// Derives the `orderly-key` header value (`ed25519:<base58 public key>`) from a secret.
+pub fn derive_orderly_key(scheme: SigningScheme, secret: &str) -> Result<String> {
+    let keypair = keypair_for_scheme(scheme, secret)?;
+    Ok(format!(
+        "ed25519:{}",
+        bs58::encode(keypair.public.as_bytes()).into_string()
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*; // Import functions from outer module
@@ -114,6 +242,17 @@ mod tests {
     const TEST_SECRET_KEY_STR: &str = "ed25519:2wyRcTuEuip6nLoNmfKvmkxMgC7zLbW8DH4PPQT5hWKd"; // Example, NOT a real secret
     const TEST_SECRET_KEY_NO_PREFIX: &str = "2wyRcTuEuip6nLoNmfKvmkxMgC7zLbW8DH4PPQT5hWKd"; // Same key without prefix
 
+    // RFC 8032 test vector 1 secret seed, and the same key as a 64-byte Solana keypair
+    const RFC8032_SEED: &str = "BbMQkQYZspmkytduTWvXEtc4mMURjsekJDvty2WtKeSb";
+    const RFC8032_SOLANA_KEYPAIR: &str =
+        "49W385L4rePHy6PAaQUovbD2aacgN4HsKXSMeUzRg4fmwXszN91JuMFrQRj3vMDpZuRF3ZknQBuRBoWQJEfXstMw";
+    const RFC8032_ORDERLY_KEY: &str = "ed25519:FVen3X669xLzsi6N2V91DoiyzHzg1uAgqiT8jZ9nS96Z";
+    const RFC8032_EMPTY_MESSAGE_SIGNATURE: &str =
+        "5VZDAMNgrHKQhuLMgG6CioSHfx645dl02HPgZSJJAVVfuIIVkKM7rMYeOXAc+bRr0lv18FlbviRlUUFDjnoQCw==";
+    const REQUEST_MESSAGE: &str = "1649920583000GET/v1/positions";
+    const REQUEST_SIGNATURE: &str =
+        "Bp2eBqbHaR+Qkbv3XYSDJQ/0fJBI/jCtKKMntgCQh5rvSQk+BWr9zjUIM5LiJJALKTa2856ipt9YA+j/4PKBCA==";
+
     #[test]
     fn test_get_timestamp_ms_works() {
         let ts = get_timestamp_ms().expect("Failed to get timestamp");
@@ -202,4 +341,47 @@ mod tests {
             "Signatures should be identical regardless of prefix"
         );
     }
+
+    #[test]
+    fn test_signature_vectors_orderly_key_scheme() {
+        let empty = generate_signature_with_scheme(SigningScheme::OrderlyKey, RFC8032_SEED, "")
+            .expect("Failed to sign empty message");
+        assert_eq!(empty, RFC8032_EMPTY_MESSAGE_SIGNATURE);
+
+        let request = generate_signature(RFC8032_SEED, REQUEST_MESSAGE)
+            .expect("Failed to sign request message");
+        assert_eq!(request, REQUEST_SIGNATURE);
+
+        let key = derive_orderly_key(SigningScheme::OrderlyKey, RFC8032_SEED).unwrap();
+        assert_eq!(key, RFC8032_ORDERLY_KEY);
+    }
+
+    #[test]
+    fn test_signature_vectors_solana_ed25519_scheme() {
+        let empty = generate_signature_with_scheme(
+            SigningScheme::SolanaEd25519,
+            RFC8032_SOLANA_KEYPAIR,
+            "",
+        )
+        .expect("Failed to sign empty message");
+        assert_eq!(empty, RFC8032_EMPTY_MESSAGE_SIGNATURE);
+
+        let request = generate_signature_with_scheme(
+            SigningScheme::SolanaEd25519,
+            RFC8032_SOLANA_KEYPAIR,
+            REQUEST_MESSAGE,
+        )
+        .expect("Failed to sign request message");
+        assert_eq!(request, REQUEST_SIGNATURE);
+
+        let key = derive_orderly_key(SigningScheme::SolanaEd25519, RFC8032_SOLANA_KEYPAIR).unwrap();
+        assert_eq!(key, RFC8032_ORDERLY_KEY);
+    }
+
+    #[test]
+    fn test_solana_scheme_rejects_seed_only_secret() {
+        let result =
+            generate_signature_with_scheme(SigningScheme::SolanaEd25519, RFC8032_SEED, "msg");
+        assert!(matches!(result, Err(OrderlyError::AuthenticationError(_))));
+    }
 }
@@ -1,6 +1,7 @@
 use reqwest::header::HeaderMap;
-use reqwest::StatusCode;
+use reqwest::{Method, StatusCode};
 use serde_json::Value;
+use std::time::Duration;
 use thiserror::Error;
 use url::ParseError;
 
@@ -34,8 +35,12 @@ pub enum OrderlyError {
     ///
     /// This includes errors like invalid parameters, authentication failures,
     /// and other client-side issues.
-    #[error("Client Error: status={status}, code={code}, message={message}, data={data:?}")]
+    #[error("Client Error: {method} {path}: status={status}, code={code}, message={message}, data={data:?}")]
     ClientError {
+        /// The HTTP method of the failed request
+        method: Method,
+        /// The URL path of the failed request, without the query string
+        path: String,
         /// The HTTP status code returned by the server
         status: StatusCode,
         /// The error code specific to the Orderly API
@@ -52,8 +57,12 @@ pub enum OrderlyError {
     ///
     /// This includes errors like internal server errors, service unavailability,
     /// and other server-side issues.
-    #[error("Server Error: status={status}, code={code}, message={message}")]
+    #[error("Server Error: {method} {path}: status={status}, code={code}, message={message}")]
     ServerError {
+        /// The HTTP method of the failed request
+        method: Method,
+        /// The URL path of the failed request, without the query string
+        path: String,
         /// The HTTP status code returned by the server
         status: StatusCode,
         /// The error code specific to the Orderly API
@@ -64,6 +73,15 @@ pub enum OrderlyError {
         header: HeaderMap,
     },
 
+    /// Represents a request rejected by the server's rate limiter (HTTP 429).
+    #[error("Rate Limited: retry_after={retry_after:?}")]
+    RateLimited {
+        /// How long to wait before retrying, parsed from the `Retry-After` header if present
+        retry_after: Option<Duration>,
+        /// The HTTP headers from the response
+        header: HeaderMap,
+    },
+
     /// Indicates that a required parameter was missing from a request.
     #[error("Parameter Required Error: Missing required parameter '{param}'")]
     ParameterRequiredError {
@@ -142,6 +160,8 @@ pub enum OrderlyError {
     ValidationError(String),
 
     /// Indicates the wallet is already registered.
+    ///
+    /// `register_solana_account` no longer returns this; it looks up the existing account ID instead.
     #[error("Wallet already registered: {0}")]
     RegistrationNotRequired(String),
 
@@ -156,6 +176,156 @@ pub enum OrderlyError {
     /// Represents errors during message signing.
     #[error("Signing error: {0}")]
     SigningError(String),
+
+    /// An operation did not complete within the allotted time.
+    #[error("Timed out: {0}")]
+    Timeout(String),
+
+    /// A mutating request was suppressed in dry-run mode and has no synthetic response.
+    #[error("Dry run: {0} was not sent")]
+    DryRun(String),
+
+    /// `replace_order` cancelled the old order, but the replacement failed without a
+    /// definitive rejection (timeout, 5xx, connection reset) and may be live.
+    ///
+    /// The old order is not restored in this case. Look the replacement up by
+    /// `client_order_id` (or list open orders) before placing anything else.
+    #[error("Replacement for order {old_order_id} has an unknown outcome (client_order_id={client_order_id:?}): {source}")]
+    ReplaceOutcomeUnknown {
+        /// ID of the cancelled order
+        old_order_id: u64,
+        /// `client_order_id` of the replacement order, if it had one
+        client_order_id: Option<String>,
+        /// Error returned while creating the replacement
+        source: Box<OrderlyError>,
+    },
+}
+
+impl OrderlyError {
+    /// Returns the typed Orderly API error code, if this error carries one.
+    ///
+    /// `RateLimited` maps to [`OrderlyApiCode::TooManyRequests`]. The raw code remains
+    /// available on the `ClientError`/`ServerError` variants.
+    pub fn api_code(&self) -> Option<OrderlyApiCode> {
+        match self {
+            OrderlyError::ClientError { code, .. } | OrderlyError::ServerError { code, .. } => {
+                Some(OrderlyApiCode::from(*code))
+            }
+            OrderlyError::RateLimited { .. } => Some(OrderlyApiCode::TooManyRequests),
+            _ => None,
+        }
+    }
+}
+
+/// Documented Orderly API error codes.
+///
+/// Codes not listed here are preserved as [`OrderlyApiCode::Unknown`].
+///
+/// # Examples
+///
+/// ```
+/// use orderly_connector_rs::error::OrderlyApiCode;
+///
+/// assert_eq!(OrderlyApiCode::from(-1006), OrderlyApiCode::ResourceNotFound);
+/// assert_eq!(OrderlyApiCode::from(-42).code(), -42);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderlyApiCode {
+    /// -1000: An unknown error occurred while processing the request
+    UnknownError,
+    /// -1001: The API key or secret is in the wrong format
+    InvalidSignature,
+    /// -1002: API key or secret is invalid, or lacks permission
+    Unauthorized,
+    /// -1003: Rate limit exceeded
+    TooManyRequests,
+    /// -1004: An unknown parameter was sent
+    UnknownParam,
+    /// -1005: Some parameters are in the wrong format
+    InvalidParam,
+    /// -1006: The data is not found in the server (e.g. order not found)
+    ResourceNotFound,
+    /// -1007: The data already exists or the request is a duplicate
+    DuplicateRequest,
+    /// -1008: The quantity to settle is higher than allowed
+    QuantityTooHigh,
+    /// -1009: Cannot request withdrawal settlement
+    CanNotWithdrawal,
+    /// -1011: Cannot place or cancel orders due to an internal network error
+    RpcNotConnect,
+    /// -1012: The place/cancel order request was rejected by the server
+    RpcReject,
+    /// -1101: Insufficient margin for the requested operation
+    RiskTooHigh,
+    /// -1102: The order value (price * size) is too small
+    MinNotional,
+    /// -1103: The order price does not satisfy the symbol's price filter
+    PriceFilter,
+    /// -1104: The order quantity does not satisfy the symbol's size filter
+    SizeFilter,
+    /// -1105: The order price is outside the allowed range from the mark price
+    PercentageFilter,
+    /// -1201: The liquidation request ratio is too small
+    LiquidationRequestRatioTooSmall,
+    /// -1202: The account does not need to be liquidated
+    NoNeedToLiquidate,
+    /// Any code not covered above, including `0` when the error body had no code
+    Unknown(i64),
+}
+
+impl OrderlyApiCode {
+    /// Returns the raw numeric code.
+    pub fn code(&self) -> i64 {
+        match self {
+            OrderlyApiCode::UnknownError => -1000,
+            OrderlyApiCode::InvalidSignature => -1001,
+            OrderlyApiCode::Unauthorized => -1002,
+            OrderlyApiCode::TooManyRequests => -1003,
+            OrderlyApiCode::UnknownParam => -1004,
+            OrderlyApiCode::InvalidParam => -1005,
+            OrderlyApiCode::ResourceNotFound => -1006,
+            OrderlyApiCode::DuplicateRequest => -1007,
+            OrderlyApiCode::QuantityTooHigh => -1008,
+            OrderlyApiCode::CanNotWithdrawal => -1009,
+            OrderlyApiCode::RpcNotConnect => -1011,
+            OrderlyApiCode::RpcReject => -1012,
+            OrderlyApiCode::RiskTooHigh => -1101,
+            OrderlyApiCode::MinNotional => -1102,
+            OrderlyApiCode::PriceFilter => -1103,
+            OrderlyApiCode::SizeFilter => -1104,
+            OrderlyApiCode::PercentageFilter => -1105,
+            OrderlyApiCode::LiquidationRequestRatioTooSmall => -1201,
+            OrderlyApiCode::NoNeedToLiquidate => -1202,
+            OrderlyApiCode::Unknown(code) => *code,
+        }
+    }
+}
+
+impl From<i64> for OrderlyApiCode {
+    fn from(code: i64) -> Self {
+        match code {
+            -1000 => OrderlyApiCode::UnknownError,
+            -1001 => OrderlyApiCode::InvalidSignature,
+            -1002 => OrderlyApiCode::Unauthorized,
+            -1003 => OrderlyApiCode::TooManyRequests,
+            -1004 => OrderlyApiCode::UnknownParam,
+            -1005 => OrderlyApiCode::InvalidParam,
+            -1006 => OrderlyApiCode::ResourceNotFound,
+            -1007 => OrderlyApiCode::DuplicateRequest,
+            -1008 => OrderlyApiCode::QuantityTooHigh,
+            -1009 => OrderlyApiCode::CanNotWithdrawal,
+            -1011 => OrderlyApiCode::RpcNotConnect,
+            -1012 => OrderlyApiCode::RpcReject,
+            -1101 => OrderlyApiCode::RiskTooHigh,
+            -1102 => OrderlyApiCode::MinNotional,
+            -1103 => OrderlyApiCode::PriceFilter,
+            -1104 => OrderlyApiCode::SizeFilter,
+            -1105 => OrderlyApiCode::PercentageFilter,
+            -1201 => OrderlyApiCode::LiquidationRequestRatioTooSmall,
+            -1202 => OrderlyApiCode::NoNeedToLiquidate,
+            other => OrderlyApiCode::Unknown(other),
+        }
+    }
 }
 
 impl From<bs58::decode::Error> for OrderlyError {
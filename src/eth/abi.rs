@@ -13,6 +13,9 @@
 //! Each message type is encoded according to specific ABI rules and then hashed using Keccak-256
 //! for signing purposes.
 //!
+//! Withdrawals from EVM chains use a typed EIP-712 message instead; see
+//! [`create_evm_withdrawal_message`] and [`EvmWithdrawalMessage::eip712_digest`].
+//!
 //! # Implementation Details
 //!
 //! The implementation follows the specifications outlined in `trade.md`:
@@ -48,6 +51,7 @@
 //! ```
 
 use crate::error::{OrderlyError, Result};
+use serde::Serialize;
 use solabi::encode::{Encode, Encoder, Size};
 use solabi::ethprim::U256;
 use solabi::keccak::v256; // Use the canonical Keccak-256 implementation
@@ -238,6 +242,188 @@ pub fn create_registration_message(
     })
 }
 
+/// EIP-712 domain name used by Orderly for off-chain messages.
+pub const ORDERLY_EIP712_NAME: &str = "Orderly";
+/// EIP-712 domain version used by Orderly for off-chain messages.
+pub const ORDERLY_EIP712_VERSION: &str = "1";
+/// Verifying contract of Orderly's off-chain EIP-712 domain on mainnet.
+pub const ORDERLY_MAINNET_VERIFYING_CONTRACT: &str = "0x6F7a338F2aA472838dEFD3283eB360d4Dff5D203";
+/// Verifying contract of Orderly's off-chain EIP-712 domain on testnet.
+pub const ORDERLY_TESTNET_VERIFYING_CONTRACT: &str = "0x1826B75e2ef249173FC735149AE4B8e9ea10abff";
+
+const EIP712_DOMAIN_TYPE: &str =
+    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+const EVM_WITHDRAW_TYPE: &str = "Withdraw(string brokerId,uint256 chainId,address receiver,string token,uint256 amount,uint64 withdrawNonce,uint64 timestamp)";
+
+/// Parses a `0x`-prefixed, 20-byte hex EVM address. Checksum casing is not enforced.
+pub fn parse_evm_address(address: &str) -> Result<[u8; 20], OrderlyError> {
+    let hex_str = address.strip_prefix("0x").ok_or_else(|| {
+        OrderlyError::ValidationError(format!("EVM address must start with 0x: {}", address))
+    })?;
+    let bytes = hex::decode(hex_str)
+        .map_err(|e| OrderlyError::ValidationError(format!("Invalid EVM address hex: {}", e)))?;
+    bytes.try_into().map_err(|_| {
+        OrderlyError::ValidationError(format!("EVM address must be 20 bytes: {}", address))
+    })
+}
+
+/// Left-pads an address into a 32-byte ABI word.
+fn address_word(address: &[u8; 20]) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address);
+    word
+}
+
+/// Represents the EIP-712 domain an EVM message is signed under.
+///
+/// # Fields
+///
+/// * `name` - Domain name, `"Orderly"` for Orderly messages
+/// * `version` - Domain version, `"1"` for Orderly messages
+/// * `chain_id` - The EVM chain ID the user signs on (e.g., 42161 for Arbitrum)
+/// * `verifying_contract` - The verifying contract address as a `0x` hex string
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eip712Domain {
+    pub name: String,
+    pub version: String,
+    pub chain_id: u64,
+    pub verifying_contract: String,
+}
+
+impl Eip712Domain {
+    /// Creates Orderly's off-chain domain for `chain_id` and `verifying_contract`.
+    pub fn orderly(chain_id: u64, verifying_contract: &str) -> Self {
+        Self {
+            name: ORDERLY_EIP712_NAME.to_string(),
+            version: ORDERLY_EIP712_VERSION.to_string(),
+            chain_id,
+            verifying_contract: verifying_contract.to_string(),
+        }
+    }
+
+    /// Computes the EIP-712 domain separator.
+    pub fn separator(&self) -> Result<[u8; 32], OrderlyError> {
+        let verifying_contract = parse_evm_address(&self.verifying_contract)?;
+        let mut encoded = Vec::with_capacity(32 * 5);
+        encoded.extend_from_slice(&v256(EIP712_DOMAIN_TYPE.as_bytes()));
+        encoded.extend_from_slice(&v256(self.name.as_bytes()));
+        encoded.extend_from_slice(&v256(self.version.as_bytes()));
+        encoded.extend_from_slice(&U256::from(self.chain_id).to_be_bytes());
+        encoded.extend_from_slice(&address_word(&verifying_contract));
+        Ok(v256(&encoded))
+    }
+}
+
+/// Represents a withdrawal message for an EVM chain, signed as EIP-712 typed data.
+///
+/// Serializes to the `message` object expected by `POST /v1/withdraw_request`.
+///
+/// # Fields
+///
+/// * `broker_id` - The broker ID string (e.g., "woofi_pro")
+/// * `chain_id` - The EVM chain ID to withdraw to
+/// * `receiver` - The receiving EVM address as a `0x` hex string
+/// * `token` - The token symbol (e.g., "USDC")
+/// * `amount` - The withdrawal amount in the token's smallest unit
+/// * `withdraw_nonce` - Nonce from `GET /v1/withdraw_nonce`
+/// * `timestamp` - Unix timestamp in milliseconds
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvmWithdrawalMessage {
+    pub broker_id: String,
+    pub chain_id: u64,
+    pub receiver: String,
+    pub token: String,
+    pub amount: u64,
+    pub withdraw_nonce: u64,
+    pub timestamp: u64,
+}
+
+impl EvmWithdrawalMessage {
+    /// Computes the EIP-712 struct hash of this message.
+    pub fn struct_hash(&self) -> Result<[u8; 32], OrderlyError> {
+        let receiver = parse_evm_address(&self.receiver)?;
+        let mut encoded = Vec::with_capacity(32 * 8);
+        encoded.extend_from_slice(&v256(EVM_WITHDRAW_TYPE.as_bytes()));
+        encoded.extend_from_slice(&v256(self.broker_id.as_bytes()));
+        encoded.extend_from_slice(&U256::from(self.chain_id).to_be_bytes());
+        encoded.extend_from_slice(&address_word(&receiver));
+        encoded.extend_from_slice(&v256(self.token.as_bytes()));
+        encoded.extend_from_slice(&U256::from(self.amount).to_be_bytes());
+        encoded.extend_from_slice(&U256::from(self.withdraw_nonce).to_be_bytes());
+        encoded.extend_from_slice(&U256::from(self.timestamp).to_be_bytes());
+        Ok(v256(&encoded))
+    }
+
+    /// Computes the digest to sign: `keccak256(0x1901 || domainSeparator || structHash)`.
+    pub fn eip712_digest(&self, domain: &Eip712Domain) -> Result<[u8; 32], OrderlyError> {
+        let mut encoded = Vec::with_capacity(2 + 32 * 2);
+        encoded.extend_from_slice(&[0x19, 0x01]);
+        encoded.extend_from_slice(&domain.separator()?);
+        encoded.extend_from_slice(&self.struct_hash()?);
+        Ok(v256(&encoded))
+    }
+}
+
+/// Creates a withdrawal message for an EVM chain.
+///
+/// # Arguments
+///
+/// * `broker_id` - The broker ID string (e.g., "woofi_pro")
+/// * `chain_id` - The EVM chain ID to withdraw to (e.g., 42161 for Arbitrum)
+/// * `receiver` - The receiving EVM address as a `0x` hex string
+/// * `token` - The token symbol (e.g., "USDC")
+/// * `amount` - The withdrawal amount in the token's smallest unit
+/// * `withdraw_nonce` - Unique nonce for the withdrawal request
+/// * `timestamp` - Unix timestamp in milliseconds
+///
+/// # Returns
+///
+/// Returns a `Result` containing the constructed `EvmWithdrawalMessage` or an `OrderlyError`
+/// if the receiver address is invalid.
+///
+/// # Examples
+///
+/// ```rust
+/// use orderly_connector_rs::eth::abi::{
+///     create_evm_withdrawal_message, Eip712Domain, ORDERLY_MAINNET_VERIFYING_CONTRACT,
+/// };
+///
+/// let message = create_evm_withdrawal_message(
+///     "woofi_pro",
+///     42161,
+///     "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+///     "USDC",
+///     100_000_000,
+///     12345,
+///     1678886400000,
+/// ).expect("Failed to create message");
+///
+/// let domain = Eip712Domain::orderly(42161, ORDERLY_MAINNET_VERIFYING_CONTRACT);
+/// let digest = message.eip712_digest(&domain).expect("Failed to hash message");
+/// ```
+pub fn create_evm_withdrawal_message(
+    broker_id: &str,
+    chain_id: u64,
+    receiver: &str,
+    token: &str,
+    amount: u64,
+    withdraw_nonce: u64,
+    timestamp: u64,
+) -> Result<EvmWithdrawalMessage, OrderlyError> {
+    parse_evm_address(receiver)?;
+
+    Ok(EvmWithdrawalMessage {
+        broker_id: broker_id.to_string(),
+        chain_id,
+        receiver: receiver.to_string(),
+        token: token.to_string(),
+        amount,
+        withdraw_nonce,
+        timestamp,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -393,4 +579,65 @@ mod tests {
             _ => panic!("Expected ValidationError"),
         }
     }
+
+    /// Tests the EIP-712 hashing of an EVM withdrawal message.
+    ///
+    /// The expected values were computed independently from the EIP-712 spec.
+    #[test]
+    fn test_evm_withdrawal_message_eip712_digest() {
+        let message = create_evm_withdrawal_message(
+            "woofi_pro",
+            42161,
+            "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+            "USDC",
+            100_000_000,
+            12345,
+            1678886400000,
+        )
+        .expect("Failed to create message");
+        let domain = Eip712Domain::orderly(42161, ORDERLY_MAINNET_VERIFYING_CONTRACT);
+
+        assert_eq!(
+            domain.separator().unwrap(),
+            hex_to_arr32("6c98191559c60eb363f98b3e97dac0f7308cdce3c9e367456bd14b013653259b")
+        );
+        assert_eq!(
+            message.struct_hash().unwrap(),
+            hex_to_arr32("634eeb9244d8f23accb5571bdd523ad98c3ec4f7b46ab7bac236b75819246812")
+        );
+        assert_eq!(
+            message.eip712_digest(&domain).unwrap(),
+            hex_to_arr32("c8d3e23580c7d1b330ab79230066f2e29947e1f4da21bf36d0e6a7e93655b576")
+        );
+    }
+
+    /// Tests that the message serializes with the field names the API expects.
+    #[test]
+    fn test_evm_withdrawal_message_serialization() {
+        let message = create_evm_withdrawal_message(
+            "woofi_pro",
+            42161,
+            "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+            "USDC",
+            1000,
+            1,
+            1678886400000,
+        )
+        .unwrap();
+
+        let value = serde_json::to_value(&message).unwrap();
+        assert_eq!(value["brokerId"], "woofi_pro");
+        assert_eq!(value["chainId"], 42161);
+        assert_eq!(value["withdrawNonce"], 1);
+    }
+
+    /// Tests error handling for invalid EVM receiver addresses.
+    #[test]
+    fn test_create_evm_withdrawal_message_invalid_receiver() {
+        for receiver in ["2c7536e3605d9c16a7a3d7b1898e529396a65c23", "0x1234", "0xzz"] {
+            let result =
+                create_evm_withdrawal_message("test_broker", 1, receiver, "USDC", 1000, 1, 1);
+            assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+        }
+    }
 }
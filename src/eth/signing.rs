@@ -0,0 +1,113 @@
+//! secp256k1 signing for EVM wallets
+//!
+//! Produces Ethereum-style `r || s || v` signatures over 32-byte digests, such as the
+//! EIP-712 digest of an [`EvmWithdrawalMessage`](crate::eth::abi::EvmWithdrawalMessage).
+
+use crate::error::{OrderlyError, Result};
+use libsecp256k1::{Message, PublicKey, SecretKey};
+use solabi::keccak::v256;
+
+/// An EVM wallet key used to sign Orderly messages.
+///
+/// # Examples
+///
+/// ```rust
+/// use orderly_connector_rs::eth::signing::EvmSigner;
+///
+/// let signer = EvmSigner::from_private_key(
+///     "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+/// ).expect("Invalid private key");
+/// assert_eq!(signer.address(), "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23");
+/// ```
+#[derive(Clone)]
+pub struct EvmSigner {
+    secret_key: SecretKey,
+}
+
+impl std::fmt::Debug for EvmSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the private key
+        f.debug_struct("EvmSigner")
+            .field("address", &self.address())
+            .finish()
+    }
+}
+
+impl EvmSigner {
+    /// Creates a signer from a hex private key, with or without a `0x` prefix.
+    pub fn from_private_key(private_key: &str) -> Result<Self> {
+        let hex_str = private_key.strip_prefix("0x").unwrap_or(private_key);
+        let bytes = hex::decode(hex_str)
+            .map_err(|e| OrderlyError::SigningError(format!("Invalid private key hex: {}", e)))?;
+        let secret_key = SecretKey::parse_slice(&bytes)
+            .map_err(|e| OrderlyError::SigningError(format!("Invalid private key: {:?}", e)))?;
+        Ok(Self { secret_key })
+    }
+
+    /// Returns the wallet address as a lowercase `0x` hex string.
+    pub fn address(&self) -> String {
+        let public_key = PublicKey::from_secret_key(&self.secret_key).serialize();
+        // Skip the 0x04 uncompressed-point prefix; the address is the last 20 bytes of the hash
+        let hash = v256(&public_key[1..]);
+        format!("0x{}", hex::encode(&hash[12..]))
+    }
+
+    /// Signs a 32-byte digest, returning the 65-byte `r || s || v` signature as `0x` hex
+    /// with `v` in Ethereum's 27/28 form.
+    pub fn sign_digest(&self, digest: &[u8; 32]) -> String {
+        let (signature, recovery_id) =
+            libsecp256k1::sign(&Message::parse(digest), &self.secret_key);
+        let mut bytes = signature.serialize().to_vec();
+        bytes.push(27 + recovery_id.serialize());
+        format!("0x{}", hex::encode(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PRIVATE_KEY: &str = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    #[test]
+    fn test_evm_signer_address() {
+        let signer = EvmSigner::from_private_key(PRIVATE_KEY).unwrap();
+        assert_eq!(
+            signer.address(),
+            "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23"
+        );
+
+        // The 0x prefix is optional
+        let unprefixed = EvmSigner::from_private_key(&PRIVATE_KEY[2..]).unwrap();
+        assert_eq!(unprefixed.address(), signer.address());
+    }
+
+    /// Deterministic (RFC 6979) signature over the digest from the EIP-712 withdrawal test
+    /// in `eth::abi`, computed independently.
+    #[test]
+    fn test_evm_signer_sign_digest() {
+        let signer = EvmSigner::from_private_key(PRIVATE_KEY).unwrap();
+        let digest: [u8; 32] =
+            hex::decode("c8d3e23580c7d1b330ab79230066f2e29947e1f4da21bf36d0e6a7e93655b576")
+                .unwrap()
+                .try_into()
+                .unwrap();
+
+        assert_eq!(
+            signer.sign_digest(&digest),
+            "0x55e44ec768b3b8eefe064605df236ee0834d0fbb411727796097275a6cbda88e6af074f75af8e0c645a73f5b43a2076b8322f1d037ef704b5cf78d97a294952d1b"
+        );
+    }
+
+    #[test]
+    fn test_evm_signer_invalid_key() {
+        assert!(matches!(
+            EvmSigner::from_private_key("0x1234"),
+            Err(OrderlyError::SigningError(_))
+        ));
+        assert!(matches!(
+            EvmSigner::from_private_key("not hex"),
+            Err(OrderlyError::SigningError(_))
+        ));
+    }
+}
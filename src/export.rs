@@ -0,0 +1,184 @@
+//! CSV export of trade and asset history rows.
+//!
+//! Requires the `csv` feature. Columns are written in a fixed order with a header row,
+//! so exports from different versions of this crate line up.
+
+use crate::error::{OrderlyError, Result};
+use crate::types::{AssetHistoryEntry, Trade};
+use serde::Serialize;
+use std::io::Write;
+
+/// Column order used by [`trades_to_csv`].
+pub const TRADE_CSV_COLUMNS: [&str; 11] = [
+    "id",
+    "symbol",
+    "side",
+    "order_id",
+    "order_source",
+    "executed_price",
+    "executed_quantity",
+    "fee",
+    "fee_asset",
+    "is_maker",
+    "executed_timestamp",
+];
+
+/// Column order used by [`asset_history_to_csv`].
+pub const ASSET_HISTORY_CSV_COLUMNS: [&str; 11] = [
+    "id",
+    "token",
+    "side",
+    "amount",
+    "fee",
+    "transaction_hash",
+    "chain_id",
+    "chain_name",
+    "trans_status",
+    "created_time",
+    "updated_time",
+];
+
+fn csv_error(err: csv::Error) -> OrderlyError {
+    OrderlyError::IoError(err.into())
+}
+
+/// Returns the name an enum variant has on the wire, e.g. `"BUY"` for `Side::Buy`.
+fn wire_name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(s)) => s,
+        Ok(other) => other.to_string(),
+        Err(_) => String::new(),
+    }
+}
+
+fn optional<T: ToString>(value: &Option<T>) -> String {
+    value.as_ref().map(ToString::to_string).unwrap_or_default()
+}
+
+/// Writes `rows` as CSV to `writer`, with a header row of [`TRADE_CSV_COLUMNS`].
+///
+/// # Examples
+///
+/// ```no_run
+/// # async fn run(
+/// #     client: orderly_connector_rs::rest::OrderlyService,
+/// #     creds: orderly_connector_rs::rest::client::Credentials<'_>,
+/// # ) -> orderly_connector_rs::Result<()> {
+/// use orderly_connector_rs::export::trades_to_csv;
+///
+/// let trades = client.get_all_trades(&creds, None).await?;
+/// let file = std::fs::File::create("trades.csv")?;
+/// trades_to_csv(&trades, file)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn trades_to_csv<W: Write>(rows: &[Trade], writer: W) -> Result<()> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer
+        .write_record(TRADE_CSV_COLUMNS)
+        .map_err(csv_error)?;
+    for trade in rows {
+        csv_writer
+            .write_record([
+                trade.id.to_string(),
+                trade.symbol.clone(),
+                wire_name(&trade.side),
+                trade.order_id.to_string(),
+                optional(&trade.order_source),
+                trade.executed_price.to_string(),
+                trade.executed_quantity.to_string(),
+                trade.fee.to_string(),
+                trade.fee_asset.clone(),
+                trade.is_maker.to_string(),
+                trade.executed_timestamp.to_string(),
+            ])
+            .map_err(csv_error)?;
+    }
+    csv_writer.flush()?;
+    Ok(())
+}
+
+/// Writes `rows` as CSV to `writer`, with a header row of [`ASSET_HISTORY_CSV_COLUMNS`].
+pub fn asset_history_to_csv<W: Write>(rows: &[AssetHistoryEntry], writer: W) -> Result<()> {
+    let mut csv_writer = csv::Writer::from_writer(writer);
+    csv_writer
+        .write_record(ASSET_HISTORY_CSV_COLUMNS)
+        .map_err(csv_error)?;
+    for entry in rows {
+        csv_writer
+            .write_record([
+                entry.id.clone(),
+                entry.token.clone(),
+                wire_name(&entry.side),
+                entry.amount.to_string(),
+                optional(&entry.fee),
+                optional(&entry.transaction_hash),
+                optional(&entry.chain_id),
+                optional(&entry.chain_name),
+                optional(&entry.trans_status),
+                entry.created_time.to_string(),
+                entry.updated_time.to_string(),
+            ])
+            .map_err(csv_error)?;
+    }
+    csv_writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{AssetHistoryType, AssetTxStatus, ChainId, Side};
+
+    #[test]
+    fn test_trades_to_csv() {
+        let trades = vec![Trade {
+            id: 1,
+            symbol: "PERP_ETH_USDC".to_string(),
+            side: Side::Sell,
+            order_id: 1001,
+            order_source: None,
+            executed_price: 2000.5,
+            executed_quantity: 0.1,
+            fee: 0.01,
+            fee_asset: "USDC".to_string(),
+            is_maker: true,
+            executed_timestamp: 1677721600000,
+        }];
+
+        let mut out = Vec::new();
+        trades_to_csv(&trades, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], TRADE_CSV_COLUMNS.join(","));
+        assert_eq!(
+            lines[1],
+            "1,PERP_ETH_USDC,SELL,1001,,2000.5,0.1,0.01,USDC,true,1677721600000"
+        );
+    }
+
+    #[test]
+    fn test_asset_history_to_csv() {
+        let entries = vec![AssetHistoryEntry {
+            id: "7".to_string(),
+            token: "USDC".to_string(),
+            side: AssetHistoryType::Withdrawal,
+            amount: 100.0,
+            fee: Some(1.0),
+            transaction_hash: None,
+            chain_id: Some(ChainId::ARBITRUM),
+            chain_name: Some("Arbitrum".to_string()),
+            trans_status: Some(AssetTxStatus::Completed),
+            created_time: 1677721600000,
+            updated_time: 1677721660000,
+        }];
+
+        let mut out = Vec::new();
+        asset_history_to_csv(&entries, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(
+            text.lines().nth(1).unwrap(),
+            "7,USDC,WITHDRAW,100,1,,42161,Arbitrum,COMPLETED,1677721600000,1677721660000"
+        );
+    }
+}
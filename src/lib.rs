@@ -1,6 +1,8 @@
 pub mod auth;
 pub mod error;
 pub mod eth;
+#[cfg(feature = "csv")]
+pub mod export;
 pub mod rest;
 pub mod solana;
 pub mod types;
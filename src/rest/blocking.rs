@@ -0,0 +1,245 @@
+//! Synchronous wrapper around [`OrderlyService`].
+//!
+//! Requires the `blocking` feature. Each call runs the corresponding async method to
+//! completion on a private current-thread Tokio runtime, so scripts and CLI tools can
+//! use the REST API without setting up async. The WebSocket clients remain async-only.
+
+use crate::error::Result;
+use crate::rest::client::{
+    AsCredentials, ExchangeInfoResponse, OrderlyService, ReplaceOrderOutcome, SymbolInfo,
+};
+use crate::types::*;
+use serde_json::Value;
+use std::collections::HashMap;
+use tokio::runtime::{Builder, Runtime};
+
+/// Blocking counterpart of [`OrderlyService`] covering the common read and trade calls.
+///
+/// Methods block the calling thread until the request completes. Do not call them from
+/// inside an async runtime: Tokio panics when a runtime is started from within another.
+/// Use [`Self::service`] for endpoints that have no blocking wrapper.
+///
+/// # Examples
+///
+/// ```no_run
+/// use orderly_connector_rs::rest::{client::Credentials, BlockingOrderlyService};
+///
+/// let service = BlockingOrderlyService::new(true, None).expect("Failed to create service");
+/// let creds = Credentials {
+///     orderly_key: "your_key",
+///     orderly_secret: "your_secret",
+///     orderly_account_id: "your_account_id",
+/// };
+/// let positions = service.get_positions(&creds).expect("Failed to fetch positions");
+/// println!("{:?}", positions.data.rows);
+/// ```
+pub struct BlockingOrderlyService {
+    service: OrderlyService,
+    runtime: Runtime,
+}
+
+impl BlockingOrderlyService {
+    /// Creates a blocking service for mainnet or testnet. See [`OrderlyService::new`].
+    pub fn new(is_testnet: bool, timeout_sec: Option<u64>) -> Result<Self> {
+        Self::from_service(OrderlyService::new(is_testnet, timeout_sec)?)
+    }
+
+    /// Creates a blocking service for an explicit base URL. See
+    /// [`OrderlyService::with_base_url`].
+    pub fn with_base_url(base_url_str: &str, timeout_sec: Option<u64>) -> Result<Self> {
+        Self::from_service(OrderlyService::with_base_url(base_url_str, timeout_sec)?)
+    }
+
+    /// Wraps an already configured [`OrderlyService`], e.g. one built with
+    /// [`OrderlyService::with_options`].
+    pub fn from_service(service: OrderlyService) -> Result<Self> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+        Ok(Self { service, runtime })
+    }
+
+    /// Returns the wrapped async service.
+    pub fn service(&self) -> &OrderlyService {
+        &self.service
+    }
+
+    // ===== Public =====
+
+    /// See [`OrderlyService::get_system_status`].
+    pub fn get_system_status(&self) -> Result<Value> {
+        self.runtime.block_on(self.service.get_system_status())
+    }
+
+    /// See [`OrderlyService::get_exchange_info`].
+    pub fn get_exchange_info(&self, symbol: Option<&str>) -> Result<ExchangeInfoResponse> {
+        self.runtime
+            .block_on(self.service.get_exchange_info(symbol))
+    }
+
+    /// See [`OrderlyService::get_symbols_map`].
+    pub fn get_symbols_map(&self) -> Result<HashMap<String, SymbolInfo>> {
+        self.runtime.block_on(self.service.get_symbols_map())
+    }
+
+    /// See [`OrderlyService::get_futures_info`].
+    pub fn get_futures_info(&self, symbol: Option<&str>) -> Result<Value> {
+        self.runtime.block_on(self.service.get_futures_info(symbol))
+    }
+
+    /// See [`OrderlyService::get_all_tickers`].
+    pub fn get_all_tickers(&self) -> Result<Vec<TickerData>> {
+        self.runtime.block_on(self.service.get_all_tickers())
+    }
+
+    /// See [`OrderlyService::get_market_trades`].
+    pub fn get_market_trades(&self, symbol: &str) -> Result<GetPublicTradesResponse> {
+        self.runtime
+            .block_on(self.service.get_market_trades(symbol))
+    }
+
+    // ===== Account =====
+
+    /// See [`OrderlyService::get_account_info`].
+    pub fn get_account_info(&self, creds: &impl AsCredentials) -> Result<GetAccountInfoResponse> {
+        self.runtime.block_on(self.service.get_account_info(creds))
+    }
+
+    /// See [`OrderlyService::get_holding`].
+    pub fn get_holding(&self, creds: &impl AsCredentials) -> Result<GetHoldingResponse> {
+        self.runtime.block_on(self.service.get_holding(creds))
+    }
+
+    /// See [`OrderlyService::get_asset_history`].
+    pub fn get_asset_history(
+        &self,
+        creds: &impl AsCredentials,
+        params: Option<GetAssetHistoryParams>,
+    ) -> Result<GetAssetHistoryResponse> {
+        self.runtime
+            .block_on(self.service.get_asset_history(creds, params))
+    }
+
+    // ===== Positions =====
+
+    /// See [`OrderlyService::get_positions`].
+    pub fn get_positions(&self, creds: &impl AsCredentials) -> Result<GetPositionsResponse> {
+        self.runtime.block_on(self.service.get_positions(creds))
+    }
+
+    /// See [`OrderlyService::get_position`].
+    pub fn get_position(
+        &self,
+        creds: &impl AsCredentials,
+        symbol: &str,
+    ) -> Result<GetSinglePositionResponse> {
+        self.runtime
+            .block_on(self.service.get_position(creds, symbol))
+    }
+
+    /// See [`OrderlyService::get_position_history`].
+    pub fn get_position_history(
+        &self,
+        creds: &impl AsCredentials,
+        params: Option<GetPositionHistoryParams>,
+    ) -> Result<GetPositionHistoryResponse> {
+        self.runtime
+            .block_on(self.service.get_position_history(creds, params))
+    }
+
+    // ===== Orders =====
+
+    /// See [`OrderlyService::create_order`].
+    pub fn create_order(
+        &self,
+        creds: &impl AsCredentials,
+        order_req: CreateOrderRequest,
+    ) -> Result<CreateOrderResponse> {
+        self.runtime
+            .block_on(self.service.create_order(creds, order_req))
+    }
+
+    /// See [`OrderlyService::replace_order`].
+    pub fn replace_order(
+        &self,
+        creds: &impl AsCredentials,
+        old_order_id: u64,
+        symbol: &str,
+        new_order_req: CreateOrderRequest,
+    ) -> Result<ReplaceOrderOutcome> {
+        self.runtime.block_on(self.service.replace_order(
+            creds,
+            old_order_id,
+            symbol,
+            new_order_req,
+        ))
+    }
+
+    /// See [`OrderlyService::get_order`].
+    pub fn get_order(&self, creds: &impl AsCredentials, order_id: u64) -> Result<GetOrderResponse> {
+        self.runtime
+            .block_on(self.service.get_order(creds, order_id))
+    }
+
+    /// See [`OrderlyService::cancel_order`].
+    pub fn cancel_order(
+        &self,
+        creds: &impl AsCredentials,
+        order_id: u64,
+        symbol: &str,
+    ) -> Result<CancelOrderResponse> {
+        self.runtime
+            .block_on(self.service.cancel_order(creds, order_id, symbol))
+    }
+
+    /// See [`OrderlyService::cancel_batch_orders`].
+    pub fn cancel_batch_orders(
+        &self,
+        creds: &impl AsCredentials,
+        order_ids: Vec<u64>,
+    ) -> Result<BatchCancelOrdersResponse> {
+        self.runtime
+            .block_on(self.service.cancel_batch_orders(creds, order_ids))
+    }
+
+    /// See [`OrderlyService::get_orders`].
+    pub fn get_orders(
+        &self,
+        creds: &impl AsCredentials,
+        params: Option<GetOrdersParams>,
+    ) -> Result<GetOrdersResponse> {
+        self.runtime
+            .block_on(self.service.get_orders(creds, params))
+    }
+
+    /// See [`OrderlyService::get_open_orders`].
+    pub fn get_open_orders(
+        &self,
+        creds: &impl AsCredentials,
+        symbol: Option<&str>,
+    ) -> Result<Vec<Order>> {
+        self.runtime
+            .block_on(self.service.get_open_orders(creds, symbol))
+    }
+
+    /// See [`OrderlyService::get_trades`].
+    pub fn get_trades(
+        &self,
+        creds: &impl AsCredentials,
+        params: Option<GetTradesParams>,
+    ) -> Result<GetTradesResponse> {
+        self.runtime
+            .block_on(self.service.get_trades(creds, params))
+    }
+
+    /// See [`OrderlyService::get_orderbook_snapshot`].
+    pub fn get_orderbook_snapshot(
+        &self,
+        creds: &impl AsCredentials,
+        symbol: &str,
+        max_level: Option<u32>,
+    ) -> Result<GetOrderbookSnapshotResponse> {
+        self.runtime.block_on(
+            self.service
+                .get_orderbook_snapshot(creds, symbol, max_level),
+        )
+    }
+}
@@ -1,12 +1,16 @@
-use crate::auth::{self, get_timestamp_ms};
-use crate::error::{OrderlyError, Result};
-use crate::eth::abi::create_registration_message;
+use crate::auth::{self, get_timestamp_ms, AuthVersion, SigningScheme};
+use crate::error::{OrderlyApiCode, OrderlyError, Result};
+use crate::eth::abi::{create_evm_withdrawal_message, create_registration_message, Eip712Domain};
+use crate::eth::signing::EvmSigner;
+use crate::rest::metrics::MetricsObserver;
+use crate::rest::pagination::{collect_pages, MAX_AUTO_PAGES};
+use crate::rest::transport::Transport;
 use crate::solana::signing::sign_solana_message;
 use crate::solana::types::SolanaConfig;
 use crate::types::*;
 use log::{error, info, warn};
-use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
-use reqwest::{Client as HttpClient, Method, Request, Response};
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Client as HttpClient, Method, Request, Response, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -14,15 +18,66 @@ use solabi::encode::encode;
 use solabi::keccak::v256;
 use solana_sdk::signer::keypair::Keypair;
 use solana_sdk::signer::Signer;
-use std::time::Duration;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use url::Url; // For keypair.pubkey() // Import v256
 
 const MAINNET_API_URL: &str = "https://api.orderly.org";
 const TESTNET_API_URL: &str = "https://testnet-api-evm.orderly.network";
 const DEFAULT_TIMEOUT_SECONDS: u64 = 10;
+const MAX_BATCH_CANCEL_ORDERS: usize = 10; // Documented limit for DELETE /v1/batch-order
+const WITHDRAWAL_POLL_INTERVAL: Duration = Duration::from_secs(5); // Used by wait_for_withdrawal
+const MAX_CONCURRENT_SUBACCOUNT_REQUESTS: usize = 5; // Used by get_positions_all_subaccounts
+
+/// Checks a withdrawal request locally so malformed requests never reach the server.
+///
+/// The amount must be positive and the address non-empty. Which chains and tokens can be
+/// withdrawn, and at what precision, changes over time and is left to the server.
+fn validate_withdraw_request(req: &WithdrawRequest<'_>) -> Result<()> {
+    if !req.amount.is_finite() || req.amount <= 0.0 {
+        return Err(OrderlyError::ValidationError(format!(
+            "Withdrawal amount must be positive, got {}",
+            req.amount
+        )));
+    }
+    if req.withdraw_address.trim().is_empty() {
+        return Err(OrderlyError::ValidationError(
+            "withdraw_address must not be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Formats a millisecond timestamp as a `YYYY-MM-DD` UTC date.
+fn utc_date(timestamp_ms: u64) -> Result<String> {
+    let datetime = i64::try_from(timestamp_ms)
+        .ok()
+        .and_then(chrono::DateTime::from_timestamp_millis)
+        .ok_or_else(|| {
+            OrderlyError::ValidationError(format!("Timestamp out of range: {}", timestamp_ms))
+        })?;
+    Ok(datetime.format("%Y-%m-%d").to_string())
+}
+
+/// Source of synthetic order/withdrawal IDs returned in dry-run mode.
+static DRY_RUN_IDS: AtomicU64 = AtomicU64::new(1);
+
+/// Parses a `Retry-After` header given in seconds. HTTP-date values are not supported.
+fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
 
 /// Holds the necessary credentials for authenticating with private Orderly endpoints.
-#[derive(Debug, Clone)] // Clone is useful, Debug for logging
+///
+/// The `Debug` output redacts `orderly_key` and `orderly_secret`, so credentials can be
+/// logged without leaking them.
+#[derive(Clone)]
 pub struct Credentials<'a> {
     /// The public API key provided by Orderly Network.
     pub orderly_key: &'a str,
@@ -32,6 +87,264 @@ pub struct Credentials<'a> {
     pub orderly_account_id: &'a str,
 }
 
+impl std::fmt::Debug for Credentials<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("orderly_key", &auth::REDACTED)
+            .field("orderly_secret", &auth::REDACTED)
+            .field("orderly_account_id", &self.orderly_account_id)
+            .finish()
+    }
+}
+
+impl Credentials<'_> {
+    /// Loads credentials from the `ORDERLY_API_KEY`, `ORDERLY_SECRET` and
+    /// `ORDERLY_ACCOUNT_ID` environment variables.
+    ///
+    /// Returns owned strings, which can be passed to any signed [`OrderlyService`] method.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OrderlyError::ValidationError` naming the first variable that is unset,
+    /// empty or not valid Unicode.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use orderly_connector_rs::rest::{client::Credentials, OrderlyService};
+    ///
+    /// # async fn run() -> orderly_connector_rs::Result<()> {
+    /// let owned = Credentials::from_env()?;
+    /// let service = OrderlyService::new(true, None)?;
+    /// let positions = service.get_positions(&owned).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_env() -> Result<OwnedCredentials> {
+        let var = |name: &str| match std::env::var(name) {
+            Ok(value) if !value.trim().is_empty() => Ok(value),
+            _ => Err(OrderlyError::ValidationError(format!("{} not set", name))),
+        };
+        Ok(OwnedCredentials {
+            orderly_key: var("ORDERLY_API_KEY")?,
+            orderly_secret: var("ORDERLY_SECRET")?,
+            orderly_account_id: var("ORDERLY_ACCOUNT_ID")?,
+        })
+    }
+}
+
+/// Owned counterpart of [`Credentials`], for holding credentials loaded at runtime
+/// without leaking them to `'static`.
+///
+/// Signed [`OrderlyService`] methods accept it directly via [`AsCredentials`]. Like
+/// [`Credentials`], the `Debug` output redacts `orderly_key` and `orderly_secret`.
+#[derive(Clone)]
+pub struct OwnedCredentials {
+    /// The public API key provided by Orderly Network.
+    pub orderly_key: String,
+    /// The private API key provided by Orderly Network, used for signing requests.
+    pub orderly_secret: String,
+    /// The user's unique account identifier on Orderly Network.
+    pub orderly_account_id: String,
+}
+
+impl OwnedCredentials {
+    /// Creates owned credentials from the key, secret and account ID.
+    pub fn new(
+        orderly_key: impl Into<String>,
+        orderly_secret: impl Into<String>,
+        orderly_account_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            orderly_key: orderly_key.into(),
+            orderly_secret: orderly_secret.into(),
+            orderly_account_id: orderly_account_id.into(),
+        }
+    }
+
+    /// Returns a borrowing [`Credentials`] view of these credentials.
+    pub fn as_credentials(&self) -> Credentials<'_> {
+        Credentials {
+            orderly_key: &self.orderly_key,
+            orderly_secret: &self.orderly_secret,
+            orderly_account_id: &self.orderly_account_id,
+        }
+    }
+}
+
+impl std::fmt::Debug for OwnedCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OwnedCredentials")
+            .field("orderly_key", &auth::REDACTED)
+            .field("orderly_secret", &auth::REDACTED)
+            .field("orderly_account_id", &self.orderly_account_id)
+            .finish()
+    }
+}
+
+/// Anything that can lend a borrowing [`Credentials`] view.
+///
+/// Every signed [`OrderlyService`] method accepts `&impl AsCredentials`, so both the
+/// zero-copy [`Credentials`] and the owned [`OwnedCredentials`] can be passed directly.
+pub trait AsCredentials {
+    /// Returns the credentials to sign with.
+    fn as_credentials(&self) -> Credentials<'_>;
+}
+
+impl AsCredentials for Credentials<'_> {
+    fn as_credentials(&self) -> Credentials<'_> {
+        self.clone()
+    }
+}
+
+impl AsCredentials for OwnedCredentials {
+    fn as_credentials(&self) -> Credentials<'_> {
+        OwnedCredentials::as_credentials(self)
+    }
+}
+
+impl<T: AsCredentials + ?Sized> AsCredentials for &T {
+    fn as_credentials(&self) -> Credentials<'_> {
+        (**self).as_credentials()
+    }
+}
+
+/// The components of a signed private request, as returned by
+/// [`OrderlyService::build_signed_request_parts`].
+#[derive(Debug, Clone)]
+pub struct SignedRequestParts {
+    /// HTTP method of the request
+    pub method: Method,
+    /// Full request URL, including any query string
+    pub url: Url,
+    /// Request headers, including the `orderly-*` authentication headers
+    pub headers: HeaderMap,
+    /// JSON body exactly as signed, if any
+    pub body: Option<String>,
+}
+
+/// A signed request together with what is needed to sign it again.
+///
+/// Dereferences to the built [`Request`]. `send_request` re-signs it with a fresh
+/// timestamp if the server rejects the signature or a GET is retried.
+struct SignedRequest {
+    request: Request,
+    creds: OwnedCredentials,
+    method: Method,
+    path: String,
+    body: Option<String>,
+}
+
+/// Builds the request sent by a retry of a transient GET failure.
+type RebuildRequest<'a> = dyn Fn() -> Result<Request> + Send + Sync + 'a;
+
+/// Symbol info map cached by `get_symbols_map_cached`, with the time it was fetched.
+type SymbolsCache = Option<(Instant, HashMap<String, SymbolInfo>)>;
+
+impl std::ops::Deref for SignedRequest {
+    type Target = Request;
+
+    fn deref(&self) -> &Request {
+        &self.request
+    }
+}
+
+/// Rate-limit budget reported by the server in `x-ratelimit-*` response headers.
+///
+/// Each field is `None` if the corresponding header was absent or not a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// Requests allowed in the current window (`x-ratelimit-limit`)
+    pub limit: Option<u64>,
+    /// Requests left in the current window (`x-ratelimit-remaining`)
+    pub remaining: Option<u64>,
+    /// When the window resets, as reported by the server (`x-ratelimit-reset`)
+    pub reset: Option<u64>,
+}
+
+impl RateLimitStatus {
+    /// Parses the `x-ratelimit-*` headers, returning `None` if none of them are present.
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let parse = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+        let status = Self {
+            limit: parse("x-ratelimit-limit"),
+            remaining: parse("x-ratelimit-remaining"),
+            reset: parse("x-ratelimit-reset"),
+        };
+        if status.limit.is_none() && status.remaining.is_none() && status.reset.is_none() {
+            None
+        } else {
+            Some(status)
+        }
+    }
+}
+
+/// Result of [`OrderlyService::replace_order`].
+///
+/// Only produced once the old order was cancelled; failures before that are returned as
+/// the method's error.
+#[derive(Debug)]
+pub struct ReplaceOrderOutcome {
+    /// Response to cancelling the old order
+    pub cancel: CancelOrderResponse,
+    /// Result of creating the replacement order
+    pub create: Result<CreateOrderResponse>,
+    /// Result of re-creating the old order, attempted only if `create` was rejected with a
+    /// 4xx response and the old order still had quantity left to fill
+    pub rollback: Option<Result<CreateOrderResponse>>,
+}
+
+/// How GET requests are retried after transient network failures.
+///
+/// Only connection errors and timeouts are retried, never HTTP error responses, and only
+/// for GET requests, so orders and withdrawals are never submitted twice. Signed requests
+/// are signed again with a fresh timestamp for every retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled for each further retry
+    pub initial_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+        }
+    }
+}
+
+/// Options for constructing an [`OrderlyService`] via [`OrderlyService::with_options`].
+#[derive(Debug, Clone, Default)]
+pub struct OrderlyServiceOptions {
+    /// Whether to use testnet (true) or mainnet (false). Ignored if `base_url` is set.
+    pub is_testnet: bool,
+    /// Optional explicit base URL, e.g. for a mock server
+    pub base_url: Option<String>,
+    /// Optional timeout in seconds for HTTP requests (defaults to 10)
+    pub timeout_sec: Option<u64>,
+    /// Optional proxy URL applied to all requests, e.g. `http://proxy:8080`
+    pub proxy: Option<String>,
+    /// Optional `User-Agent` header sent with every request
+    pub user_agent: Option<String>,
+    /// Optional pre-configured HTTP client. When set, `timeout_sec`, `proxy` and
+    /// `user_agent` are ignored and the client is used as-is.
+    pub http_client: Option<HttpClient>,
+    /// When true, mutating requests are built and signed but never sent; see
+    /// [`OrderlyService::is_dry_run`].
+    pub dry_run: bool,
+    /// Optional retry policy for GET requests that fail with a connection error or
+    /// timeout. Without one, failed requests are not retried.
+    pub retry_policy: Option<RetryPolicy>,
+}
+
 /// A service client for interacting with the Orderly Network REST API.
 ///
 /// This service holds shared components like the HTTP client and base URL,
@@ -80,6 +393,30 @@ pub struct OrderlyService {
     http_client: HttpClient,
     /// The base URL for the Orderly API (either mainnet or testnet).
     base_url: Url,
+    /// How private requests are signed (Orderly API secret or Solana keypair).
+    signing_scheme: SigningScheme,
+    /// Optional validity window in milliseconds sent with signed requests.
+    recv_window_ms: Option<u64>,
+    /// Which authentication header names signed requests carry.
+    auth_version: AuthVersion,
+    /// Optional observer notified of every request's path, status and latency.
+    metrics: Option<Arc<dyn MetricsObserver>>,
+    /// Sends built requests; the `http_client` itself unless replaced via `with_transport`.
+    transport: Arc<dyn Transport>,
+    /// Whether mutating requests are suppressed (see `OrderlyServiceOptions::dry_run`).
+    dry_run: bool,
+    /// Retry policy for GET requests failing with transient network errors, if any.
+    retry_policy: Option<RetryPolicy>,
+    /// Last fee rates response per account id, with the time it was fetched.
+    /// Shared between clones so they all benefit from the same cache.
+    fee_rates_cache: Arc<Mutex<HashMap<String, (Instant, GetFeeRatesResponse)>>>,
+    /// Last symbol info map and the time it was fetched, shared between clones.
+    symbols_cache: Arc<Mutex<SymbolsCache>>,
+    /// Rate-limit headers of the most recent response that carried them, shared between clones.
+    last_rate_limit: Arc<Mutex<Option<RateLimitStatus>>>,
+    /// Server time minus local time in milliseconds, added to signing timestamps.
+    /// Set by `sync_time` and shared between clones.
+    clock_offset_ms: Arc<AtomicI64>,
     // User-specific fields removed
     // timeout is configured directly in the HttpClient
 }
@@ -133,20 +470,10 @@ impl OrderlyService {
     /// }
     /// ```
     pub fn new(is_testnet: bool, timeout_sec: Option<u64>) -> Result<Self> {
-        let base_url_str = if is_testnet {
-            TESTNET_API_URL
-        } else {
-            MAINNET_API_URL
-        };
-        let base_url = Url::parse(base_url_str)?;
-
-        let timeout_duration = Duration::from_secs(timeout_sec.unwrap_or(DEFAULT_TIMEOUT_SECONDS));
-
-        let http_client = HttpClient::builder().timeout(timeout_duration).build()?; // Propagates reqwest::Error via From trait in OrderlyError
-
-        Ok(Self {
-            http_client,
-            base_url,
+        Self::with_options(OrderlyServiceOptions {
+            is_testnet,
+            timeout_sec,
+            ..Default::default()
         })
     }
 
@@ -163,54 +490,300 @@ impl OrderlyService {
     ///
     /// A `Result` containing the configured service or an error if initialization fails.
     pub fn with_base_url(base_url_str: &str, timeout_sec: Option<u64>) -> Result<Self> {
+        Self::with_options(OrderlyServiceOptions {
+            base_url: Some(base_url_str.to_string()),
+            timeout_sec,
+            ..Default::default()
+        })
+    }
+
+    /// Creates a new Orderly REST API service around an externally configured HTTP client.
+    ///
+    /// Use this to share a connection pool, custom TLS roots or HTTP/2 settings with the
+    /// rest of an application. The client's own timeout settings apply. To combine a custom
+    /// client with a custom base URL, set `http_client` and `base_url` in
+    /// [`OrderlyServiceOptions`] instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client` - The `reqwest::Client` to send requests with
+    /// * `is_testnet` - Whether to use testnet (true) or mainnet (false)
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the configured service or an error if initialization fails.
+    pub fn with_http_client(http_client: HttpClient, is_testnet: bool) -> Result<Self> {
+        Self::with_options(OrderlyServiceOptions {
+            is_testnet,
+            http_client: Some(http_client),
+            ..Default::default()
+        })
+    }
+
+    /// Creates a new Orderly REST API service from [`OrderlyServiceOptions`].
+    ///
+    /// This is the most flexible constructor, allowing an HTTP proxy and a custom
+    /// `User-Agent` header in addition to the network and timeout settings.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Network, timeout, proxy and user agent settings
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the configured service or an error if the base URL or proxy
+    /// URL is invalid, or the HTTP client cannot be built.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use orderly_connector_rs::rest::client::{OrderlyService, OrderlyServiceOptions};
+    ///
+    /// let service = OrderlyService::with_options(OrderlyServiceOptions {
+    ///     is_testnet: true,
+    ///     proxy: Some("http://proxy.internal:8080".to_string()),
+    ///     user_agent: Some("my-trading-bot/1.0".to_string()),
+    ///     ..Default::default()
+    /// })
+    /// .expect("Failed to create service");
+    /// ```
+    pub fn with_options(options: OrderlyServiceOptions) -> Result<Self> {
+        let base_url_str = match &options.base_url {
+            Some(url) => url.as_str(),
+            None if options.is_testnet => TESTNET_API_URL,
+            None => MAINNET_API_URL,
+        };
         let base_url = Url::parse(base_url_str)?;
-        let timeout_duration = Duration::from_secs(timeout_sec.unwrap_or(DEFAULT_TIMEOUT_SECONDS));
-        let http_client = HttpClient::builder().timeout(timeout_duration).build()?;
+
+        let http_client = match options.http_client {
+            Some(client) => client,
+            None => {
+                let timeout_duration =
+                    Duration::from_secs(options.timeout_sec.unwrap_or(DEFAULT_TIMEOUT_SECONDS));
+                let mut builder = HttpClient::builder().timeout(timeout_duration);
+                if let Some(proxy_url) = &options.proxy {
+                    builder = builder.proxy(reqwest::Proxy::all(proxy_url.as_str())?);
+                }
+                if let Some(user_agent) = &options.user_agent {
+                    builder = builder.user_agent(user_agent.as_str());
+                }
+                builder.build()? // Propagates reqwest::Error via From trait in OrderlyError
+            }
+        };
+
         Ok(Self {
+            transport: Arc::new(http_client.clone()),
             http_client,
             base_url,
+            signing_scheme: SigningScheme::default(),
+            recv_window_ms: None,
+            auth_version: AuthVersion::default(),
+            metrics: None,
+            dry_run: options.dry_run,
+            retry_policy: options.retry_policy,
+            fee_rates_cache: Arc::new(Mutex::new(HashMap::new())),
+            symbols_cache: Arc::new(Mutex::new(None)),
+            last_rate_limit: Arc::new(Mutex::new(None)),
+            clock_offset_ms: Arc::new(AtomicI64::new(0)),
         })
     }
 
-    /// Builds a signed reqwest::Request using provided credentials.
-    async fn build_signed_request<T: Serialize>(
+    /// Sets the scheme used to sign private requests.
+    ///
+    /// With [`SigningScheme::SolanaEd25519`], `Credentials::orderly_secret` must hold the
+    /// base58 Solana keypair and the `orderly-key` header is derived from it, so
+    /// `Credentials::orderly_key` is ignored.
+    pub fn with_signing_scheme(mut self, signing_scheme: SigningScheme) -> Self {
+        self.signing_scheme = signing_scheme;
+        self
+    }
+
+    /// Sets how long, in milliseconds, signed requests stay valid after their timestamp.
+    ///
+    /// The value is sent in the `orderly-recv-window` header so the server tolerates
+    /// more latency or clock skew. Without it, the server's default window applies.
+    pub fn with_recv_window_ms(mut self, recv_window_ms: u64) -> Self {
+        self.recv_window_ms = Some(recv_window_ms);
+        self
+    }
+
+    /// Sets the authentication header names emitted by signed requests.
+    ///
+    /// Defaults to [`AuthVersion::V1`], the `orderly-*` headers.
+    pub fn with_auth_version(mut self, auth_version: AuthVersion) -> Self {
+        self.auth_version = auth_version;
+        self
+    }
+
+    /// Returns true if this service runs in dry-run mode.
+    ///
+    /// In dry-run mode, mutating methods build and sign their request, log it and return
+    /// a synthetic success response with a generated ID instead of sending it. This covers
+    /// `create_order`, `cancel_order`, `cancel_batch_orders`, `request_withdrawal`,
+    /// `withdraw_evm`, `create_algo_order` and `create_bracket_order`. Any other non-GET
+    /// request fails with `OrderlyError::DryRun`. GET requests are sent as usual.
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    /// Registers an observer that is notified when each request starts and ends.
+    ///
+    /// Without an observer no timing is recorded.
+    pub fn with_metrics_observer(mut self, observer: Arc<dyn MetricsObserver>) -> Self {
+        self.metrics = Some(observer);
+        self
+    }
+
+    /// Replaces the transport that sends requests, e.g. with a `MockTransport` (behind the
+    /// `test-util` feature) in tests.
+    ///
+    /// Requests are still built, signed and parsed by the service; only sending them is
+    /// delegated. Metrics, retries and rate-limit tracking apply as usual.
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Returns the rate-limit budget from the most recent response that reported one.
+    ///
+    /// Every response, successful or not, is checked for `x-ratelimit-*` headers. Use
+    /// this to slow down before the server starts answering with HTTP 429. Returns
+    /// `None` until a response with rate-limit headers has been received.
+    pub fn last_rate_limit(&self) -> Option<RateLimitStatus> {
+        *self
+            .last_rate_limit
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Returns the offset, in milliseconds, added to the local clock when signing requests.
+    ///
+    /// Zero until [`Self::sync_time`] has run.
+    pub fn clock_offset_ms(&self) -> i64 {
+        self.clock_offset_ms.load(Ordering::Relaxed)
+    }
+
+    /// Measures the offset between the local clock and the server clock and uses it to
+    /// timestamp subsequent signed requests.
+    ///
+    /// The server time is the `timestamp` of a `GET /v1/public/system_info` response,
+    /// compared against the local time halfway through the round trip. The offset is
+    /// shared by all clones of this service. Signed requests rejected with
+    /// [`OrderlyApiCode::InvalidSignature`]
+    /// call this automatically before being re-signed and retried once.
+    ///
+    /// Returns the new offset in milliseconds (server time minus local time).
+    pub async fn sync_time(&self) -> Result<i64> {
+        let sent_at = get_timestamp_ms()?;
+        let status = self.get_system_status().await?;
+        let received_at = get_timestamp_ms()?;
+        let server_time = status["timestamp"].as_u64().ok_or_else(|| {
+            OrderlyError::ValidationError("system_info response has no timestamp".to_string())
+        })?;
+        let local_time = sent_at + received_at.saturating_sub(sent_at) / 2;
+        let offset = server_time as i64 - local_time as i64;
+        self.clock_offset_ms.store(offset, Ordering::Relaxed);
+        info!("Synced clock with server, offset {} ms", offset);
+        Ok(offset)
+    }
+
+    /// Returns the current time in milliseconds, adjusted by the synced clock offset.
+    fn signing_timestamp_ms(&self) -> Result<u64> {
+        let now = get_timestamp_ms()? as i64;
+        Ok(now.saturating_add(self.clock_offset_ms()).max(0) as u64)
+    }
+
+    /// Builds and signs the components of a private request without sending it.
+    ///
+    /// Returns the URL, method, headers (including the `orderly-*` authentication headers)
+    /// and JSON body that [`OrderlyService`] would send, so the request can be transmitted
+    /// over a different transport or constructed on an offline machine. The signature
+    /// covers the current timestamp, adjusted by any offset measured with
+    /// [`Self::sync_time`], so the request must be sent promptly.
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - Credentials used to sign the request
+    /// * `method` - HTTP method
+    /// * `path` - Request path including any query string, e.g. `/v1/orders?symbol=PERP_ETH_USDC`
+    /// * `body` - Optional JSON body
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use orderly_connector_rs::rest::client::{Credentials, OrderlyService};
+    /// use reqwest::Method;
+    ///
+    /// let service = OrderlyService::new(true, None).expect("Failed to create service");
+    /// let creds = Credentials {
+    ///     orderly_key: "your_api_key",
+    ///     orderly_secret: "your_api_secret",
+    ///     orderly_account_id: "your_account_id",
+    /// };
+    /// let parts = service
+    ///     .build_signed_request_parts::<()>(&creds, Method::GET, "/v1/positions", None)
+    ///     .expect("Failed to sign request");
+    /// println!("{} {}", parts.method, parts.url);
+    /// ```
+    pub fn build_signed_request_parts<T: Serialize>(
         &self,
-        creds: &Credentials<'_>, // Accept credentials
+        creds: &impl AsCredentials,
         method: Method,
         path: &str,
         body: Option<T>,
-    ) -> Result<Request> {
-        let timestamp = get_timestamp_ms()?;
-        let full_url = self.base_url.join(path)?;
-
+    ) -> Result<SignedRequestParts> {
         let body_str = match &body {
-            Some(b) => serde_json::to_string(b)?, // Propagates SerdeError
-            None => String::new(),
+            Some(b) => Some(serde_json::to_string(b)?), // Propagates SerdeError
+            None => None,
         };
+        self.sign_parts(&creds.as_credentials(), method, path, body_str)
+    }
+
+    /// Signs an already serialized body, so a request can be re-signed with the same bytes.
+    fn sign_parts(
+        &self,
+        creds: &Credentials<'_>,
+        method: Method,
+        path: &str,
+        body_str: Option<String>,
+    ) -> Result<SignedRequestParts> {
+        let timestamp = self.signing_timestamp_ms()?;
+        let full_url = self.base_url.join(path)?;
 
-        let message_to_sign = format!("{}{}{}{}", timestamp, method.as_str(), path, body_str);
+        let message_to_sign = format!(
+            "{}{}{}{}",
+            timestamp,
+            method.as_str(),
+            path,
+            body_str.as_deref().unwrap_or_default()
+        );
         // Use credentials passed in
-        let signature = auth::generate_signature(creds.orderly_secret, &message_to_sign)?;
+        let signature = auth::generate_signature_with_scheme(
+            self.signing_scheme,
+            creds.orderly_secret,
+            &message_to_sign,
+        )?;
+        let orderly_key = match self.signing_scheme {
+            SigningScheme::OrderlyKey => creds.orderly_key.to_string(),
+            SigningScheme::SolanaEd25519 => {
+                auth::derive_orderly_key(self.signing_scheme, creds.orderly_secret)?
+            }
+        };
 
+        let names = self.auth_version.header_names();
         let mut headers = HeaderMap::new();
-        headers.insert(
-            HeaderName::from_static("orderly-timestamp"),
-            HeaderValue::from(timestamp),
-        );
+        headers.insert(names.timestamp, HeaderValue::from(timestamp));
         // Use credentials passed in
-        headers.insert(
-            HeaderName::from_static("orderly-key"),
-            HeaderValue::from_str(creds.orderly_key)?,
-        );
-        headers.insert(
-            HeaderName::from_static("orderly-signature"),
-            HeaderValue::from_str(&signature)?,
-        );
+        headers.insert(names.key, HeaderValue::from_str(&orderly_key)?);
+        headers.insert(names.signature, HeaderValue::from_str(&signature)?);
         // Use credentials passed in
         headers.insert(
-            HeaderName::from_static("orderly-account-id"),
+            names.account_id,
             HeaderValue::from_str(creds.orderly_account_id)?,
         );
+        if let Some(recv_window_ms) = self.recv_window_ms {
+            headers.insert(names.recv_window, HeaderValue::from(recv_window_ms));
+        }
 
         if method != Method::DELETE {
             headers.insert(
@@ -224,179 +797,346 @@ impl OrderlyService {
             );
         }
 
-        let mut request_builder = self.http_client.request(method, full_url).headers(headers);
+        Ok(SignedRequestParts {
+            method,
+            url: full_url,
+            headers,
+            body: body_str,
+        })
+    }
 
-        if let Some(b) = body {
-            request_builder = request_builder.json(&b);
+    /// Builds a signed reqwest::Request using provided credentials.
+    async fn build_signed_request<T: Serialize>(
+        &self,
+        creds: &impl AsCredentials, // Accept credentials
+        method: Method,
+        path: &str,
+        body: Option<T>,
+    ) -> Result<SignedRequest> {
+        let parts = self.build_signed_request_parts(creds, method.clone(), path, body)?;
+        let creds = creds.as_credentials();
+        Ok(SignedRequest {
+            body: parts.body.clone(),
+            request: self.request_from_parts(parts)?,
+            creds: OwnedCredentials::new(
+                creds.orderly_key,
+                creds.orderly_secret,
+                creds.orderly_account_id,
+            ),
+            method,
+            path: path.to_string(),
+        })
+    }
+
+    /// Turns signed parts into a reqwest::Request.
+    fn request_from_parts(&self, parts: SignedRequestParts) -> Result<Request> {
+        let mut request_builder = self
+            .http_client
+            .request(parts.method, parts.url)
+            .headers(parts.headers);
+
+        // Send exactly the bytes that were signed
+        if let Some(b) = parts.body {
+            request_builder = request_builder.body(b);
         }
 
         Ok(request_builder.build()?) // Propagates reqwest::Error
     }
 
-    /// Sends a request and handles the response, parsing success or error.
-    async fn send_request<T: DeserializeOwned>(&self, request: Request) -> Result<T> {
-        let response = self.http_client.execute(request).await?;
-        let status = response.status();
-        let headers = response.headers().clone();
+    /// Sends a signed request and handles the response, parsing success or error.
+    ///
+    /// Orderly has no separate code for expired timestamps, so a drifted clock shows up as
+    /// `InvalidSignature` (-1001). On that code only, the clock is resynced via
+    /// [`Self::sync_time`] and the request is re-signed and sent once more. Any other error,
+    /// a failed resync or a second rejection is returned as is.
+    async fn send_request<T: DeserializeOwned>(&self, signed: SignedRequest) -> Result<T> {
+        let SignedRequest {
+            request,
+            creds,
+            method,
+            path,
+            body,
+        } = signed;
+        let resign = || {
+            let parts =
+                self.sign_parts(&creds.as_credentials(), method.clone(), &path, body.clone())?;
+            self.request_from_parts(parts)
+        };
+        match self.dispatch(request, &resign).await {
+            Err(err) if err.api_code() == Some(OrderlyApiCode::InvalidSignature) => {
+                warn!(
+                    "Signature rejected for {} {}; resyncing clock and retrying once",
+                    method, path
+                );
+                if let Err(sync_err) = self.sync_time().await {
+                    warn!("Clock resync failed: {}", sync_err);
+                    return Err(err);
+                }
+                self.dispatch(resign()?, &resign).await
+            }
+            result => result,
+        }
+    }
 
-        if status.is_success() {
-            // Read the body text first
-            let body_text = response.text().await?; // Consume response here
-
-            // Attempt to parse the text as JSON
-            match serde_json::from_str::<T>(&body_text) {
-                // Parse from text
-                Ok(parsed_body) => Ok(parsed_body),
-                Err(e) => {
-                    // Log the raw body text if JSON parsing fails
-                    error!(
-                        "Failed to parse successful response body (Status: {}). Error: {}. Body: {}",
-                        status,
+    /// Sends an unsigned public request and handles the response.
+    async fn send_public_request<T: DeserializeOwned>(&self, request: Request) -> Result<T> {
+        // Unsigned requests are resent as they are
+        let template = request.try_clone();
+        let clone = || {
+            template
+                .as_ref()
+                .and_then(Request::try_clone)
+                .ok_or_else(|| {
+                    OrderlyError::ValidationError(
+                        "Request cannot be cloned for a retry".to_string(),
+                    )
+                })
+        };
+        self.dispatch(request, &clone).await
+    }
+
+    /// Executes a request and parses its response, calling `rebuild` for each retry.
+    ///
+    /// With the `tracing` feature, this runs inside an `orderly.request` span recording the
+    /// HTTP method, URL path, response status and Orderly error code. Headers (and with them
+    /// the key and signature), query strings and bodies are never recorded.
+    async fn dispatch<T: DeserializeOwned>(
+        &self,
+        request: Request,
+        rebuild: &RebuildRequest<'_>,
+    ) -> Result<T> {
+        if self.dry_run && request.method() != Method::GET {
+            Self::log_dry_run(&request);
+            return Err(OrderlyError::DryRun(format!(
+                "{} {}",
+                request.method(),
+                request.url().path()
+            )));
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+
+            let span = tracing::info_span!(
+                "orderly.request",
+                http.method = %request.method(),
+                http.path = %request.url().path(),
+                http.status = tracing::field::Empty,
+                orderly.code = tracing::field::Empty,
+            );
+            let result = async {
+                let method = request.method().clone();
+                let path = request.url().path().to_string();
+                let response = self.execute(request, rebuild).await?;
+                tracing::Span::current().record("http.status", response.status().as_u16());
+                Self::handle_response(response, method, path).await
+            }
+            .instrument(span.clone())
+            .await;
+            if let Some(code) = result.as_ref().err().and_then(|e| e.api_code()) {
+                span.record("orderly.code", code.code());
+            }
+            result
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        {
+            let method = request.method().clone();
+            let path = request.url().path().to_string();
+            let response = self.execute(request, rebuild).await?;
+            Self::handle_response(response, method, path).await
+        }
+    }
+
+    /// Logs a request suppressed in dry-run mode. Headers are left out as they carry the signature.
+    fn log_dry_run(request: &Request) {
+        let body = request
+            .body()
+            .and_then(|b| b.as_bytes())
+            .map(String::from_utf8_lossy)
+            .unwrap_or_default();
+        info!(
+            "Dry run: not sending {} {} Body: {}",
+            request.method(),
+            request.url(),
+            body
+        );
+    }
+
+    /// Logs `request` and wraps `data` in a synthetic success response, for dry-run mode.
+    fn dry_run_response<D>(request: &Request, data: D) -> Result<SuccessResponse<D>> {
+        Self::log_dry_run(request);
+        Ok(SuccessResponse {
+            success: true,
+            data,
+            timestamp: get_timestamp_ms()?,
+        })
+    }
+
+    /// Returns a new synthetic ID for dry-run responses.
+    fn next_dry_run_id() -> u64 {
+        DRY_RUN_IDS.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Executes a request, reporting it to the metrics observer if one is set and
+    /// recording any rate-limit headers of the response.
+    ///
+    /// GET requests failing with a connection error or timeout are retried according to
+    /// the service's [`RetryPolicy`], if one is set. Each retry sends the request returned
+    /// by `rebuild` after the backoff, so signed requests carry a fresh timestamp.
+    async fn execute(
+        &self,
+        mut request: Request,
+        rebuild: &RebuildRequest<'_>,
+    ) -> Result<Response> {
+        let policy = match self.retry_policy {
+            Some(policy) if request.method() == Method::GET => policy,
+            _ => RetryPolicy {
+                max_retries: 0,
+                initial_backoff: Duration::ZERO,
+            },
+        };
+
+        let mut backoff = policy.initial_backoff;
+        let mut attempt = 0;
+        let response = loop {
+            match self.execute_once(request).await {
+                Err(e) if attempt < policy.max_retries && (e.is_connect() || e.is_timeout()) => {
+                    attempt += 1;
+                    warn!(
+                        "Transient error on GET (attempt {} of {}): {}. Retrying in {:?}",
+                        attempt,
+                        policy.max_retries + 1,
                         e,
-                        body_text // We already have the text
+                        backoff
                     );
-                    // Determine if the serde error should be mapped to a specific OrderlyError
-                    // For now, just wrap the serde error.
-                    Err(OrderlyError::Serde(e)) // Wrap serde error
+                    tokio::time::sleep(backoff).await;
+                    backoff = backoff.saturating_mul(2);
+                    request = match rebuild() {
+                        Ok(next) => next,
+                        Err(rebuild_err) => {
+                            warn!("Could not rebuild request for retry: {}", rebuild_err);
+                            return Err(e.into());
+                        }
+                    };
                 }
+                result => break result?,
             }
-        } else {
-            // Attempt to parse error response body
-            let error_body_text = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "<Could not read error response body as text>".to_string());
-            let error_body: Value = match serde_json::from_str(&error_body_text) {
-                Ok(val) => val,
-                Err(_) => {
-                    // If parsing error body fails, create a generic error
-                    let error_kind =
-                        if status.is_client_error() {
-                            OrderlyError::ClientError {
-                                status,
-                                code: 0, // Unknown code
-                                message: format!(
-                                "Request failed with status {} (could not parse error body: {})",
-                                status, error_body_text // Include raw text in message
-                            ),
-                                data: None,
-                                header: headers,
-                            }
-                        } else {
-                            OrderlyError::ServerError {
-                                status,
-                                code: 0, // Unknown code
-                                message: format!(
-                                "Request failed with status {} (could not parse error body: {})",
-                                status, error_body_text // Include raw text in message
-                            ),
-                                header: headers,
-                            }
-                        };
-                    return Err(error_kind);
-                }
-            };
+        };
 
-            let code = error_body["code"].as_i64().unwrap_or(0);
-            let message = error_body["message"]
-                .as_str()
-                .unwrap_or(&error_body_text) // Use raw text as fallback message
-                .to_string();
-            let data = error_body.get("data").cloned(); // Optional 'data' field in errors
-
-            let error = if status.is_client_error() {
-                OrderlyError::ClientError {
-                    status,
-                    code,
-                    message,
-                    data,
-                    header: headers,
-                }
-            } else {
-                OrderlyError::ServerError {
-                    status,
-                    code,
-                    message,
-                    header: headers,
-                }
-            };
-            Err(error)
+        if let Some(status) = RateLimitStatus::from_headers(response.headers()) {
+            *self
+                .last_rate_limit
+                .lock()
+                .unwrap_or_else(|e| e.into_inner()) = Some(status);
         }
+        Ok(response)
     }
 
-    /// Sends an unsigned public request and handles the response.
-    async fn send_public_request<T: DeserializeOwned>(&self, request: Request) -> Result<T> {
-        let response = self.http_client.execute(request).await?;
-        Self::handle_response(response).await // Use a shared response handler
+    /// Sends a request once, reporting it to the metrics observer if one is set.
+    async fn execute_once(&self, request: Request) -> reqwest::Result<Response> {
+        match &self.metrics {
+            None => self.transport.execute(request).await,
+            Some(metrics) => {
+                let path = request.url().path().to_string();
+                metrics.on_request_start(&path);
+                let started = Instant::now();
+                let result = self.transport.execute(request).await;
+                let status = result.as_ref().ok().map(|r| r.status().as_u16());
+                metrics.on_request_end(&path, status, started.elapsed());
+                result
+            }
+        }
     }
 
     /// Shared logic to handle response status and body parsing (for both public and private).
-    async fn handle_response<T: DeserializeOwned>(response: Response) -> Result<T> {
+    ///
+    /// `method` and `path` identify the request in any `ClientError`/`ServerError` returned.
+    async fn handle_response<T: DeserializeOwned>(
+        response: Response,
+        method: Method,
+        path: String,
+    ) -> Result<T> {
         let status = response.status();
         let headers = response.headers().clone();
 
         if status.is_success() {
-            let parsed_body = response.json::<T>().await?;
-            Ok(parsed_body)
+            // Read the body text first so it can be logged if parsing fails
+            let body_text = response.text().await?;
+            serde_json::from_str::<T>(&body_text).map_err(|e| {
+                error!(
+                    "Failed to parse successful response body (Status: {}). Error: {}. Body: {}",
+                    status, e, body_text
+                );
+                OrderlyError::Serde(e)
+            })
+        } else if status == StatusCode::TOO_MANY_REQUESTS {
+            Err(OrderlyError::RateLimited {
+                retry_after: parse_retry_after(&headers),
+                header: headers,
+            })
         } else {
             let error_body_text = response
                 .text()
                 .await
                 .unwrap_or_else(|_| "<Could not read error response body as text>".to_string());
-            let error_body: Value = match serde_json::from_str(&error_body_text) {
-                Ok(val) => val,
-                Err(_) => {
-                    let error_kind =
-                        if status.is_client_error() {
-                            OrderlyError::ClientError {
-                                status,
-                                code: 0,
-                                message: format!(
-                                "Request failed with status {} (could not parse error body: {})",
-                                status, error_body_text // Include raw text in message
-                            ),
-                                data: None,
-                                header: headers,
-                            }
-                        } else {
-                            OrderlyError::ServerError {
-                                status,
-                                code: 0,
-                                message: format!(
-                                "Request failed with status {} (could not parse error body: {})",
-                                status, error_body_text // Include raw text in message
-                            ),
-                                header: headers,
-                            }
-                        };
-                    return Err(error_kind);
-                }
-            };
+            Err(Self::parse_error_body(
+                status,
+                headers,
+                &error_body_text,
+                method,
+                path,
+            ))
+        }
+    }
 
-            let code = error_body["code"].as_i64().unwrap_or(0);
-            let message = error_body["message"]
-                .as_str()
-                .unwrap_or(&error_body_text) // Use raw text as fallback message
-                .to_string();
-            let data = error_body.get("data").cloned();
-
-            let error = if status.is_client_error() {
-                OrderlyError::ClientError {
-                    status,
-                    code,
-                    message,
-                    data,
-                    header: headers,
-                }
-            } else {
-                OrderlyError::ServerError {
-                    status,
-                    code,
-                    message,
-                    header: headers,
-                }
-            };
-            Err(error)
+    /// Builds a `ClientError`/`ServerError` from a non-success response body.
+    fn parse_error_body(
+        status: StatusCode,
+        headers: HeaderMap,
+        body_text: &str,
+        method: Method,
+        path: String,
+    ) -> OrderlyError {
+        let (code, message, data) = match serde_json::from_str::<Value>(body_text) {
+            Ok(error_body) => (
+                error_body["code"].as_i64().unwrap_or(0),
+                error_body["message"]
+                    .as_str()
+                    .unwrap_or(body_text) // Use raw text as fallback message
+                    .to_string(),
+                error_body.get("data").cloned(), // Optional 'data' field in errors
+            ),
+            Err(_) => (
+                0, // Unknown code
+                format!(
+                    "Request failed with status {} (could not parse error body: {})",
+                    status, body_text
+                ),
+                None,
+            ),
+        };
+
+        if status.is_client_error() {
+            OrderlyError::ClientError {
+                method,
+                path,
+                status,
+                code,
+                message,
+                data,
+                header: headers,
+            }
+        } else {
+            OrderlyError::ServerError {
+                method,
+                path,
+                status,
+                code,
+                message,
+                header: headers,
+            }
         }
     }
 
@@ -439,6 +1179,16 @@ impl OrderlyService {
         self.send_public_request(request).await
     }
 
+    /// Retrieves the scheduled maintenance window, if any.
+    /// GET /v1/public/maintenance_config
+    ///
+    /// Use this to pause trading ahead of a window instead of reacting to rejected orders.
+    pub async fn get_maintenance_info(&self) -> Result<GetMaintenanceInfoResponse> {
+        let url = self.base_url.join("/v1/public/maintenance_config")?;
+        let request = self.http_client.get(url).build()?;
+        self.send_public_request(request).await
+    }
+
     /// Retrieves exchange information, optionally filtered by symbol.
     ///
     /// This endpoint provides detailed information about available trading pairs,
@@ -483,7 +1233,49 @@ impl OrderlyService {
         };
         let url = self.base_url.join(&path)?;
         let request = self.http_client.get(url).build()?;
-        self.send_request::<ExchangeInfoResponse>(request).await
+        self.send_public_request::<ExchangeInfoResponse>(request)
+            .await
+    }
+
+    /// Retrieves trading rules for all symbols, indexed by symbol name.
+    ///
+    /// Calls `get_exchange_info(None)` and builds a map for constant-time lookups,
+    /// e.g. to pass into [`OrderlyService::create_order_validated`].
+    pub async fn get_symbols_map(&self) -> Result<HashMap<String, SymbolInfo>> {
+        let response = self.get_exchange_info(None).await?;
+        let rows = match response.data {
+            ExchangeInfoData::All(all) => all.rows,
+            ExchangeInfoData::Single(info) => vec![info],
+        };
+        Ok(rows
+            .into_iter()
+            .map(|info| (info.symbol.clone(), info))
+            .collect())
+    }
+
+    /// Same as [`OrderlyService::get_symbols_map`], but reuses the last map if it is
+    /// younger than `ttl`.
+    ///
+    /// The cache is shared between clones of this service. Errors are never cached.
+    pub async fn get_symbols_map_cached(
+        &self,
+        ttl: Duration,
+    ) -> Result<HashMap<String, SymbolInfo>> {
+        if let Some((fetched_at, cached)) = self
+            .symbols_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+        {
+            if fetched_at.elapsed() < ttl {
+                return Ok(cached.clone());
+            }
+        }
+
+        let symbols = self.get_symbols_map().await?;
+        *self.symbols_cache.lock().unwrap_or_else(|e| e.into_inner()) =
+            Some((Instant::now(), symbols.clone()));
+        Ok(symbols)
     }
 
     /// Retrieves futures contract information, optionally filtered by symbol.
@@ -500,6 +1292,19 @@ impl OrderlyService {
         self.send_public_request(request).await
     }
 
+    /// Retrieves the 24h ticker of every symbol in a single request.
+    /// GET /v1/public/futures
+    ///
+    /// Returns the same [`TickerData`] as the WebSocket ticker streams, so a client can
+    /// start from a full snapshot instead of waiting for the first push per symbol.
+    /// The REST response has no trade count, so `trade_count` is always `None`.
+    pub async fn get_all_tickers(&self) -> Result<Vec<TickerData>> {
+        let url = self.base_url.join("/v1/public/futures")?;
+        let request = self.http_client.get(url).build()?;
+        let response: GetTickersResponse = self.send_public_request(request).await?;
+        Ok(response.data.rows)
+    }
+
     /// Get funding rate history for all markets.
     /// GET /v1/public/market_info/funding_history
     ///
@@ -538,6 +1343,64 @@ impl OrderlyService {
         self.send_public_request(request).await
     }
 
+    /// Get the trading rewards epoch that is currently running, if any.
+    /// GET /v1/public/trading_rewards/epoch_info
+    ///
+    /// Fetches all epochs and returns the one whose `[start_time, end_time)` contains the
+    /// current time, or `None` between epochs.
+    pub async fn get_current_epoch(&self) -> Result<Option<EpochInfo>> {
+        let path = "/v1/public/trading_rewards/epoch_info";
+        let url = self.base_url.join(path)?;
+        let request = self.http_client.get(url).build()?;
+        let response: GetEpochInfoResponse = self.send_public_request(request).await?;
+        let now = get_timestamp_ms()?;
+        Ok(response
+            .data
+            .rows
+            .into_iter()
+            .find(|epoch| epoch.contains(now)))
+    }
+
+    /// Get the funding rate history of a single market, oldest first.
+    /// GET /v1/public/funding_rate_history
+    ///
+    /// Returns one page of funding settlements for `symbol`, optionally bounded by
+    /// `params.start_t`/`params.end_t`. Rows are sorted by `funding_rate_timestamp`.
+    ///
+    /// https://orderly.network/docs/build-on-omnichain/evm-api/restful-api/public/get-funding-rate-history-for-one-market
+    pub async fn get_symbol_funding_history(
+        &self,
+        symbol: &str,
+        params: Option<GetSymbolFundingHistoryParams>,
+    ) -> Result<GetSymbolFundingHistoryResponse> {
+        if symbol.trim().is_empty() {
+            return Err(OrderlyError::ValidationError(
+                "symbol must not be empty".to_string(),
+            ));
+        }
+
+        let mut path = format!("/v1/public/funding_rate_history?symbol={}", symbol);
+        if let Some(p) = params {
+            if let Ok(query) = serde_qs::to_string(&p) {
+                if !query.is_empty() {
+                    path.push('&');
+                    path.push_str(&query);
+                }
+            } else {
+                warn!("Failed to serialize GetSymbolFundingHistoryParams to query string");
+            }
+        }
+        let url = self.base_url.join(&path)?;
+        let request = self.http_client.get(url).build()?;
+        let mut response: GetSymbolFundingHistoryResponse =
+            self.send_public_request(request).await?;
+        response
+            .data
+            .rows
+            .sort_by_key(|row| row.funding_rate_timestamp);
+        Ok(response)
+    }
+
     /// Get open interest for all trading pairs.
     /// GET /v1/public/market_info/traders_open_interests
     ///
@@ -773,21 +1636,223 @@ impl OrderlyService {
         self.send_public_request(request).await
     }
 
-    // --- Private Endpoints (Orders) ---
+    // --- Private Endpoints (Orders) ---
+
+    /// Creates a new order for the specified user.
+    /// Corresponds to POST /v1/order
+    ///
+    /// Orders that set both `order_quantity` and `order_amount`, or `order_amount` on an
+    /// order type other than MARKET/BID/ASK, fail with `ValidationError` without contacting
+    /// the server (see [`CreateOrderRequest::validate_size_fields`]). So do GTD orders whose
+    /// expiry has already passed (see [`CreateOrderRequest::validate_time_in_force`]).
+    ///
+    /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/create-order
+    pub async fn create_order(
+        &self,
+        creds: &impl AsCredentials, // Added credentials parameter
+        order_req: CreateOrderRequest,
+    ) -> Result<CreateOrderResponse> {
+        order_req
+            .validate_size_fields()
+            .and_then(|_| order_req.validate_time_in_force())
+            .map_err(OrderlyError::ValidationError)?;
+        let client_order_id = order_req.client_order_id.clone();
+        let request = self
+            .build_signed_request(creds, Method::POST, "/v1/order", Some(order_req)) // Pass creds
+            .await?;
+        if self.dry_run {
+            return Self::dry_run_response(
+                &request,
+                CreateOrderResponseData {
+                    order_id: Self::next_dry_run_id(),
+                    client_order_id,
+                },
+            );
+        }
+        self.send_request::<CreateOrderResponse>(request).await
+    }
+
+    /// Creates a new order after checking it against the symbol's trading rules.
+    ///
+    /// Runs [`SymbolInfo::validate_order`] first and returns its `ValidationError`
+    /// without contacting the server if the order would be rejected for its price tick,
+    /// quantity tick, quantity bounds or minimum notional.
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - Credentials of the account placing the order
+    /// * `order_req` - The order to place
+    /// * `symbol_info` - Trading rules for the order's symbol, from `get_exchange_info`
+    pub async fn create_order_validated(
+        &self,
+        creds: &impl AsCredentials,
+        order_req: CreateOrderRequest,
+        symbol_info: &SymbolInfo,
+    ) -> Result<CreateOrderResponse> {
+        symbol_info.validate_order(&order_req)?;
+        self.create_order(creds, order_req).await
+    }
+
+    /// Flattens the open position in `symbol` with a reduce-only market order.
+    ///
+    /// Fetches the position with [`get_position`](Self::get_position) and submits a market
+    /// order on the opposite side for exactly `position_qty`, with `reduce_only = true`, so
+    /// the order can never open or flip a position. Returns `OrderlyError::ValidationError`
+    /// if there is no open position.
+    pub async fn create_reduce_only_close(
+        &self,
+        creds: &impl AsCredentials,
+        symbol: &str,
+    ) -> Result<CreateOrderResponse> {
+        let position = self.get_position(creds, symbol).await?.data.position;
+        let side = if position.position_qty > 0.0 {
+            Side::Sell
+        } else if position.position_qty < 0.0 {
+            Side::Buy
+        } else {
+            return Err(OrderlyError::ValidationError(format!(
+                "No open position for {}",
+                symbol
+            )));
+        };
+        let order_req = CreateOrderRequest::market_close_position(
+            symbol.to_string(),
+            side,
+            position.position_qty.abs(),
+        );
+        self.create_order(creds, order_req).await
+    }
+
+    /// Replaces an open order by cancelling it and creating `new_order_req`.
+    ///
+    /// The old order is fetched first so it can be restored: if the server rejects the
+    /// replacement (a 4xx response) after the cancel succeeded, the unfilled remainder of
+    /// the old order is re-created with its original type, side, price and `reduce_only`
+    /// flag, and the outcome is reported in [`ReplaceOrderOutcome::rollback`]. The restored
+    /// order carries no `client_order_id`, since the old one stays taken until the cancel
+    /// completes.
+    ///
+    /// This is not atomic. Between the cancel and the create (or the rollback) the account
+    /// has no order resting, the old order may fill further before the cancel lands, and a
+    /// rolled-back order loses its queue priority and gets a new `order_id`. Use an edit
+    /// endpoint instead where one is available.
+    ///
+    /// # Errors
+    ///
+    /// `OrderlyError::ValidationError` without contacting the server if `new_order_req` is
+    /// for a different symbol or fails [`CreateOrderRequest::validate_size_fields`] or
+    /// [`CreateOrderRequest::validate_time_in_force`], and without cancelling if it reuses
+    /// the old order's `client_order_id`. Errors fetching or cancelling the old order are
+    /// returned as-is; in that case no new order was placed.
+    ///
+    /// `OrderlyError::ReplaceOutcomeUnknown` if creating the replacement failed in any other
+    /// way than a 4xx rejection (timeout, 5xx, connection reset). The replacement may then
+    /// be live, so the old order is not restored.
+    pub async fn replace_order(
+        &self,
+        creds: &impl AsCredentials,
+        old_order_id: u64,
+        symbol: &str,
+        new_order_req: CreateOrderRequest,
+    ) -> Result<ReplaceOrderOutcome> {
+        if new_order_req.symbol != symbol {
+            return Err(OrderlyError::ValidationError(format!(
+                "Replacement order symbol {} does not match {}",
+                new_order_req.symbol, symbol
+            )));
+        }
+        new_order_req
+            .validate_size_fields()
+            .and_then(|_| new_order_req.validate_time_in_force())
+            .map_err(OrderlyError::ValidationError)?;
+
+        let old = self.get_order(creds, old_order_id).await?.data.order;
+        if new_order_req.client_order_id.is_some()
+            && new_order_req.client_order_id == old.client_order_id
+        {
+            return Err(OrderlyError::ValidationError(format!(
+                "Replacement order must not reuse client_order_id {:?} of order {}",
+                old.client_order_id, old_order_id
+            )));
+        }
+        let client_order_id = new_order_req.client_order_id.clone();
+        let cancel = self.cancel_order(creds, old_order_id, symbol).await?;
+        let create = match self.create_order(creds, new_order_req).await {
+            // Only a 4xx response proves nothing was placed; otherwise the replacement may be live
+            Err(e)
+                if !matches!(
+                    e,
+                    OrderlyError::ClientError { .. } | OrderlyError::RateLimited { .. }
+                ) =>
+            {
+                warn!(
+                    "Replacement for order {} failed ({}); it may be live, not restoring",
+                    old_order_id, e
+                );
+                return Err(OrderlyError::ReplaceOutcomeUnknown {
+                    old_order_id,
+                    client_order_id,
+                    source: Box::new(e),
+                });
+            }
+            create => create,
+        };
+
+        let rollback = match &create {
+            Ok(_) => None,
+            Err(e) => {
+                warn!(
+                    "Replacement for order {} rejected ({}); restoring the old order",
+                    old_order_id, e
+                );
+                match Self::remaining_order_request(&old) {
+                    Some(restore) => Some(self.create_order(creds, restore).await),
+                    None => None,
+                }
+            }
+        };
+
+        Ok(ReplaceOrderOutcome {
+            cancel,
+            create,
+            rollback,
+        })
+    }
 
-    /// Creates a new order for the specified user.
-    /// Corresponds to POST /v1/order
-    ///
-    /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/create-order
-    pub async fn create_order(
-        &self,
-        creds: &Credentials<'_>, // Added credentials parameter
-        order_req: CreateOrderRequest,
-    ) -> Result<CreateOrderResponse> {
-        let request = self
-            .build_signed_request(creds, Method::POST, "/v1/order", Some(order_req)) // Pass creds
-            .await?;
-        self.send_request::<CreateOrderResponse>(request).await
+    /// Builds a request re-creating the unfilled remainder of `order`, or `None` if nothing
+    /// is left to fill.
+    fn remaining_order_request(order: &Order) -> Option<CreateOrderRequest> {
+        let (order_quantity, order_amount) = match (order.order_quantity, order.order_amount) {
+            (Some(quantity), _) => {
+                let remaining = quantity - order.executed_quantity.unwrap_or(0.0);
+                if remaining <= 0.0 {
+                    return None;
+                }
+                (Some(remaining), None)
+            }
+            (None, Some(amount)) => {
+                let remaining = amount - order.executed_value.unwrap_or(0.0);
+                if remaining <= 0.0 {
+                    return None;
+                }
+                (None, Some(remaining))
+            }
+            (None, None) => return None,
+        };
+        Some(CreateOrderRequest {
+            symbol: order.symbol.clone(),
+            order_type: order.order_type.clone(),
+            side: order.side.clone(),
+            order_price: order.order_price,
+            order_quantity,
+            order_amount,
+            // The old id stays taken while the cancel is pending
+            client_order_id: None,
+            visible_quantity: order.visible_quantity,
+            reduce_only: order.reduce_only,
+            time_in_force: None,
+            expire_time: None,
+        })
     }
 
     /// Retrieves a specific order by its ID for the specified user.
@@ -796,7 +1861,7 @@ impl OrderlyService {
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-order
     pub async fn get_order(
         &self,
-        creds: &Credentials<'_>, // Added credentials parameter
+        creds: &impl AsCredentials, // Added credentials parameter
         order_id: u64,
     ) -> Result<GetOrderResponse> {
         let path = format!("/v1/order/{}", order_id);
@@ -812,7 +1877,7 @@ impl OrderlyService {
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/cancel-order
     pub async fn cancel_order(
         &self,
-        creds: &Credentials<'_>, // Added credentials parameter
+        creds: &impl AsCredentials, // Added credentials parameter
         order_id: u64,
         symbol: &str,
     ) -> Result<CancelOrderResponse> {
@@ -820,16 +1885,74 @@ impl OrderlyService {
         let request = self
             .build_signed_request::<()>(creds, Method::DELETE, &path, None) // Pass creds
             .await?;
+        if self.dry_run {
+            return Self::dry_run_response(
+                &request,
+                CancelOrderResponseData {
+                    status: "CANCEL_SENT".to_string(),
+                },
+            );
+        }
         self.send_request::<CancelOrderResponse>(request).await
     }
 
+    /// Cancels a specific set of orders by ID for the specified user.
+    /// Corresponds to DELETE /v1/batch-order?order_ids={order_ids}
+    ///
+    /// Up to 10 order IDs may be cancelled per request. The response contains one row per
+    /// order ID so callers can tell which cancels succeeded.
+    ///
+    /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/batch-cancel-order
+    pub async fn cancel_batch_orders(
+        &self,
+        creds: &impl AsCredentials,
+        order_ids: Vec<u64>,
+    ) -> Result<BatchCancelOrdersResponse> {
+        if order_ids.is_empty() {
+            return Err(OrderlyError::ValidationError(
+                "order_ids must not be empty".to_string(),
+            ));
+        }
+        if order_ids.len() > MAX_BATCH_CANCEL_ORDERS {
+            return Err(OrderlyError::ValidationError(format!(
+                "Cannot cancel more than {} orders per batch (got {})",
+                MAX_BATCH_CANCEL_ORDERS,
+                order_ids.len()
+            )));
+        }
+
+        let ids = order_ids
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let path = format!("/v1/batch-order?order_ids={}", ids);
+        let request = self
+            .build_signed_request::<()>(creds, Method::DELETE, &path, None) // Pass creds
+            .await?;
+        if self.dry_run {
+            let rows = order_ids
+                .into_iter()
+                .map(|order_id| BatchCancelOrderResult {
+                    order_id,
+                    status: "CANCEL_SENT".to_string(),
+                    code: None,
+                    message: None,
+                })
+                .collect();
+            return Self::dry_run_response(&request, BatchCancelOrdersResponseData { rows });
+        }
+        self.send_request::<BatchCancelOrdersResponse>(request)
+            .await
+    }
+
     /// Retrieves multiple orders for the specified user based on filter parameters.
     /// Corresponds to GET /v1/orders
     ///
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-orders
     pub async fn get_orders(
         &self,
-        creds: &Credentials<'_>,
+        creds: &impl AsCredentials,
         params: Option<GetOrdersParams>,
     ) -> Result<GetOrdersResponse> {
         let mut path = "/v1/orders".to_string();
@@ -849,6 +1972,46 @@ impl OrderlyService {
         self.send_request::<GetOrdersResponse>(request).await
     }
 
+    /// Retrieves all orders matching `params` across all pages.
+    ///
+    /// Calls `get_orders` repeatedly, starting at `params.page` (default 1), until the
+    /// pagination meta reports no further records or `MAX_AUTO_PAGES` pages were fetched.
+    pub async fn get_all_orders(
+        &self,
+        creds: &impl AsCredentials,
+        params: Option<GetOrdersParams>,
+    ) -> Result<Vec<Order>> {
+        let params = params.unwrap_or_default();
+        collect_pages("get_all_orders", params.page.unwrap_or(1), |page| {
+            let params = GetOrdersParams {
+                page: Some(page),
+                ..params.clone()
+            };
+            async move {
+                let response = self.get_orders(creds, Some(params)).await?;
+                Ok((response.data.rows, response.data.meta))
+            }
+        })
+        .await
+    }
+
+    /// Retrieves all working orders, optionally restricted to one symbol.
+    ///
+    /// Fetches every page of `GET /v1/orders?status=INCOMPLETE`, which covers the NEW,
+    /// ACCEPTED and PARTIAL_FILLED orders in a single pass.
+    pub async fn get_open_orders(
+        &self,
+        creds: &impl AsCredentials,
+        symbol: Option<&str>,
+    ) -> Result<Vec<Order>> {
+        let params = GetOrdersParams {
+            symbol: symbol.map(str::to_string),
+            status: Some(OrderStatus::Incomplete),
+            ..Default::default()
+        };
+        self.get_all_orders(creds, Some(params)).await
+    }
+
     // ===== Account Information =====
 
     /// Get current account information for the specified user.
@@ -857,7 +2020,7 @@ impl OrderlyService {
     /// https://orderly.network/docs/build-on-omnichain/evm-api/restful-api/private/get-account-information#get-account-information
     pub async fn get_account_info(
         &self,
-        creds: &Credentials<'_>, // Added credentials parameter
+        creds: &impl AsCredentials, // Added credentials parameter
     ) -> Result<GetAccountInfoResponse> {
         let request = self
             .build_signed_request::<()>(creds, Method::GET, "/v1/client/info", None) // Pass creds
@@ -865,13 +2028,51 @@ impl OrderlyService {
         self.send_request::<GetAccountInfoResponse>(request).await
     }
 
+    /// Get the account mode (futures-only or unified) currently in effect.
+    /// GET /v1/client/account_mode
+    pub async fn get_account_mode(
+        &self,
+        creds: &impl AsCredentials,
+    ) -> Result<GetAccountModeResponse> {
+        let request = self
+            .build_signed_request::<()>(creds, Method::GET, "/v1/client/account_mode", None)
+            .await?;
+        self.send_request::<GetAccountModeResponse>(request).await
+    }
+
+    /// Switch the account between futures-only and unified mode.
+    /// POST /v1/client/account_mode
+    ///
+    /// Returns the effective account mode after the change. `AccountMode::Unknown` cannot
+    /// be requested and is rejected with a `ValidationError` before any request is sent.
+    pub async fn set_account_mode(
+        &self,
+        creds: &impl AsCredentials,
+        account_mode: AccountMode,
+    ) -> Result<SetAccountModeResponse> {
+        if account_mode == AccountMode::Unknown {
+            return Err(OrderlyError::ValidationError(
+                "account_mode must be FUTURES or UNIFIED".to_string(),
+            ));
+        }
+        let request = self
+            .build_signed_request(
+                creds,
+                Method::POST,
+                "/v1/client/account_mode",
+                Some(SetAccountModeRequest { account_mode }),
+            )
+            .await?;
+        self.send_request::<SetAccountModeResponse>(request).await
+    }
+
     // ===== Holdings / Balances =====
 
     /// Get current holdings (balances) for all tokens for the specified user.
     /// GET /v1/client/holding
     ///
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-holding
-    pub async fn get_holding(&self, creds: &Credentials<'_>) -> Result<GetHoldingResponse> {
+    pub async fn get_holding(&self, creds: &impl AsCredentials) -> Result<GetHoldingResponse> {
         // Added credentials parameter
         let request = self
             .build_signed_request::<()>(creds, Method::GET, "/v1/client/holding", None) // Pass creds
@@ -879,13 +2080,50 @@ impl OrderlyService {
         self.send_request::<GetHoldingResponse>(request).await
     }
 
+    /// Gets current holdings valued in USD, plus the portfolio total.
+    ///
+    /// USDC is valued at 1. Other tokens use the index price of their `PERP_{TOKEN}_USDC`
+    /// market from [`Self::get_futures_info`]; tokens without such a market are left
+    /// unvalued (see [`HoldingsValuation::unpriced_tokens`]). To value holdings against
+    /// another price source, pass them to [`value_holdings`] directly.
+    pub async fn get_holdings_with_valuation(
+        &self,
+        creds: &impl AsCredentials,
+    ) -> Result<HoldingsValuation> {
+        let holdings = self.get_holding(creds).await?.data.holding;
+        let futures = self.get_futures_info(None).await?;
+        let index_prices: HashMap<String, f64> = futures["data"]["rows"]
+            .as_array()
+            .map(|rows| {
+                rows.iter()
+                    .filter_map(|row| {
+                        Some((
+                            row["symbol"].as_str()?.to_string(),
+                            row["index_price"].as_f64()?,
+                        ))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(value_holdings(holdings, |token| {
+            if token.eq_ignore_ascii_case("USDC") {
+                Some(1.0)
+            } else {
+                index_prices
+                    .get(&format!("PERP_{}_USDC", token.to_ascii_uppercase()))
+                    .copied()
+            }
+        }))
+    }
+
     // ===== Positions =====
 
     /// Get all current positions for the specified user.
     /// GET /v1/positions
     ///
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-all-positions-info
-    pub async fn get_positions(&self, creds: &Credentials<'_>) -> Result<GetPositionsResponse> {
+    pub async fn get_positions(&self, creds: &impl AsCredentials) -> Result<GetPositionsResponse> {
         // Added credentials parameter
         let request = self
             .build_signed_request::<()>(creds, Method::GET, "/v1/positions", None) // Pass creds
@@ -893,13 +2131,43 @@ impl OrderlyService {
         self.send_request::<GetPositionsResponse>(request).await
     }
 
+    /// Get positions for several sub-accounts, keyed by sub-account id.
+    /// GET /v1/positions
+    ///
+    /// Each request is signed with `creds` but sent with the sub-account's id in the
+    /// `orderly-account-id` header, so `creds` must belong to the main account. At most
+    /// `MAX_CONCURRENT_SUBACCOUNT_REQUESTS` requests are in flight at once to stay within
+    /// rate limits. Fails with the first error encountered.
+    pub async fn get_positions_all_subaccounts(
+        &self,
+        creds: &impl AsCredentials,
+        sub_account_ids: &[&str],
+    ) -> Result<HashMap<String, GetPositionsResponse>> {
+        let mut positions = HashMap::with_capacity(sub_account_ids.len());
+        for batch in sub_account_ids.chunks(MAX_CONCURRENT_SUBACCOUNT_REQUESTS) {
+            let requests = batch.iter().map(|&sub_account_id| async move {
+                let sub_creds = Credentials {
+                    orderly_account_id: sub_account_id,
+                    ..creds.as_credentials()
+                };
+                let response = self.get_positions(&sub_creds).await?;
+                Ok::<_, OrderlyError>((sub_account_id.to_string(), response))
+            });
+            for result in futures_util::future::join_all(requests).await {
+                let (sub_account_id, response) = result?;
+                positions.insert(sub_account_id, response);
+            }
+        }
+        Ok(positions)
+    }
+
     /// Get position for a specific symbol for the specified user.
     /// GET /v1/position/{symbol}
     ///
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-one-position-info
     pub async fn get_position(
         &self,
-        creds: &Credentials<'_>,
+        creds: &impl AsCredentials,
         symbol: &str,
     ) -> Result<GetSinglePositionResponse> {
         // Added credentials parameter
@@ -911,6 +2179,33 @@ impl OrderlyService {
             .await
     }
 
+    /// Get closed position history for the specified user.
+    /// GET /v1/position_history
+    ///
+    /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-position-history
+    pub async fn get_position_history(
+        &self,
+        creds: &impl AsCredentials,
+        params: Option<GetPositionHistoryParams>,
+    ) -> Result<GetPositionHistoryResponse> {
+        let mut path = "/v1/position_history".to_string();
+        if let Some(p) = params {
+            if let Ok(query) = serde_qs::to_string(&p) {
+                if !query.is_empty() {
+                    path.push('?');
+                    path.push_str(&query);
+                }
+            } else {
+                warn!("Failed to serialize GetPositionHistoryParams to query string");
+            }
+        }
+        let request = self
+            .build_signed_request::<()>(creds, Method::GET, &path, None) // Pass creds
+            .await?;
+        self.send_request::<GetPositionHistoryResponse>(request)
+            .await
+    }
+
     // ===== Asset History (Deposits/Withdrawals) =====
 
     /// Get asset history (deposits, withdrawals) for the specified user.
@@ -919,7 +2214,7 @@ impl OrderlyService {
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-asset-history
     pub async fn get_asset_history(
         &self,
-        creds: &Credentials<'_>,
+        creds: &impl AsCredentials,
         params: Option<GetAssetHistoryParams>,
     ) -> Result<GetAssetHistoryResponse> {
         let mut path = "/v1/asset/history".to_string();
@@ -939,6 +2234,29 @@ impl OrderlyService {
         self.send_request::<GetAssetHistoryResponse>(request).await
     }
 
+    /// Retrieves the full asset history matching `params` across all pages.
+    ///
+    /// Calls `get_asset_history` repeatedly, starting at `params.page` (default 1), until the
+    /// pagination meta reports no further records or `MAX_AUTO_PAGES` pages were fetched.
+    pub async fn get_all_asset_history(
+        &self,
+        creds: &impl AsCredentials,
+        params: Option<GetAssetHistoryParams>,
+    ) -> Result<Vec<AssetHistoryEntry>> {
+        let params = params.unwrap_or_default();
+        collect_pages("get_all_asset_history", params.page.unwrap_or(1), |page| {
+            let params = GetAssetHistoryParams {
+                page: Some(page),
+                ..params.clone()
+            };
+            async move {
+                let response = self.get_asset_history(creds, Some(params)).await?;
+                Ok((response.data.rows, response.data.meta))
+            }
+        })
+        .await
+    }
+
     // ===== Trades =====
 
     /// Get trade history for the specified user.
@@ -947,7 +2265,7 @@ impl OrderlyService {
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-trades
     pub async fn get_trades(
         &self,
-        creds: &Credentials<'_>,
+        creds: &impl AsCredentials,
         params: Option<GetTradesParams>,
     ) -> Result<GetTradesResponse> {
         let mut path = "/v1/trades".to_string();
@@ -967,17 +2285,44 @@ impl OrderlyService {
         self.send_request::<GetTradesResponse>(request).await
     }
 
+    /// Retrieves all trades matching `params` across all pages.
+    ///
+    /// Calls `get_trades` repeatedly, starting at `params.page` (default 1), until the
+    /// pagination meta reports no further records or `MAX_AUTO_PAGES` pages were fetched.
+    pub async fn get_all_trades(
+        &self,
+        creds: &impl AsCredentials,
+        params: Option<GetTradesParams>,
+    ) -> Result<Vec<Trade>> {
+        let params = params.unwrap_or_default();
+        collect_pages("get_all_trades", params.page.unwrap_or(1), |page| {
+            let params = GetTradesParams {
+                page: Some(page),
+                ..params.clone()
+            };
+            async move {
+                let response = self.get_trades(creds, Some(params)).await?;
+                Ok((response.data.rows, response.data.meta))
+            }
+        })
+        .await
+    }
+
     /// Get specific trade by ID for the specified user.
     /// GET /v1/trade/{trade_id}
     ///
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-trade
-    pub async fn get_trade(&self, creds: &Credentials<'_>, trade_id: u64) -> Result<Value> {
+    pub async fn get_trade(
+        &self,
+        creds: &impl AsCredentials,
+        trade_id: u64,
+    ) -> Result<GetTradeResponse> {
         // Added credentials parameter
         let path = format!("/v1/trade/{}", trade_id);
         let request = self
             .build_signed_request::<()>(creds, Method::GET, &path, None) // Pass creds
             .await?;
-        self.send_request::<Value>(request).await
+        self.send_request::<GetTradeResponse>(request).await
     }
 
     // ===== Client Statistics =====
@@ -988,7 +2333,7 @@ impl OrderlyService {
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-client-statistics
     pub async fn get_client_statistics(
         &self,
-        creds: &Credentials<'_>, // Added credentials parameter
+        creds: &impl AsCredentials, // Added credentials parameter
     ) -> Result<GetClientStatisticsResponse> {
         let request = self
             .build_signed_request::<()>(creds, Method::GET, "/v1/client/statistics", None) // Pass creds
@@ -997,6 +2342,46 @@ impl OrderlyService {
             .await
     }
 
+    /// Get daily and aggregate trading volume between two timestamps.
+    /// GET /v1/volume/user/daily
+    ///
+    /// The endpoint works in whole UTC days, so the window is widened to the days containing
+    /// `start_t` and `end_t`.
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - Credentials of the account to query
+    /// * `start_t` - Start of the window, in ms
+    /// * `end_t` - End of the window, in ms; must not be before `start_t`
+    ///
+    /// https://orderly.network/docs/build-on-omnichain/evm-api/restful-api/private/get-user-daily-volume
+    pub async fn get_volume_stats(
+        &self,
+        creds: &impl AsCredentials,
+        start_t: u64,
+        end_t: u64,
+    ) -> Result<VolumeStats> {
+        if start_t > end_t {
+            return Err(OrderlyError::ValidationError(format!(
+                "start_t ({}) must not be after end_t ({})",
+                start_t, end_t
+            )));
+        }
+
+        let path = format!(
+            "/v1/volume/user/daily?start_date={}&end_date={}",
+            utc_date(start_t)?,
+            utc_date(end_t)?
+        );
+        let request = self
+            .build_signed_request::<()>(creds, Method::GET, &path, None)
+            .await?;
+        let response = self
+            .send_request::<GetUserDailyVolumeResponse>(request)
+            .await?;
+        Ok(VolumeStats::from_daily(start_t, end_t, response.data))
+    }
+
     // TODO: Implement endpoints for Liquidations, Fees, Settlement, Referrals, Broker, Delegate Signer, IP Restrictions etc.
 
     // ===== Withdrawals =====
@@ -1004,12 +2389,16 @@ impl OrderlyService {
     /// Request a withdrawal for the specified user.
     /// POST /v1/withdraw_request
     ///
+    /// The request is validated first (positive amount, non-empty address); invalid
+    /// requests fail with `ValidationError` without contacting the server.
+    ///
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/request-withdrawal
     pub async fn request_withdrawal(
         &self,
-        creds: &Credentials<'_>,
+        creds: &impl AsCredentials,
         withdraw_req: WithdrawRequest<'_>,
     ) -> Result<WithdrawResponse> {
+        validate_withdraw_request(&withdraw_req)?;
         let request = self
             .build_signed_request(
                 creds,
@@ -1018,22 +2407,342 @@ impl OrderlyService {
                 Some(withdraw_req),
             ) // Pass creds
             .await?;
+        if self.dry_run {
+            return Self::dry_run_response(
+                &request,
+                WithdrawResponseData {
+                    withdraw_id: Self::next_dry_run_id(),
+                },
+            );
+        }
+        self.send_request::<WithdrawResponse>(request).await
+    }
+
+    /// Withdraws to an EVM wallet, signing the withdrawal with the wallet's key.
+    /// POST /v1/withdraw_request
+    ///
+    /// Fetches a withdraw nonce, builds the EIP-712 `Withdraw` message, signs it with
+    /// `signer` and submits it. `params.receiver` is usually `signer.address()`.
+    ///
+    /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/request-withdrawal
+    pub async fn withdraw_evm(
+        &self,
+        creds: &impl AsCredentials,
+        signer: &EvmSigner,
+        params: EvmWithdrawParams<'_>,
+    ) -> Result<WithdrawResponse> {
+        let withdraw_nonce = self.get_withdraw_nonce(creds).await?;
+        let timestamp = get_timestamp_ms()?;
+        let message = create_evm_withdrawal_message(
+            params.broker_id,
+            params.chain_id.as_u64(),
+            params.receiver,
+            params.token,
+            params.amount,
+            withdraw_nonce,
+            timestamp,
+        )?;
+        let domain = Eip712Domain::orderly(params.chain_id.as_u64(), params.verifying_contract);
+        let signature = signer.sign_digest(&message.eip712_digest(&domain)?);
+
+        let body = serde_json::json!({
+            "message": message,
+            "signature": signature,
+            "userAddress": signer.address(),
+            "verifyingContract": params.verifying_contract,
+        });
+        let request = self
+            .build_signed_request(creds, Method::POST, "/v1/withdraw_request", Some(body))
+            .await?;
+        if self.dry_run {
+            return Self::dry_run_response(
+                &request,
+                WithdrawResponseData {
+                    withdraw_id: Self::next_dry_run_id(),
+                },
+            );
+        }
         self.send_request::<WithdrawResponse>(request).await
     }
     // Note: Withdrawal history fetched via get_asset_history
 
+    /// Polls asset history until the withdrawal `withdraw_id` reaches a terminal state.
+    ///
+    /// Returns the matching `AssetHistoryEntry` once its `trans_status` is `COMPLETED` or
+    /// `FAILED`, or `OrderlyError::Timeout` if that does not happen within `timeout`.
+    /// Every 5 seconds the withdrawal history is fetched page by page until the withdrawal
+    /// is found, so it is found even when newer transactions push it past the first page.
+    /// Once found, later polls only query history from its `created_time` onwards.
+    pub async fn wait_for_withdrawal(
+        &self,
+        creds: &impl AsCredentials,
+        withdraw_id: u64,
+        timeout: Duration,
+    ) -> Result<AssetHistoryEntry> {
+        let deadline = Instant::now() + timeout;
+        let id = withdraw_id.to_string();
+        let mut params = GetAssetHistoryParams {
+            side: Some(AssetHistoryType::Withdrawal),
+            ..Default::default()
+        };
+        loop {
+            if let Some(entry) = self.find_asset_history_entry(creds, &params, &id).await? {
+                if entry.is_terminal() {
+                    return Ok(entry);
+                }
+                params.start_t = Some(entry.created_time);
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(OrderlyError::Timeout(format!(
+                    "withdrawal {} did not complete within {:?}",
+                    withdraw_id, timeout
+                )));
+            }
+            tokio::time::sleep(WITHDRAWAL_POLL_INTERVAL.min(deadline - now)).await;
+        }
+    }
+
+    /// Fetches asset history matching `params` page by page until the entry `id` is found.
+    ///
+    /// Stops at the last page, or after `MAX_AUTO_PAGES` pages, returning `None`.
+    async fn find_asset_history_entry(
+        &self,
+        creds: &impl AsCredentials,
+        params: &GetAssetHistoryParams,
+        id: &str,
+    ) -> Result<Option<AssetHistoryEntry>> {
+        let mut page = 1;
+        for _ in 0..MAX_AUTO_PAGES {
+            let params = GetAssetHistoryParams {
+                page: Some(page),
+                ..params.clone()
+            };
+            let data = self.get_asset_history(creds, Some(params)).await?.data;
+            let fetched_any = !data.rows.is_empty();
+            if let Some(entry) = data.rows.into_iter().find(|e| e.id == id) {
+                return Ok(Some(entry));
+            }
+            match data.meta {
+                Some(meta) if fetched_any && meta.has_more() => page = meta.current_page + 1,
+                _ => return Ok(None),
+            }
+        }
+        Ok(None)
+    }
+
+    // ===== Convert =====
+
+    /// Get a quote for converting `amount` of `from` into `to`.
+    /// GET /v1/convert/quote
+    ///
+    /// The quote carries a `rate` and an `expire_time`; pass its `quote_id` to
+    /// [`execute_convert`](Self::execute_convert) before it expires.
+    pub async fn get_convert_quote(
+        &self,
+        creds: &impl AsCredentials,
+        from: &str,
+        to: &str,
+        amount: f64,
+    ) -> Result<GetConvertQuoteResponse> {
+        if !amount.is_finite() || amount <= 0.0 {
+            return Err(OrderlyError::ValidationError(format!(
+                "Convert amount must be positive, got {}",
+                amount
+            )));
+        }
+        if from.eq_ignore_ascii_case(to) {
+            return Err(OrderlyError::ValidationError(format!(
+                "Cannot convert {} into itself",
+                from
+            )));
+        }
+
+        let query = ConvertQuoteRequest {
+            from_token: from,
+            to_token: to,
+            amount,
+        };
+        let query_string = serde_qs::to_string(&query).map_err(|e| {
+            OrderlyError::ValidationError(format!("Failed to serialize params: {}", e))
+        })?;
+        let path = format!("/v1/convert/quote?{}", query_string);
+        let request = self
+            .build_signed_request::<()>(creds, Method::GET, &path, None)
+            .await?;
+        self.send_request::<GetConvertQuoteResponse>(request).await
+    }
+
+    /// Execute a conversion previously quoted by [`get_convert_quote`](Self::get_convert_quote).
+    /// POST /v1/convert
+    pub async fn execute_convert(
+        &self,
+        creds: &impl AsCredentials,
+        quote_id: &str,
+    ) -> Result<ExecuteConvertResponse> {
+        if quote_id.is_empty() {
+            return Err(OrderlyError::ValidationError(
+                "quote_id must not be empty".to_string(),
+            ));
+        }
+        let request = self
+            .build_signed_request(
+                creds,
+                Method::POST,
+                "/v1/convert",
+                Some(ExecuteConvertRequest { quote_id }),
+            )
+            .await?;
+        self.send_request::<ExecuteConvertResponse>(request).await
+    }
+
+    // ===== Leverage / Margin Mode =====
+
+    /// Get the leverage and margin mode in effect for `symbol`.
+    /// GET /v1/client/leverage
+    ///
+    /// https://orderly.network/docs/build-on-omnichain/evm-api/restful-api/private/get-leverage-setting
+    pub async fn get_symbol_leverage(
+        &self,
+        creds: &impl AsCredentials,
+        symbol: &str,
+    ) -> Result<GetSymbolLeverageResponse> {
+        let path = format!("/v1/client/leverage?symbol={}", symbol);
+        let request = self
+            .build_signed_request::<()>(creds, Method::GET, &path, None)
+            .await?;
+        self.send_request::<GetSymbolLeverageResponse>(request)
+            .await
+    }
+
+    /// Switch `symbol` between cross and isolated margin.
+    /// POST /v1/client/margin_mode
+    ///
+    /// Returns the effective leverage and margin mode after the change.
+    pub async fn set_symbol_margin_mode(
+        &self,
+        creds: &impl AsCredentials,
+        symbol: &str,
+        margin_mode: MarginMode,
+    ) -> Result<SetSymbolMarginModeResponse> {
+        let request = self
+            .build_signed_request(
+                creds,
+                Method::POST,
+                "/v1/client/margin_mode",
+                Some(SetSymbolMarginModeRequest {
+                    symbol,
+                    margin_mode,
+                }),
+            )
+            .await?;
+        self.send_request::<SetSymbolMarginModeResponse>(request)
+            .await
+    }
+
     // ===== Fee Rates =====
 
     /// Get current fee rates for the specified user.
     /// GET /v1/client/fee_rates
     ///
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-fee-rates
-    pub async fn get_fee_rates(&self, creds: &Credentials<'_>) -> Result<GetFeeRatesResponse> {
+    pub async fn get_fee_rates(&self, creds: &impl AsCredentials) -> Result<GetFeeRatesResponse> {
         // Added credentials parameter
         let request = self
-            .build_signed_request::<()>(creds, Method::GET, "/v1/client/fee_rates", None) // Pass creds
+            .build_signed_request::<()>(creds, Method::GET, "/v1/client/fee_rates", None) // Pass creds
+            .await?;
+        self.send_request::<GetFeeRatesResponse>(request).await
+    }
+
+    /// Get fee rates for the specified user, reusing a cached response if it is younger than `ttl`.
+    ///
+    /// Responses are cached per account id and shared between clones of this service.
+    /// Errors are never cached; a failed refresh leaves the previous entry in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - Credentials of the account to fetch fee rates for
+    /// * `ttl` - Maximum age of a cached response before it is fetched again
+    pub async fn get_fee_rates_cached(
+        &self,
+        creds: &impl AsCredentials,
+        ttl: Duration,
+    ) -> Result<GetFeeRatesResponse> {
+        let account_id = creds.as_credentials().orderly_account_id;
+        if let Some((fetched_at, cached)) = self
+            .fee_rates_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(account_id)
+        {
+            if fetched_at.elapsed() < ttl {
+                return Ok(cached.clone());
+            }
+        }
+
+        let response = self.get_fee_rates(creds).await?;
+        self.fee_rates_cache
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(account_id.to_string(), (Instant::now(), response.clone()));
+        Ok(response)
+    }
+
+    // ===== Klines =====
+
+    /// Get the latest candlesticks for a symbol.
+    /// GET /v1/kline
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - Credentials used to sign the request
+    /// * `symbol` - The trading pair symbol (e.g., "PERP_ETH_USDC")
+    /// * `interval` - Candlestick interval
+    /// * `limit` - Maximum number of candles to return (server default if `None`)
+    ///
+    /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-kline
+    pub async fn get_kline(
+        &self,
+        creds: &impl AsCredentials,
+        symbol: &str,
+        interval: KlineInterval,
+        limit: Option<u32>,
+    ) -> Result<GetKlineResponse> {
+        let mut path = format!("/v1/kline?symbol={}&type={}", symbol, interval.as_str());
+        if let Some(limit) = limit {
+            path.push_str(&format!("&limit={}", limit));
+        }
+        let request = self
+            .build_signed_request::<()>(creds, Method::GET, &path, None)
+            .await?;
+        self.send_request::<GetKlineResponse>(request).await
+    }
+
+    // ===== Trading Rewards =====
+
+    /// Get the rewards distributed to the specified user.
+    /// GET /v1/client/distribution_history
+    pub async fn get_reward_history(
+        &self,
+        creds: &impl AsCredentials,
+        params: Option<GetRewardHistoryParams>,
+    ) -> Result<GetRewardHistoryResponse> {
+        let mut path = "/v1/client/distribution_history".to_string();
+        if let Some(p) = params {
+            if let Ok(query) = serde_qs::to_string(&p) {
+                if !query.is_empty() {
+                    path.push('?');
+                    path.push_str(&query);
+                }
+            } else {
+                warn!("Failed to serialize GetRewardHistoryParams to query string");
+            }
+        }
+        let request = self
+            .build_signed_request::<()>(creds, Method::GET, &path, None)
             .await?;
-        self.send_request::<GetFeeRatesResponse>(request).await
+        self.send_request::<GetRewardHistoryResponse>(request).await
     }
 
     // ===== Liquidations =====
@@ -1044,7 +2753,7 @@ impl OrderlyService {
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-liquidations
     pub async fn get_liquidations(
         &self,
-        creds: &Credentials<'_>, // Added credentials parameter
+        creds: &impl AsCredentials, // Added credentials parameter
         params: Option<GetLiquidationsParams>,
     ) -> Result<GetLiquidationsResponse> {
         let mut path = "/v1/liquidations".to_string();
@@ -1064,6 +2773,29 @@ impl OrderlyService {
         self.send_request::<GetLiquidationsResponse>(request).await
     }
 
+    /// Retrieves all liquidations matching `params` across all pages.
+    ///
+    /// Calls `get_liquidations` repeatedly, starting at `params.page` (default 1), until the
+    /// pagination meta reports no further records or `MAX_AUTO_PAGES` pages were fetched.
+    pub async fn get_all_liquidations(
+        &self,
+        creds: &impl AsCredentials,
+        params: Option<GetLiquidationsParams>,
+    ) -> Result<Vec<LiquidationEntry>> {
+        let params = params.unwrap_or_default();
+        collect_pages("get_all_liquidations", params.page.unwrap_or(1), |page| {
+            let params = GetLiquidationsParams {
+                page: Some(page),
+                ..params.clone()
+            };
+            async move {
+                let response = self.get_liquidations(creds, Some(params)).await?;
+                Ok((response.data.rows, response.data.meta))
+            }
+        })
+        .await
+    }
+
     // ===== PnL Settlement =====
 
     /// Get PnL settlement history for the specified user.
@@ -1072,7 +2804,7 @@ impl OrderlyService {
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-settlement-history
     pub async fn get_settlement_history(
         &self,
-        creds: &Credentials<'_>, // Added credentials parameter
+        creds: &impl AsCredentials, // Added credentials parameter
         params: Option<GetSettlementsParams>,
     ) -> Result<GetSettlementsResponse> {
         let mut path = "/v1/settlements".to_string();
@@ -1100,7 +2832,7 @@ impl OrderlyService {
     /// https://orderly.network/docs/build-on-evm/evm-api/restful-api/private/get-funding-fee-history
     pub async fn get_funding_fee_history(
         &self,
-        creds: &Credentials<'_>, // Added credentials parameter
+        creds: &impl AsCredentials, // Added credentials parameter
         symbol: &str,
         params: Option<GetFundingFeeParams>,
     ) -> Result<GetFundingFeeHistoryResponse> {
@@ -1162,6 +2894,8 @@ impl OrderlyService {
     ///         trigger_price: 50000.0,
     ///         limit_price: None,
     ///         trailing_delta: None,
+    ///         trailing_type: None,
+    ///         activation_price: None,
     ///         client_order_id: Some("my_stop_loss_1".to_string()),
     ///         reduce_only: Some(true),
     ///     };
@@ -1176,7 +2910,7 @@ impl OrderlyService {
     /// ```
     pub async fn create_algo_order(
         &self,
-        creds: &Credentials<'_>,
+        creds: &impl AsCredentials,
         request: CreateAlgoOrderRequest,
     ) -> Result<SuccessResponse<AlgoOrderDetails>, OrderlyError> {
         // Validate request
@@ -1202,6 +2936,30 @@ impl OrderlyService {
                 ));
             }
         }
+        if request.order_type == AlgoOrderType::TrailingStop {
+            let delta = request.trailing_delta.ok_or_else(|| {
+                OrderlyError::ValidationError(
+                    "Trailing delta is required for trailing stop orders".to_string(),
+                )
+            })?;
+            let valid = match request.trailing_type.unwrap_or(TrailingType::Absolute) {
+                TrailingType::Percentage => delta > 0.0 && delta < 100.0,
+                TrailingType::Absolute => delta > 0.0,
+            };
+            if !valid {
+                return Err(OrderlyError::ValidationError(format!(
+                    "Invalid trailing delta {} for trailing type {:?}",
+                    delta, request.trailing_type
+                )));
+            }
+        }
+        if let Some(activation_price) = request.activation_price {
+            if activation_price <= 0.0 {
+                return Err(OrderlyError::ValidationError(
+                    "Activation price must be positive if provided".to_string(),
+                ));
+            }
+        }
 
         // Build path
         let path = "/v1/algo-order";
@@ -1210,12 +2968,279 @@ impl OrderlyService {
         let signed_request = self
             .build_signed_request(creds, Method::POST, path, Some(&request))
             .await?;
+        if self.dry_run {
+            let now = get_timestamp_ms()? as i64;
+            return Self::dry_run_response(
+                &signed_request,
+                AlgoOrderDetails {
+                    algo_order_id: Self::next_dry_run_id().to_string(),
+                    client_order_id: request.client_order_id,
+                    symbol: request.symbol,
+                    order_type: request.order_type,
+                    side: request.side,
+                    quantity: request.quantity,
+                    trigger_price: request.trigger_price,
+                    limit_price: request.limit_price,
+                    trailing_delta: request.trailing_delta,
+                    status: OrderStatus::New,
+                    reduce_only: request.reduce_only.unwrap_or(false),
+                    triggered_order_id: None,
+                    created_time: now,
+                    updated_time: now,
+                },
+            );
+        }
 
         // Send request and handle response
         self.send_request::<SuccessResponse<AlgoOrderDetails>>(signed_request)
             .await
     }
 
+    /// Creates a linked take-profit and stop-loss pair (OCO).
+    /// POST /v1/algo-order with order type `TP_SL`
+    ///
+    /// The server cancels the remaining leg once one of them fills, so there is no
+    /// need to monitor and cancel the other order manually.
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - Credentials for authentication
+    /// * `request` - Symbol, closing side, quantity and the trigger (and optional limit)
+    ///   price of each leg
+    ///
+    /// # Returns
+    ///
+    /// A `SuccessResponse` whose rows hold the parent and child algo order IDs, or a
+    /// `ValidationError` if the triggers are on the wrong side of each other for `side`
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use orderly_connector_rs::rest::client::{OrderlyService, Credentials};
+    /// use orderly_connector_rs::types::{BracketOrderRequest, Side};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let creds = Credentials {
+    ///         orderly_key: "your_api_key",
+    ///         orderly_secret: "your_api_secret",
+    ///         orderly_account_id: "your_account_id",
+    ///     };
+    ///     let service = OrderlyService::new(true, None)?;
+    ///
+    ///     // Protect a 0.1 BTC long
+    ///     let request = BracketOrderRequest {
+    ///         symbol: "PERP_BTC_USDC".to_string(),
+    ///         side: Side::Sell,
+    ///         quantity: 0.1,
+    ///         take_profit_trigger: 55000.0,
+    ///         take_profit_limit: None,
+    ///         stop_loss_trigger: 48000.0,
+    ///         stop_loss_limit: None,
+    ///         client_order_id: None,
+    ///     };
+    ///
+    ///     let response = service.create_bracket_order(&creds, request).await?;
+    ///     println!("Stop loss leg: {:?}", response.data.stop_loss_order_id());
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn create_bracket_order(
+        &self,
+        creds: &impl AsCredentials,
+        request: BracketOrderRequest,
+    ) -> Result<SuccessResponse<BracketOrderResponse>, OrderlyError> {
+        // Validate request
+        if request.symbol.is_empty() {
+            return Err(OrderlyError::ValidationError(
+                "Symbol is required".to_string(),
+            ));
+        }
+        if request.quantity <= 0.0 {
+            return Err(OrderlyError::ValidationError(
+                "Quantity must be positive".to_string(),
+            ));
+        }
+        if request.take_profit_trigger <= 0.0 || request.stop_loss_trigger <= 0.0 {
+            return Err(OrderlyError::ValidationError(
+                "Trigger prices must be positive".to_string(),
+            ));
+        }
+        let triggers_ordered = match request.side {
+            Side::Sell => request.take_profit_trigger > request.stop_loss_trigger,
+            Side::Buy => request.take_profit_trigger < request.stop_loss_trigger,
+        };
+        if !triggers_ordered {
+            return Err(OrderlyError::ValidationError(format!(
+                "Take profit trigger {} and stop loss trigger {} are on the wrong side of each other for a {:?} bracket",
+                request.take_profit_trigger, request.stop_loss_trigger, request.side
+            )));
+        }
+
+        #[derive(Serialize)]
+        struct ChildOrder<'a> {
+            symbol: &'a str,
+            order_type: AlgoOrderType,
+            side: Side,
+            trigger_price: f64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            limit_price: Option<f64>,
+            reduce_only: bool,
+        }
+
+        #[derive(Serialize)]
+        struct BracketOrderBody<'a> {
+            symbol: &'a str,
+            order_type: AlgoOrderType,
+            quantity: f64,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            client_order_id: Option<&'a str>,
+            child_orders: [ChildOrder<'a>; 2],
+        }
+
+        let take_profit_type = match request.take_profit_limit {
+            Some(_) => AlgoOrderType::TakeProfitLimit,
+            None => AlgoOrderType::TakeProfitMarket,
+        };
+        let stop_loss_type = match request.stop_loss_limit {
+            Some(_) => AlgoOrderType::StopLimit,
+            None => AlgoOrderType::StopMarket,
+        };
+        let take_profit = ChildOrder {
+            symbol: &request.symbol,
+            order_type: take_profit_type.clone(),
+            side: request.side.clone(),
+            trigger_price: request.take_profit_trigger,
+            limit_price: request.take_profit_limit,
+            reduce_only: true,
+        };
+        let stop_loss = ChildOrder {
+            symbol: &request.symbol,
+            order_type: stop_loss_type.clone(),
+            side: request.side.clone(),
+            trigger_price: request.stop_loss_trigger,
+            limit_price: request.stop_loss_limit,
+            reduce_only: true,
+        };
+        let body = BracketOrderBody {
+            symbol: &request.symbol,
+            order_type: AlgoOrderType::TpSl,
+            quantity: request.quantity,
+            client_order_id: request.client_order_id.as_deref(),
+            child_orders: [take_profit, stop_loss],
+        };
+
+        // Build signed request
+        let signed_request = self
+            .build_signed_request(creds, Method::POST, "/v1/algo-order", Some(body))
+            .await?;
+        if self.dry_run {
+            let rows = [
+                (AlgoOrderType::TpSl, request.client_order_id),
+                (take_profit_type, None),
+                (stop_loss_type, None),
+            ]
+            .into_iter()
+            .map(|(order_type, client_order_id)| BracketOrderRow {
+                algo_order_id: Self::next_dry_run_id().to_string(),
+                client_order_id,
+                order_type,
+            })
+            .collect();
+            return Self::dry_run_response(&signed_request, BracketOrderResponse { rows });
+        }
+
+        // Send request and handle response
+        self.send_request::<SuccessResponse<BracketOrderResponse>>(signed_request)
+            .await
+    }
+
+    /// Amends a working algorithmic order in place.
+    /// PUT /v1/algo-order
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - Credentials for authentication
+    /// * `algo_order_id` - ID of the algo order to edit; must be numeric
+    /// * `request` - The fields to change; unset fields are left as they are
+    ///
+    /// # Returns
+    ///
+    /// A `SuccessResponse` containing the details of the edited algo order or an error
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use orderly_connector_rs::rest::client::{OrderlyService, Credentials};
+    /// use orderly_connector_rs::types::EditAlgoOrderRequest;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let creds = Credentials {
+    ///         orderly_key: "your_api_key",
+    ///         orderly_secret: "your_api_secret",
+    ///         orderly_account_id: "your_account_id",
+    ///     };
+    ///     let service = OrderlyService::new(true, None)?;
+    ///
+    ///     // Tighten a stop loss
+    ///     let request = EditAlgoOrderRequest {
+    ///         trigger_price: Some(51000.0),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     match service.edit_algo_order(&creds, "123456", request).await {
+    ///         Ok(response) => println!("Edited algo order: {:?}", response.data),
+    ///         Err(e) => println!("Failed to edit algo order: {}", e),
+    ///     }
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn edit_algo_order(
+        &self,
+        creds: &impl AsCredentials,
+        algo_order_id: &str,
+        request: EditAlgoOrderRequest,
+    ) -> Result<SuccessResponse<AlgoOrderDetails>, OrderlyError> {
+        // Validate inputs
+        if algo_order_id.is_empty() {
+            return Err(OrderlyError::ValidationError(
+                "Algo order ID is required".to_string(),
+            ));
+        }
+        // The edit body carries the ID as a number
+        let order_id = algo_order_id.parse::<u64>().map_err(|_| {
+            OrderlyError::ValidationError(format!("Invalid algo order ID: {}", algo_order_id))
+        })?;
+        if request.is_empty() {
+            return Err(OrderlyError::ValidationError(
+                "At least one field must be changed".to_string(),
+            ));
+        }
+
+        #[derive(Serialize)]
+        struct EditAlgoOrderBody<'a> {
+            order_id: u64,
+            #[serde(flatten)]
+            request: &'a EditAlgoOrderRequest,
+        }
+
+        // Build signed request
+        let body = EditAlgoOrderBody {
+            order_id,
+            request: &request,
+        };
+        let request = self
+            .build_signed_request(creds, Method::PUT, "/v1/algo-order", Some(body))
+            .await?;
+
+        // Send request and handle response
+        self.send_request::<SuccessResponse<AlgoOrderDetails>>(request)
+            .await
+    }
+
     /// Cancels an existing algorithmic order
     ///
     /// # Arguments
@@ -1253,7 +3278,7 @@ impl OrderlyService {
     /// ```
     pub async fn cancel_algo_order(
         &self,
-        creds: &Credentials<'_>,
+        creds: &impl AsCredentials,
         symbol: &str,
         algo_order_id: &str,
     ) -> Result<SuccessResponse<AlgoOrderDetails>, OrderlyError> {
@@ -1319,7 +3344,7 @@ impl OrderlyService {
     /// ```
     pub async fn cancel_algo_order_by_client_id(
         &self,
-        creds: &Credentials<'_>,
+        creds: &impl AsCredentials,
         symbol: &str,
         client_order_id: &str,
     ) -> Result<SuccessResponse<AlgoOrderDetails>, OrderlyError> {
@@ -1398,7 +3423,7 @@ impl OrderlyService {
     /// ```
     pub async fn get_algo_orders(
         &self,
-        creds: &Credentials<'_>,
+        creds: &impl AsCredentials,
         params: GetAlgoOrdersParams,
     ) -> Result<SuccessResponse<GetAlgoOrdersResponse>, OrderlyError> {
         // Convert params to query string
@@ -1437,13 +3462,73 @@ impl OrderlyService {
     /// [Orderly API docs](https://orderly.network/docs/build-on-omnichain/evm-api/restful-api/private/orderbook-snapshot)
     pub async fn get_orderbook_snapshot(
         &self,
-        creds: &Credentials<'_>,
+        creds: &impl AsCredentials,
         symbol: &str,
         max_level: Option<u32>,
     ) -> Result<GetOrderbookSnapshotResponse> {
-        let mut path = format!("/v1/orderbook/{}", symbol);
+        self.get_orderbook(creds, symbol, max_level, None).await
+    }
+
+    /// Gets the orderbook snapshot for a symbol with normalized levels.
+    ///
+    /// Like [`Self::get_orderbook_snapshot`], but asks are sorted ascending and bids
+    /// descending by price, with zero-quantity and duplicate levels removed (see
+    /// [`OrderbookSnapshotData::normalize`]). The API does not document its level order,
+    /// so use this when computing spreads or checking for a crossed book.
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - Credentials for authentication
+    /// * `symbol` - The trading pair symbol (e.g., "PERP_ETH_USDC")
+    /// * `max_level` - Optional: the number of levels to request on both sides
+    pub async fn get_normalized_orderbook_snapshot(
+        &self,
+        creds: &impl AsCredentials,
+        symbol: &str,
+        max_level: Option<u32>,
+    ) -> Result<GetOrderbookSnapshotResponse> {
+        let mut response = self
+            .get_orderbook_snapshot(creds, symbol, max_level)
+            .await?;
+        response.data.normalize();
+        Ok(response)
+    }
+
+    /// Gets the orderbook snapshot for a symbol, optionally aggregated to a coarser price step.
+    ///
+    /// # Arguments
+    ///
+    /// * `creds` - Credentials for authentication
+    /// * `symbol` - The trading pair symbol (e.g., "PERP_ETH_USDC")
+    /// * `max_level` - Optional: the number of levels to show on both sides
+    /// * `aggregation` - Optional: price step to group levels into, e.g. `1.0` for $1
+    ///   increments. Should be a multiple of the symbol's `quote_tick`.
+    ///
+    /// [Orderly API docs](https://orderly.network/docs/build-on-omnichain/evm-api/restful-api/private/orderbook-snapshot)
+    pub async fn get_orderbook(
+        &self,
+        creds: &impl AsCredentials,
+        symbol: &str,
+        max_level: Option<u32>,
+        aggregation: Option<f64>,
+    ) -> Result<GetOrderbookSnapshotResponse> {
+        let mut query = Vec::new();
         if let Some(level) = max_level {
-            path.push_str(&format!("?max_level={}", level));
+            query.push(format!("max_level={}", level));
+        }
+        if let Some(step) = aggregation {
+            if !step.is_finite() || step <= 0.0 {
+                return Err(OrderlyError::ValidationError(format!(
+                    "Orderbook aggregation must be positive, got {}",
+                    step
+                )));
+            }
+            query.push(format!("aggregation={}", step));
+        }
+        let mut path = format!("/v1/orderbook/{}", symbol);
+        if !query.is_empty() {
+            path.push('?');
+            path.push_str(&query.join("&"));
         }
         let request = self
             .build_signed_request::<()>(creds, Method::GET, &path, None)
@@ -1470,13 +3555,38 @@ impl OrderlyService {
             .build()?; // Propagates reqwest::Error
 
         // Use the existing response handler
-        Self::handle_response(self.http_client.execute(request).await?).await
+        self.send_public_request(request).await
+    }
+
+    /// Look up the Orderly account id registered for a wallet address under `broker_id`.
+    /// GET /v1/get_account
+    ///
+    /// `0x`-prefixed addresses are looked up as EVM wallets, anything else as Solana wallets.
+    pub async fn get_account_id(&self, address: &str, broker_id: &str) -> Result<String> {
+        if address.trim().is_empty() || broker_id.trim().is_empty() {
+            return Err(OrderlyError::ValidationError(
+                "address and broker_id must not be empty".to_string(),
+            ));
+        }
+        let chain_type = if address.starts_with("0x") {
+            "EVM"
+        } else {
+            "SOL"
+        };
+        let path = format!(
+            "/v1/get_account?address={}&broker_id={}&chain_type={}",
+            address, broker_id, chain_type
+        );
+        let url = self.base_url.join(&path)?;
+        let request = self.http_client.get(url).build()?;
+        let response: GetAccountIdResponse = self.send_public_request(request).await?;
+        Ok(response.data.account_id)
     }
 
     /// Registers a Solana account with Orderly Network.
     ///
     /// This function performs the off-chain registration process:
-    /// 1. Checks if the wallet is already registered.
+    /// 1. Checks if the wallet is already registered, and if so returns its existing account ID.
     /// 2. Fetches a unique registration nonce.
     /// 3. Creates and signs an EIP-712 compliant registration message using the provided keypair.
     /// 4. Submits the registration request to the Orderly API.
@@ -1489,12 +3599,12 @@ impl OrderlyService {
     /// # Returns
     ///
     /// A `Result` containing the Orderly Account ID (`String`) upon successful registration,
-    /// or an `OrderlyError` if any step fails.
+    /// or the existing account ID if the wallet was already registered, so repeated calls are
+    /// safe.
     ///
     /// # Errors
     ///
-    /// Returns `OrderlyError::RegistrationNotRequired` if the account is already registered.
-    /// Returns other `OrderlyError` variants for API errors, signing issues, etc.
+    /// Returns `OrderlyError` variants for API errors, signing issues, etc.
     pub async fn register_solana_account(
         &self,
         solana_config: &SolanaConfig,
@@ -1514,10 +3624,13 @@ impl OrderlyService {
         if check_resp.success {
             if let Some(data) = check_resp.data {
                 if data.is_registered {
-                    info!("Address {} is already registered.", user_address);
-                    // TODO: Decide if we should fetch the account ID here or just signal it's done.
-                    // For now, returning an error indicating no action needed.
-                    return Err(OrderlyError::RegistrationNotRequired(user_address));
+                    info!(
+                        "Address {} is already registered; looking up its account ID.",
+                        user_address
+                    );
+                    return self
+                        .get_account_id(&user_address, &solana_config.broker_id)
+                        .await;
                 }
             }
         } else {
@@ -1558,7 +3671,7 @@ impl OrderlyService {
 
         let message_to_sign = create_registration_message(
             &solana_config.broker_id,
-            solana_config.orderly_solana_chain_id,
+            solana_config.orderly_solana_chain_id.as_u64(),
             timestamp,
             registration_nonce,
         )?;
@@ -1605,7 +3718,7 @@ impl OrderlyService {
     }
 
     /// Fetches the withdrawal nonce for the current user (required for withdrawal message signing).
-    pub async fn get_withdraw_nonce(&self, creds: &Credentials<'_>) -> Result<u64> {
+    pub async fn get_withdraw_nonce(&self, creds: &impl AsCredentials) -> Result<u64> {
         let request = self
             .build_signed_request::<()>(creds, Method::GET, "/v1/withdraw_nonce", None)
             .await?;
@@ -1631,7 +3744,7 @@ impl OrderlyService {
     /// https://orderly.network/docs/build-on-omnichain/evm-api/restful-api/private/get-builders-users-volumes
     pub async fn get_broker_volume(
         &self,
-        creds: &Credentials<'_>,
+        creds: &impl AsCredentials,
         params: GetBrokerVolumeParams,
     ) -> Result<GetBrokerVolumeResponse> {
         let mut path = "/v1/volume/broker/daily".to_string();
@@ -1665,6 +3778,171 @@ pub struct SymbolInfo {
     pub updated_time: u64,
     pub imr_factor: Option<f64>,
     pub liquidation_fee: Option<f64>,
+    /// Minimum initial margin rate of the symbol
+    pub base_imr: Option<f64>,
+}
+
+/// Returns true if `value` is a whole number of `tick`s, allowing for float rounding error.
+fn is_tick_multiple(value: f64, tick: f64) -> bool {
+    if tick <= 0.0 {
+        return true;
+    }
+    let steps = value / tick;
+    (steps - steps.round()).abs() < 1e-6
+}
+
+/// Number of decimal places needed to represent `tick` exactly (capped at 12).
+fn tick_decimals(tick: f64) -> i32 {
+    (0..12)
+        .find(|&d| {
+            let scaled = tick * 10f64.powi(d);
+            (scaled - scaled.round()).abs() < 1e-9 * scaled.max(1.0)
+        })
+        .unwrap_or(12)
+}
+
+/// Rounds `value` down to the nearest multiple of `tick`.
+///
+/// Values that are already a multiple up to float error (e.g. `0.3` with tick `0.1`) are
+/// kept rather than dropped a full tick, and the result is snapped to the tick's number of
+/// decimals so `round_to_tick(2000.019, 0.01)` yields exactly `2000.01`. A non-positive or
+/// non-finite `tick` leaves `value` unchanged.
+pub fn round_to_tick(value: f64, tick: f64) -> f64 {
+    if !tick.is_finite() || tick <= 0.0 {
+        return value;
+    }
+    let steps = (value / tick + 1e-9).floor();
+    let scale = 10f64.powi(tick_decimals(tick));
+    (steps * tick * scale).round() / scale
+}
+
+/// Rounds a quantity down to the nearest multiple of the lot size `lot`.
+///
+/// Same semantics as [`round_to_tick`]; rounding down keeps an order within the size the
+/// caller asked for.
+pub fn round_to_lot(qty: f64, lot: f64) -> f64 {
+    round_to_tick(qty, lot)
+}
+
+impl SymbolInfo {
+    /// Rounds `price` down to this symbol's `quote_tick`.
+    pub fn round_price(&self, price: f64) -> f64 {
+        round_to_tick(price, self.quote_tick)
+    }
+
+    /// Rounds `quantity` down to this symbol's `base_tick`.
+    pub fn round_quantity(&self, quantity: f64) -> f64 {
+        round_to_lot(quantity, self.base_tick)
+    }
+
+    /// Checks an order against this symbol's price tick, quantity tick and bounds,
+    /// and minimum notional.
+    ///
+    /// The notional is `order_price * order_quantity` when both are set, otherwise
+    /// `order_amount` if set. Checks on fields the order leaves unset are skipped.
+    ///
+    /// # Returns
+    ///
+    /// `OrderlyError::ValidationError` describing the first rule the order violates.
+    pub fn validate_order(&self, order: &CreateOrderRequest) -> Result<()> {
+        if order.symbol != self.symbol {
+            return Err(OrderlyError::ValidationError(format!(
+                "Order symbol {} does not match symbol info for {}",
+                order.symbol, self.symbol
+            )));
+        }
+
+        if let Some(price) = order.order_price {
+            if !is_tick_multiple(price, self.quote_tick) {
+                return Err(OrderlyError::ValidationError(format!(
+                    "Price {} is not a multiple of quote_tick {}",
+                    price, self.quote_tick
+                )));
+            }
+        }
+
+        if let Some(quantity) = order.order_quantity {
+            if !is_tick_multiple(quantity, self.base_tick) {
+                return Err(OrderlyError::ValidationError(format!(
+                    "Quantity {} is not a multiple of base_tick {}",
+                    quantity, self.base_tick
+                )));
+            }
+            if quantity < self.base_min || quantity > self.base_max {
+                return Err(OrderlyError::ValidationError(format!(
+                    "Quantity {} is outside [{}, {}]",
+                    quantity, self.base_min, self.base_max
+                )));
+            }
+        }
+
+        let notional = match (order.order_price, order.order_quantity) {
+            (Some(price), Some(quantity)) => Some(price * quantity),
+            _ => order.order_amount,
+        };
+        if let Some(notional) = notional {
+            if notional < self.min_notional {
+                return Err(OrderlyError::ValidationError(format!(
+                    "Notional {} is below min_notional {}",
+                    notional, self.min_notional
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Notional and initial margin of a prospective order, from [`estimate_order_margin`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrderMarginEstimate {
+    /// `|price * quantity|`, in the quote currency
+    pub notional: f64,
+    /// Initial margin rate applied to the notional
+    pub imr: f64,
+    /// `notional * imr`: collateral needed to open the order
+    pub required_margin: f64,
+}
+
+/// Estimates the notional and initial margin an order for `quantity` at `price` requires.
+///
+/// Uses Orderly's initial margin rate
+///
+/// ```text
+/// imr = max(1 / max_leverage, base_imr, imr_factor * notional^(4/5))
+/// required_margin = notional * imr
+/// ```
+///
+/// where `max_leverage` comes from `account`, `base_imr` from `symbol_info`, and
+/// `imr_factor` from `account.imr_factor` for the symbol (falling back to
+/// `symbol_info.imr_factor`). Terms whose inputs are missing are left out. The estimate
+/// covers this order alone; it does not net against an existing position or open orders.
+pub fn estimate_order_margin(
+    symbol_info: &SymbolInfo,
+    account: &AccountInfo,
+    price: f64,
+    quantity: f64,
+) -> OrderMarginEstimate {
+    let notional = (price * quantity).abs();
+    let leverage_imr = if account.max_leverage > 0.0 {
+        1.0 / account.max_leverage
+    } else {
+        0.0
+    };
+    let imr_factor = account
+        .imr_factor
+        .get(&symbol_info.symbol)
+        .copied()
+        .or(symbol_info.imr_factor)
+        .unwrap_or(0.0);
+    let imr = leverage_imr
+        .max(symbol_info.base_imr.unwrap_or(0.0))
+        .max(imr_factor * notional.powf(0.8));
+    OrderMarginEstimate {
+        notional,
+        imr,
+        required_margin: notional * imr,
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -0,0 +1,48 @@
+//! Request metrics hook for [`OrderlyService`](crate::rest::OrderlyService)
+
+use std::time::Duration;
+
+/// Receives per-request callbacks from [`OrderlyService`](crate::rest::OrderlyService).
+///
+/// No backend is bundled; implement this trait to forward counts and latencies to
+/// `metrics`, `prometheus` or similar. Callbacks run inline on the request path, so
+/// they should be cheap and must not block.
+///
+/// `path` is the URL path without the query string, e.g. `/v1/order/12345`. Paths that
+/// embed IDs or symbols should be normalised by the implementation if label cardinality
+/// matters.
+///
+/// # Examples
+///
+/// ```no_run
+/// use orderly_connector_rs::rest::{MetricsObserver, OrderlyService};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// struct LogMetrics;
+///
+/// impl MetricsObserver for LogMetrics {
+///     fn on_request_start(&self, path: &str) {
+///         println!("-> {}", path);
+///     }
+///
+///     fn on_request_end(&self, path: &str, status: Option<u16>, duration: Duration) {
+///         println!("<- {} {:?} in {:?}", path, status, duration);
+///     }
+/// }
+///
+/// let service = OrderlyService::new(true, None)
+///     .expect("Failed to create service")
+///     .with_metrics_observer(Arc::new(LogMetrics));
+/// ```
+pub trait MetricsObserver: Send + Sync {
+    /// Called just before a request is sent.
+    fn on_request_start(&self, path: &str);
+
+    /// Called once response headers arrive or the request fails.
+    ///
+    /// `status` is the HTTP status code, or `None` if no response was received
+    /// (connection error, timeout). `duration` covers sending the request and
+    /// receiving the response headers.
+    fn on_request_end(&self, path: &str, status: Option<u16>, duration: Duration);
+}
@@ -1,6 +1,19 @@
 //! REST API Client module
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
+pub mod metrics;
+mod pagination;
+pub mod transport;
 
 // Re-export the main client struct for easier access
-pub use client::OrderlyService;
+#[cfg(feature = "blocking")]
+pub use blocking::BlockingOrderlyService;
+pub use client::{
+    OrderlyService, OrderlyServiceOptions, RateLimitStatus, ReplaceOrderOutcome, RetryPolicy,
+};
+pub use metrics::MetricsObserver;
+#[cfg(any(test, feature = "test-util"))]
+pub use transport::MockTransport;
+pub use transport::Transport;
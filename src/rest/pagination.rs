@@ -0,0 +1,122 @@
+//! Page-walking shared by the `get_all_*` helpers of [`OrderlyService`](crate::rest::OrderlyService)
+
+use crate::error::Result;
+use crate::types::PaginationMeta;
+use log::warn;
+use std::future::Future;
+
+/// Safety cap on the number of pages a single `get_all_*` call fetches.
+pub(crate) const MAX_AUTO_PAGES: u32 = 100;
+
+/// Fetches consecutive pages starting at `first_page` and concatenates their rows.
+///
+/// `fetch_page` is called with each page number and returns that page's rows and
+/// pagination meta. Fetching stops when a page is empty, has no meta, or its meta
+/// reports no further records. After `MAX_AUTO_PAGES` pages the rows collected so far
+/// are returned and a warning naming `label` is logged.
+pub(crate) async fn collect_pages<T, F, Fut>(
+    label: &str,
+    first_page: u32,
+    mut fetch_page: F,
+) -> Result<Vec<T>>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<(Vec<T>, Option<PaginationMeta>)>>,
+{
+    let mut page = first_page;
+    let mut rows = Vec::new();
+    for _ in 0..MAX_AUTO_PAGES {
+        let (fetched, meta) = fetch_page(page).await?;
+        let fetched_any = !fetched.is_empty();
+        rows.extend(fetched);
+        match meta {
+            Some(meta) if fetched_any && meta.has_more() => page = meta.current_page + 1,
+            _ => return Ok(rows),
+        }
+    }
+    warn!(
+        "{} stopped after {} pages; results may be incomplete",
+        label, MAX_AUTO_PAGES
+    );
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::{Cell, RefCell};
+    use std::future::ready;
+
+    fn meta(current_page: u32, total: u32) -> Option<PaginationMeta> {
+        Some(PaginationMeta {
+            total,
+            current_page,
+            records_per_page: 1,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_follows_meta_from_first_page() {
+        let pages = RefCell::new(Vec::new());
+        let rows = collect_pages("test", 2, |page| {
+            pages.borrow_mut().push(page);
+            ready(Ok((vec![page], meta(page, 4))))
+        })
+        .await
+        .unwrap();
+        assert_eq!(rows, vec![2, 3, 4]);
+        assert_eq!(pages.into_inner(), vec![2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_stops_at_max_auto_pages() {
+        let calls = Cell::new(0);
+        let rows = collect_pages("test", 1, |page| {
+            calls.set(calls.get() + 1);
+            // The server always claims there is more
+            ready(Ok((vec![page], meta(page, u32::MAX))))
+        })
+        .await
+        .unwrap();
+        assert_eq!(calls.get(), MAX_AUTO_PAGES);
+        assert_eq!(rows.len(), MAX_AUTO_PAGES as usize);
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_stops_on_empty_page() {
+        let calls = Cell::new(0);
+        let rows: Vec<u32> = collect_pages("test", 1, |page| {
+            calls.set(calls.get() + 1);
+            // Meta still claims more records, but the page is empty
+            ready(Ok((Vec::new(), meta(page, 10))))
+        })
+        .await
+        .unwrap();
+        assert_eq!(calls.get(), 1);
+        assert!(rows.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_stops_without_meta() {
+        let calls = Cell::new(0);
+        let rows = collect_pages("test", 1, |page| {
+            calls.set(calls.get() + 1);
+            ready(Ok((vec![page, page], None)))
+        })
+        .await
+        .unwrap();
+        assert_eq!(calls.get(), 1);
+        assert_eq!(rows, vec![1, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_collect_pages_propagates_errors() {
+        let result: Result<Vec<u32>> = collect_pages("test", 1, |_| {
+            ready(Err(crate::error::OrderlyError::ValidationError(
+                "boom".to_string(),
+            )))
+        })
+        .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,139 @@
+//! Pluggable HTTP transport for [`OrderlyService`](crate::rest::OrderlyService)
+
+#[cfg(any(test, feature = "test-util"))]
+use crate::rest::client::SignedRequestParts;
+use reqwest::{Client as HttpClient, Request, Response};
+#[cfg(any(test, feature = "test-util"))]
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+#[cfg(any(test, feature = "test-util"))]
+use std::sync::Mutex;
+
+/// Future returned by [`Transport::execute`].
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = reqwest::Result<Response>> + Send + 'a>>;
+
+/// Sends fully built requests on behalf of [`OrderlyService`](crate::rest::OrderlyService).
+///
+/// The service builds, signs and parses requests itself and only hands the finished
+/// `reqwest::Request` to its transport. By default this is the service's `reqwest::Client`;
+/// install another implementation with
+/// [`OrderlyService::with_transport`](crate::rest::OrderlyService::with_transport), e.g.
+/// `MockTransport` (behind the `test-util` feature) in tests.
+pub trait Transport: Send + Sync {
+    /// Sends `request` and returns the response once its headers have arrived.
+    fn execute(&self, request: Request) -> TransportFuture<'_>;
+}
+
+impl Transport for HttpClient {
+    fn execute(&self, request: Request) -> TransportFuture<'_> {
+        Box::pin(HttpClient::execute(self, request))
+    }
+}
+
+/// In-memory [`Transport`] that answers with canned responses and records every request.
+///
+/// Responses are returned in the order they were queued. Once the queue is empty, requests
+/// are answered with HTTP 500 and an error message naming the request, which the service
+/// returns as an `OrderlyError`. A queued status that is not a valid HTTP status is sent
+/// as 500 too. Nothing touches the network, so signing, error mapping and
+/// deserialization can be tested quickly and deterministically.
+///
+/// Available with the `test-util` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// use orderly_connector_rs::rest::{MockTransport, OrderlyService};
+/// use serde_json::json;
+/// use std::sync::Arc;
+///
+/// # async fn run() -> orderly_connector_rs::Result<()> {
+/// let transport = Arc::new(MockTransport::new());
+/// transport.push_json(json!({"success": true, "timestamp": 0, "data": {"status": 0}}));
+///
+/// let service = OrderlyService::new(true, None)?.with_transport(transport.clone());
+/// service.get_system_status().await?;
+///
+/// assert_eq!(transport.requests()[0].url.path(), "/v1/public/system_info");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(any(test, feature = "test-util"))]
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    responses: Mutex<VecDeque<(u16, String)>>,
+    requests: Mutex<Vec<SignedRequestParts>>,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl MockTransport {
+    /// Creates a transport with no queued responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues a response with the given HTTP status and raw body.
+    pub fn push_response(&self, status: u16, body: impl Into<String>) {
+        self.responses
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push_back((status, body.into()));
+    }
+
+    /// Queues an HTTP 200 response with `body` serialized as JSON.
+    pub fn push_json(&self, body: serde_json::Value) {
+        self.push_response(200, body.to_string());
+    }
+
+    /// Returns every request sent so far, oldest first.
+    pub fn requests(&self) -> Vec<SignedRequestParts> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl Transport for MockTransport {
+    fn execute(&self, request: Request) -> TransportFuture<'_> {
+        let method = request.method().clone();
+        let path = request.url().path().to_string();
+        let body = request
+            .body()
+            .and_then(|b| b.as_bytes())
+            .map(|b| String::from_utf8_lossy(b).into_owned());
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(SignedRequestParts {
+                method: request.method().clone(),
+                url: request.url().clone(),
+                headers: request.headers().clone(),
+                body,
+            });
+
+        let (status, body) = self
+            .responses
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pop_front()
+            .unwrap_or_else(|| {
+                let message = format!("no mock response queued for {} {}", method, path);
+                (
+                    500,
+                    serde_json::json!({"success": false, "code": -1000, "message": message})
+                        .to_string(),
+                )
+            });
+        let mut response = http::Response::new(body);
+        *response.status_mut() =
+            http::StatusCode::from_u16(status).unwrap_or(http::StatusCode::INTERNAL_SERVER_ERROR);
+        response.headers_mut().insert(
+            http::header::CONTENT_TYPE,
+            http::HeaderValue::from_static("application/json"),
+        );
+        Box::pin(async move { Ok(Response::from(response)) })
+    }
+}
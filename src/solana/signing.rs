@@ -1,6 +1,6 @@
 use crate::error::{OrderlyError, Result};
 use crate::eth::abi::create_withdrawal_message;
-use crate::rest::client::{Credentials, OrderlyService};
+use crate::rest::client::{AsCredentials, OrderlyService};
 use crate::solana::types::SolanaConfig;
 use solabi::encode::encode;
 use solabi::keccak::v256;
@@ -73,7 +73,7 @@ pub fn sign_solana_message(message_bytes: &[u8], keypair: &Keypair) -> Result<St
 /// Returns the WithdrawalMessage and the base58 signature string.
 pub async fn prepare_withdrawal_message(
     service: &OrderlyService,
-    creds: &Credentials<'_>,
+    creds: &impl AsCredentials,
     solana_config: &SolanaConfig,
     user_keypair: &Keypair,
     receiver_addr: &str,
@@ -87,7 +87,7 @@ pub async fn prepare_withdrawal_message(
     // 3. Create message
     let message = create_withdrawal_message(
         &solana_config.broker_id,
-        solana_config.orderly_solana_chain_id,
+        solana_config.orderly_solana_chain_id.as_u64(),
         receiver_addr,
         token,
         amount,
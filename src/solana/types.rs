@@ -1,3 +1,4 @@
+use crate::types::ChainId;
 use solana_sdk::pubkey::Pubkey;
 use std::str::FromStr;
 
@@ -25,7 +26,7 @@ pub struct SolanaConfig {
     // pub orderly_chain_id: u64, // e.g., 421614 for Arb Sepolia
     // pub eip712_verifying_contract: String, // Address as hex string
     // Solana Chain ID used for off-chain message signing (e.g., 900900900 from gist)
-    pub orderly_solana_chain_id: u64,
+    pub orderly_solana_chain_id: ChainId,
 }
 
 // LayerZero Endpoint ID for Solana Mainnet (Verified from JS: getDstEID)
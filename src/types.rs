@@ -13,7 +13,10 @@ use std::fmt; // Added for precise price/quantity representation
 /// * `Market` - A market order that executes at the current market price
 /// * `Ioc` - Immediate or Cancel order that executes immediately or is cancelled
 /// * `Fok` - Fill or Kill order that must be filled completely or cancelled
-/// * `PostOnly` - Order that only adds liquidity to the order book
+/// * `PostOnly` - Order that only adds liquidity to the order book. Orderly documents no
+///   dedicated API error code for a post-only order that would cross the book, so such a
+///   rejection cannot be told apart from an `OrderlyError`; check the order's final
+///   status (execution reports or `get_order`) instead
 /// * `Ask` - A limit sell order
 /// * `Bid` - A limit buy order
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -52,7 +55,11 @@ pub enum Side {
 /// * `Rejected` - Order has been rejected
 /// * `Expired` - Order has expired
 /// * `PartialFilled` - Order has been partially filled
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+/// * `Replaced` - Order has been replaced by an edit (execution reports only)
+/// * `Incomplete` - Query filter for all working orders (`New`, `Accepted`, `PartialFilled`)
+/// * `Completed` - Query filter for all finished orders
+/// * `Unknown` - A status this client does not know yet; only produced when deserializing
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderStatus {
     New,      // Pending Create
@@ -62,7 +69,14 @@ pub enum OrderStatus {
     Rejected,
     Expired,
     PartialFilled,
-    // There might be more statuses, add as needed based on API docs
+    Replaced,
+    /// Only valid as the `status` filter of [`GetOrdersParams`]
+    Incomplete,
+    /// Only valid as the `status` filter of [`GetOrdersParams`]
+    Completed,
+    /// Any status added by the server after this client was released
+    #[serde(other)]
+    Unknown,
 }
 
 impl fmt::Display for OrderStatus {
@@ -80,6 +94,18 @@ pub enum AlgoOrderType {
     TakeProfitMarket,
     TakeProfitLimit,
     TrailingStop,
+    /// Linked take-profit and stop-loss pair; when one leg fills the other is cancelled
+    TpSl,
+}
+
+/// How the `trailing_delta` of a trailing stop is interpreted
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TrailingType {
+    /// Delta is a percentage of the market price, e.g. `1.5` for 1.5%
+    Percentage,
+    /// Delta is an absolute price distance in the quote currency
+    Absolute,
 }
 
 /// Represents the time in force for an order.
@@ -89,7 +115,209 @@ pub enum AlgoOrderType {
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OrderlyTimeInForce {
     Gtc, // Good 'Til Canceled
-         // Add others if supported explicitly via a TIF field, e.g., Gtd (Good 'Til Date)
+    Gtd, // Good 'Til Date: expires at the order's `expire_time`
+}
+
+/// Candlestick interval shared by `OrderlyService::get_kline` and the `@kline_*` WebSocket topics.
+///
+/// Serializes to (and parses from) the exact token used by the API, e.g. `"1m"` or `"1mon"`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KlineInterval {
+    #[serde(rename = "1m")]
+    OneMinute,
+    #[serde(rename = "5m")]
+    FiveMinutes,
+    #[serde(rename = "15m")]
+    FifteenMinutes,
+    #[serde(rename = "30m")]
+    ThirtyMinutes,
+    #[serde(rename = "1h")]
+    OneHour,
+    #[serde(rename = "4h")]
+    FourHours,
+    #[serde(rename = "12h")]
+    TwelveHours,
+    #[serde(rename = "1d")]
+    OneDay,
+    #[serde(rename = "1w")]
+    OneWeek,
+    #[serde(rename = "1mon")]
+    OneMonth,
+}
+
+impl KlineInterval {
+    /// Every supported interval, shortest first.
+    pub const ALL: [KlineInterval; 10] = [
+        KlineInterval::OneMinute,
+        KlineInterval::FiveMinutes,
+        KlineInterval::FifteenMinutes,
+        KlineInterval::ThirtyMinutes,
+        KlineInterval::OneHour,
+        KlineInterval::FourHours,
+        KlineInterval::TwelveHours,
+        KlineInterval::OneDay,
+        KlineInterval::OneWeek,
+        KlineInterval::OneMonth,
+    ];
+
+    /// Returns the API token for this interval, e.g. `"1h"`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            KlineInterval::OneMinute => "1m",
+            KlineInterval::FiveMinutes => "5m",
+            KlineInterval::FifteenMinutes => "15m",
+            KlineInterval::ThirtyMinutes => "30m",
+            KlineInterval::OneHour => "1h",
+            KlineInterval::FourHours => "4h",
+            KlineInterval::TwelveHours => "12h",
+            KlineInterval::OneDay => "1d",
+            KlineInterval::OneWeek => "1w",
+            KlineInterval::OneMonth => "1mon",
+        }
+    }
+}
+
+impl fmt::Display for KlineInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for KlineInterval {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|interval| interval.as_str() == s)
+            .ok_or_else(|| format!("invalid kline interval: {:?}", s))
+    }
+}
+
+// --- Chain IDs ---
+
+/// A chain ID as used by Orderly, e.g. in withdrawals, registrations and asset history.
+///
+/// Orderly sends chain IDs both as JSON numbers and as strings, so `ChainId`
+/// deserializes from either form. It serializes as a number.
+///
+/// # Examples
+///
+/// ```
+/// use orderly_connector_rs::types::ChainId;
+///
+/// let chain_id: ChainId = serde_json::from_str("\"42161\"").unwrap();
+/// assert_eq!(chain_id, ChainId::ARBITRUM);
+/// assert_eq!(chain_id.to_string(), "42161");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(transparent)]
+pub struct ChainId(pub u64);
+
+impl ChainId {
+    pub const ETHEREUM: ChainId = ChainId(1);
+    pub const OPTIMISM: ChainId = ChainId(10);
+    pub const POLYGON: ChainId = ChainId(137);
+    pub const BASE: ChainId = ChainId(8453);
+    pub const ARBITRUM: ChainId = ChainId(42161);
+    pub const ARBITRUM_SEPOLIA: ChainId = ChainId(421614);
+    pub const SOLANA_MAINNET: ChainId = ChainId(900900900);
+    pub const SOLANA_DEVNET: ChainId = ChainId(901901901);
+
+    /// Creates a chain ID from its numeric value.
+    pub const fn new(id: u64) -> Self {
+        ChainId(id)
+    }
+
+    /// Returns the numeric value of the chain ID.
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+
+    /// Returns `true` for Orderly's Solana chain IDs.
+    pub fn is_solana(self) -> bool {
+        self == Self::SOLANA_MAINNET || self == Self::SOLANA_DEVNET
+    }
+}
+
+impl From<u64> for ChainId {
+    fn from(id: u64) -> Self {
+        ChainId(id)
+    }
+}
+
+impl From<ChainId> for u64 {
+    fn from(chain_id: ChainId) -> Self {
+        chain_id.0
+    }
+}
+
+impl fmt::Display for ChainId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::str::FromStr for ChainId {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim().parse().map(ChainId)
+    }
+}
+
+impl<'de> Deserialize<'de> for ChainId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Wire {
+            Number(u64),
+            String(String),
+        }
+
+        match Wire::deserialize(deserializer)? {
+            Wire::Number(id) => Ok(ChainId(id)),
+            Wire::String(s) => s
+                .parse()
+                .map_err(|_| serde::de::Error::custom(format!("invalid chain id: {:?}", s))),
+        }
+    }
+}
+
+/// Serializes a [`ChainId`] as a JSON string, for endpoints that expect the string form.
+fn serialize_chain_id_as_string<S>(chain_id: &ChainId, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_str(chain_id)
+}
+
+/// Deserializes a flag sent either as a JSON boolean or as `1`/`0`.
+///
+/// GET /v1/trades returns `is_maker` as a boolean, GET /v1/trade/{trade_id} as an integer.
+fn deserialize_bool_or_int<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Wire {
+        Bool(bool),
+        Number(u64),
+    }
+
+    match Wire::deserialize(deserializer)? {
+        Wire::Bool(flag) => Ok(flag),
+        Wire::Number(0) => Ok(false),
+        Wire::Number(1) => Ok(true),
+        Wire::Number(n) => Err(serde::de::Error::custom(format!(
+            "expected 0 or 1, got {}",
+            n
+        ))),
+    }
 }
 
 // --- Request Structs ---
@@ -161,6 +389,9 @@ pub enum OrderlyTimeInForce {
 /// * `client_order_id` - Optional client-specified order ID (36 chars max, can include hyphens)
 /// * `visible_quantity` - Optional visible quantity for iceberg orders
 /// * `reduce_only` - When true, order can only reduce position size (recommended for closes/decreases)
+/// * `time_in_force` - Optional explicit time in force
+/// * `expire_time` - Expiry of a `Gtd` order as a 13-digit timestamp (ms); required for
+///   `Gtd` and rejected otherwise
 /// Reference: https://orderly.network/docs/build-on-omnichain/evm-api/restful-api/private/create-order
 #[derive(Serialize, Debug, Clone)]
 pub struct CreateOrderRequest {
@@ -179,6 +410,10 @@ pub struct CreateOrderRequest {
     pub visible_quantity: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reduce_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<OrderlyTimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expire_time: Option<u64>,
     // Add other optional fields like trigger_price etc. if needed
 }
 
@@ -211,6 +446,8 @@ impl CreateOrderRequest {
             client_order_id: None,
             visible_quantity: None,
             reduce_only: None,
+            time_in_force: None,
+            expire_time: None,
         }
     }
 
@@ -246,6 +483,8 @@ impl CreateOrderRequest {
             client_order_id: None,
             visible_quantity: None,
             reduce_only: Some(true), // Critical: prevents position increases
+            time_in_force: None,
+            expire_time: None,
         }
     }
 
@@ -283,6 +522,8 @@ impl CreateOrderRequest {
             client_order_id: None,
             visible_quantity: None,
             reduce_only: Some(true), // Critical: prevents position increases
+            time_in_force: None,
+            expire_time: None,
         }
     }
 
@@ -325,6 +566,77 @@ impl CreateOrderRequest {
         self
     }
 
+    /// Builder method to set an explicit time in force.
+    pub fn with_time_in_force(mut self, time_in_force: OrderlyTimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Builder method to make this a GTD order expiring at `expire_time` (13-digit
+    /// timestamp in ms). Sets `time_in_force` to `OrderlyTimeInForce::Gtd`.
+    pub fn with_expire_time(mut self, expire_time: u64) -> Self {
+        self.time_in_force = Some(OrderlyTimeInForce::Gtd);
+        self.expire_time = Some(expire_time);
+        self
+    }
+
+    /// Checks that `order_quantity` and `order_amount` are combined the way the API allows.
+    ///
+    /// `order_quantity` is the size in base currency (ETH in PERP_ETH_USDC) and
+    /// `order_amount` the size in quote currency (USDC). The two are mutually exclusive,
+    /// and `order_amount` is only accepted on MARKET, BID and ASK orders.
+    ///
+    /// `OrderlyService::create_order` runs this check before sending the order.
+    pub fn validate_size_fields(&self) -> Result<(), String> {
+        if self.order_quantity.is_some() && self.order_amount.is_some() {
+            return Err(
+                "Cannot specify both order_quantity and order_amount: order_quantity is the size \
+                 in base currency and order_amount the size in quote currency, and the API \
+                 accepts only one of them"
+                    .to_string(),
+            );
+        }
+        if self.order_amount.is_some()
+            && !matches!(
+                self.order_type,
+                OrderType::Market | OrderType::Bid | OrderType::Ask
+            )
+        {
+            return Err(format!(
+                "order_amount (size in quote currency) is only accepted for MARKET, BID and ASK \
+                 orders, not {:?}; use order_quantity (size in base currency) instead",
+                self.order_type
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that `expire_time` is set exactly for GTD orders and lies in the future.
+    ///
+    /// `OrderlyService::create_order` runs this check before sending the order.
+    pub fn validate_time_in_force(&self) -> Result<(), String> {
+        match (&self.time_in_force, self.expire_time) {
+            (Some(OrderlyTimeInForce::Gtd), Some(expire_time)) => {
+                let now = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_millis() as u64)
+                    .unwrap_or_default();
+                if expire_time <= now {
+                    return Err(format!(
+                        "GTD expire_time {} is not in the future (now {})",
+                        expire_time, now
+                    ));
+                }
+                Ok(())
+            }
+            (Some(OrderlyTimeInForce::Gtd), None) => {
+                Err("GTD orders require expire_time".to_string())
+            }
+            (_, Some(_)) => Err("expire_time is only valid for GTD orders".to_string()),
+            (_, None) => Ok(()),
+        }
+    }
+
     /// Validates the order request for common issues.
     ///
     /// # Returns
@@ -354,10 +666,11 @@ impl CreateOrderRequest {
             _ => {}
         }
 
-        // Check quantity/amount exclusivity
-        if self.order_quantity.is_some() && self.order_amount.is_some() {
-            return Err("Cannot specify both order_quantity and order_amount".to_string());
-        }
+        // Check quantity/amount exclusivity and which order types accept an amount
+        self.validate_size_fields()?;
+
+        // Check that a GTD order has not already expired
+        self.validate_time_in_force()?;
 
         // Check that either quantity or amount is specified
         if self.order_quantity.is_none() && self.order_amount.is_none() {
@@ -462,12 +775,60 @@ pub struct CreateAlgoOrderRequest {
     pub limit_price: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub trailing_delta: Option<f64>,
+    /// How `trailing_delta` is interpreted for trailing stops; absolute when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_type: Option<TrailingType>,
+    /// Market price at which a trailing stop starts trailing; immediately when unset
+    #[serde(rename = "activated_price", skip_serializing_if = "Option::is_none")]
+    pub activation_price: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub client_order_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reduce_only: Option<bool>,
 }
 
+/// Request parameters for a linked take-profit/stop-loss (OCO) pair.
+///
+/// Both legs are reduce-only orders on `side`, i.e. the side that closes the position
+/// (`Sell` for a long). Legs are market orders unless a limit price is given.
+#[derive(Debug, Clone)]
+pub struct BracketOrderRequest {
+    pub symbol: String,
+    pub side: Side,
+    pub quantity: f64,
+    pub take_profit_trigger: f64,
+    pub take_profit_limit: Option<f64>,
+    pub stop_loss_trigger: f64,
+    pub stop_loss_limit: Option<f64>,
+    pub client_order_id: Option<String>,
+}
+
+/// Request parameters for amending a working algorithmic order.
+///
+/// Only the fields that are set are sent; at least one must be set.
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct EditAlgoOrderRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_price: Option<f64>,
+    /// Sent as `price`
+    #[serde(rename = "price", skip_serializing_if = "Option::is_none")]
+    pub limit_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quantity: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trailing_delta: Option<f64>,
+}
+
+impl EditAlgoOrderRequest {
+    /// Returns true if no field is set, i.e. the edit would change nothing.
+    pub fn is_empty(&self) -> bool {
+        self.trigger_price.is_none()
+            && self.limit_price.is_none()
+            && self.quantity.is_none()
+            && self.trailing_delta.is_none()
+    }
+}
+
 /// Parameters for querying algorithmic orders
 #[derive(Debug, Clone, Serialize, Default)]
 pub struct GetAlgoOrdersParams {
@@ -524,6 +885,7 @@ pub struct SuccessResponse<T> {
 /// * `total_fee` - The total fee for the order
 /// * `fee_asset` - The asset in which fees are paid
 /// * `visible_quantity` - The visible quantity (for iceberg orders)
+/// * `reduce_only` - Whether the order can only reduce the position
 /// * `created_time` - The timestamp when the order was created
 /// * `updated_time` - The timestamp when the order was last updated
 #[derive(Deserialize, Debug, Clone)]
@@ -544,9 +906,11 @@ pub struct Order {
     pub total_fee: Option<f64>,
     pub fee_asset: Option<String>,
     pub visible_quantity: Option<f64>,
+    #[serde(default)]
+    pub reduce_only: Option<bool>,
     pub created_time: u64,
     pub updated_time: u64,
-    // Add reduce_only, source, trigger_price etc. if present in actual response
+    // Add source, trigger_price etc. if present in actual response
 }
 
 /// Response structure for algorithmic order details
@@ -568,6 +932,49 @@ pub struct AlgoOrderDetails {
     pub updated_time: i64,
 }
 
+/// One order created by a bracket request: the parent or one of its legs
+#[derive(Debug, Clone, Deserialize)]
+pub struct BracketOrderRow {
+    pub algo_order_id: String,
+    pub client_order_id: Option<String>,
+    pub order_type: AlgoOrderType,
+}
+
+/// Response structure for a bracket (TP/SL) order
+#[derive(Debug, Clone, Deserialize)]
+pub struct BracketOrderResponse {
+    pub rows: Vec<BracketOrderRow>,
+}
+
+impl BracketOrderResponse {
+    /// ID of the parent `TP_SL` order, which cancels both legs when cancelled.
+    pub fn parent_order_id(&self) -> Option<&str> {
+        self.find(|t| *t == AlgoOrderType::TpSl)
+    }
+
+    /// ID of the take-profit leg.
+    pub fn take_profit_order_id(&self) -> Option<&str> {
+        self.find(|t| {
+            matches!(
+                t,
+                AlgoOrderType::TakeProfitMarket | AlgoOrderType::TakeProfitLimit
+            )
+        })
+    }
+
+    /// ID of the stop-loss leg.
+    pub fn stop_loss_order_id(&self) -> Option<&str> {
+        self.find(|t| matches!(t, AlgoOrderType::StopMarket | AlgoOrderType::StopLimit))
+    }
+
+    fn find(&self, pred: impl Fn(&AlgoOrderType) -> bool) -> Option<&str> {
+        self.rows
+            .iter()
+            .find(|row| pred(&row.order_type))
+            .map(|row| row.algo_order_id.as_str())
+    }
+}
+
 /// Response structure for a list of algorithmic orders
 #[derive(Debug, Clone, Deserialize)]
 pub struct GetAlgoOrdersResponse {
@@ -614,6 +1021,13 @@ pub struct PaginationMeta {
     pub records_per_page: u32,
 }
 
+impl PaginationMeta {
+    /// Returns `true` if records remain beyond the current page.
+    pub fn has_more(&self) -> bool {
+        (self.current_page as u64) * (self.records_per_page as u64) < self.total as u64
+    }
+}
+
 pub type GetOrdersResponse = SuccessResponse<GetOrdersResponseData>;
 
 // Response for successful cancellation (often just success:true)
@@ -624,6 +1038,55 @@ pub struct CancelOrderResponseData {
 
 pub type CancelOrderResponse = SuccessResponse<CancelOrderResponseData>;
 
+/// Outcome of cancelling a single order within a batch cancel request.
+#[derive(Deserialize, Debug, Clone)]
+pub struct BatchCancelOrderResult {
+    pub order_id: u64,
+    pub status: String, // e.g., "CANCEL_SENT" or "FAILED"
+    #[serde(default)]
+    pub code: Option<i64>, // Error code when the cancel failed
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct BatchCancelOrdersResponseData {
+    pub rows: Vec<BatchCancelOrderResult>,
+}
+
+pub type BatchCancelOrdersResponse = SuccessResponse<BatchCancelOrdersResponseData>;
+
+// --- System Maintenance ---
+
+/// A scheduled (or ongoing) maintenance window.
+#[derive(Deserialize, Debug, Clone)]
+pub struct MaintenanceInfo {
+    /// Start of the window in ms, if one is scheduled
+    pub start_time: Option<u64>,
+    /// End of the window in ms, if known
+    pub end_time: Option<u64>,
+    /// Components affected by the maintenance, e.g. "trading"
+    #[serde(default)]
+    pub components: Vec<String>,
+    pub status: String,
+}
+
+impl MaintenanceInfo {
+    /// Returns true if `timestamp_ms` falls inside the maintenance window.
+    ///
+    /// A window without an end time is treated as open-ended.
+    pub fn is_active_at(&self, timestamp_ms: u64) -> bool {
+        match self.start_time {
+            Some(start) => {
+                timestamp_ms >= start && !matches!(self.end_time, Some(end) if timestamp_ms >= end)
+            }
+            None => false,
+        }
+    }
+}
+
+pub type GetMaintenanceInfoResponse = SuccessResponse<MaintenanceInfo>;
+
 // --- Account Information ---
 
 #[derive(Deserialize, Debug, Clone)]
@@ -641,6 +1104,21 @@ pub struct AccountInfo {
     pub futures_maker_fee_rate: f64,
     pub imr_factor: std::collections::HashMap<String, f64>,
     pub max_notional: std::collections::HashMap<String, i64>,
+    /// Collateral available for new positions and withdrawals
+    #[serde(default)]
+    pub free_collateral: Option<f64>,
+    /// Total collateral value across all holdings
+    #[serde(default)]
+    pub total_collateral_value: Option<f64>,
+    /// Current margin ratio of the account
+    #[serde(default)]
+    pub margin_ratio: Option<f64>,
+    /// Margin ratio below which the account is liquidated
+    #[serde(default)]
+    pub maintenance_margin_ratio: Option<f64>,
+    /// Margin ratio required to open new positions
+    #[serde(default)]
+    pub initial_margin_ratio: Option<f64>,
 }
 
 pub type GetAccountInfoResponse = SuccessResponse<AccountInfo>;
@@ -664,6 +1142,62 @@ pub struct GetHoldingResponseData {
 
 pub type GetHoldingResponse = SuccessResponse<GetHoldingResponseData>;
 
+/// A [`Holding`] with its value in USD.
+#[derive(Debug, Clone)]
+pub struct HoldingValuation {
+    pub holding: Holding,
+    /// USD price of one unit of the token, if known
+    pub usd_price: Option<f64>,
+    /// `holding.holding * usd_price`, if the price is known
+    pub usd_value: Option<f64>,
+}
+
+/// All holdings of an account valued in USD, from [`value_holdings`].
+#[derive(Debug, Clone)]
+pub struct HoldingsValuation {
+    pub holdings: Vec<HoldingValuation>,
+    /// Sum of the `usd_value`s that are known
+    pub total_usd_value: f64,
+}
+
+impl HoldingsValuation {
+    /// Tokens that could not be valued and are missing from `total_usd_value`.
+    pub fn unpriced_tokens(&self) -> Vec<&str> {
+        self.holdings
+            .iter()
+            .filter(|h| h.usd_value.is_none())
+            .map(|h| h.holding.token.as_str())
+            .collect()
+    }
+}
+
+/// Values `holdings` in USD using `usd_price`, which returns the price of one unit of a
+/// token or `None` if it is unknown.
+///
+/// Each holding's full balance, `frozen` included, is valued. Holdings without a price keep
+/// `usd_value: None` and are left out of the total.
+pub fn value_holdings<F>(holdings: Vec<Holding>, usd_price: F) -> HoldingsValuation
+where
+    F: Fn(&str) -> Option<f64>,
+{
+    let holdings: Vec<HoldingValuation> = holdings
+        .into_iter()
+        .map(|holding| {
+            let usd_price = usd_price(&holding.token);
+            HoldingValuation {
+                usd_value: usd_price.map(|price| holding.holding * price),
+                usd_price,
+                holding,
+            }
+        })
+        .collect();
+    let total_usd_value = holdings.iter().filter_map(|h| h.usd_value).sum();
+    HoldingsValuation {
+        holdings,
+        total_usd_value,
+    }
+}
+
 // --- Positions ---
 
 #[derive(Deserialize, Debug, Clone)]
@@ -702,6 +1236,18 @@ pub struct GetPositionsResponseData {
     // pub meta: Option<PaginationMeta>,
 }
 
+impl GetPositionsResponseData {
+    /// Returns the position for `symbol`, if any.
+    pub fn by_symbol(&self, symbol: &str) -> Option<&Position> {
+        self.rows.iter().find(|p| p.symbol == symbol)
+    }
+
+    /// Returns the positions with a non-zero `position_qty`.
+    pub fn nonzero_positions(&self) -> Vec<&Position> {
+        self.rows.iter().filter(|p| p.position_qty != 0.0).collect()
+    }
+}
+
 pub type GetPositionsResponse = SuccessResponse<GetPositionsResponseData>;
 
 #[derive(Deserialize, Debug, Clone)]
@@ -713,16 +1259,198 @@ pub struct GetSinglePositionResponseData {
 
 pub type GetSinglePositionResponse = SuccessResponse<GetSinglePositionResponseData>;
 
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct GetPositionHistoryParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_t: Option<u64>, // Timestamp ms
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_t: Option<u64>, // Timestamp ms
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+}
+
+/// A closed (or partially closed) position record.
+#[derive(Deserialize, Debug, Clone)]
+pub struct PositionHistoryEntry {
+    pub position_id: u64,
+    pub symbol: String,
+    pub side: String,            // "LONG" or "SHORT"
+    pub position_status: String, // e.g., "closed", "partial_closed"
+    #[serde(default)]
+    pub avg_open_price: Option<f64>,
+    #[serde(default)]
+    pub avg_close_price: Option<f64>,
+    #[serde(default)]
+    pub max_position_qty: Option<f64>,
+    #[serde(default)]
+    pub closed_position_qty: Option<f64>,
+    pub realized_pnl: f64,
+    #[serde(default)]
+    pub trading_fee: Option<f64>,
+    #[serde(default)]
+    pub accumulated_funding_fee: Option<f64>,
+    pub open_timestamp: u64,
+    #[serde(default)]
+    pub close_timestamp: Option<u64>, // None while still partially open
+    #[serde(default)]
+    pub last_update_time: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct GetPositionHistoryResponseData {
+    pub rows: Vec<PositionHistoryEntry>,
+    #[serde(default)]
+    pub meta: Option<PaginationMeta>,
+}
+
+impl GetPositionHistoryResponseData {
+    /// Aggregates this page's rows per symbol; see [`pnl_by_symbol`].
+    pub fn pnl_by_symbol(
+        &self,
+        start_t: Option<u64>,
+        end_t: Option<u64>,
+    ) -> std::collections::HashMap<String, PnlSummary> {
+        pnl_by_symbol(&self.rows, start_t, end_t)
+    }
+}
+
+pub type GetPositionHistoryResponse = SuccessResponse<GetPositionHistoryResponseData>;
+
+/// Realized PnL and fees of one symbol, summed over position history rows.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PnlSummary {
+    pub realized_pnl: f64,
+    pub trading_fee: f64,
+    pub funding_fee: f64,
+    /// Number of position records aggregated
+    pub positions: usize,
+}
+
+/// Sums `realized_pnl`, `trading_fee` and `accumulated_funding_fee` per symbol.
+///
+/// A row is included when its close time (falling back to `last_update_time`, then
+/// `open_timestamp`, for rows still partially open) lies within `start_t..=end_t`, both
+/// in ms; `None` leaves that side unbounded. Missing fees count as zero.
+pub fn pnl_by_symbol(
+    rows: &[PositionHistoryEntry],
+    start_t: Option<u64>,
+    end_t: Option<u64>,
+) -> std::collections::HashMap<String, PnlSummary> {
+    let mut summaries: std::collections::HashMap<String, PnlSummary> =
+        std::collections::HashMap::new();
+    for row in rows {
+        let time = row
+            .close_timestamp
+            .or(row.last_update_time)
+            .unwrap_or(row.open_timestamp);
+        if start_t.is_some_and(|start| time < start) || end_t.is_some_and(|end| time > end) {
+            continue;
+        }
+        let summary = summaries.entry(row.symbol.clone()).or_default();
+        summary.realized_pnl += row.realized_pnl;
+        summary.trading_fee += row.trading_fee.unwrap_or(0.0);
+        summary.funding_fee += row.accumulated_funding_fee.unwrap_or(0.0);
+        summary.positions += 1;
+    }
+    summaries
+}
+
 // --- Asset History (Deposits/Withdrawals) ---
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AssetHistoryType {
     Deposit,
+    #[serde(rename = "WITHDRAW", alias = "WITHDRAWAL")]
     Withdrawal,
     // Other types like Transfer, Interest, RealizedPnl, Fee, FundingFee, etc.?
 }
 
+/// Processing state of a deposit or withdrawal (`trans_status` in asset history).
+///
+/// States not known to this client deserialize as `Unknown` with the raw value, so new
+/// server-side states do not break parsing.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AssetTxStatus {
+    /// Request received, not yet processed
+    New,
+    /// Confirmed on chain, awaiting settlement
+    Confirm,
+    /// Being processed
+    Processing,
+    /// Waiting for cross-chain liquidity to be rebalanced
+    PendingRebalance,
+    /// Funds have been credited or sent (terminal)
+    Completed,
+    /// The transaction failed (terminal)
+    Failed,
+    /// A state not known to this client, with its raw value
+    Unknown(String),
+}
+
+impl AssetTxStatus {
+    /// Returns the wire representation, e.g. `"COMPLETED"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            AssetTxStatus::New => "NEW",
+            AssetTxStatus::Confirm => "CONFIRM",
+            AssetTxStatus::Processing => "PROCESSING",
+            AssetTxStatus::PendingRebalance => "PENDING_REBALANCE",
+            AssetTxStatus::Completed => "COMPLETED",
+            AssetTxStatus::Failed => "FAILED",
+            AssetTxStatus::Unknown(raw) => raw,
+        }
+    }
+
+    /// Whether this is a final state (`Completed` or `Failed`).
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, AssetTxStatus::Completed | AssetTxStatus::Failed)
+    }
+}
+
+impl fmt::Display for AssetTxStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for AssetTxStatus {
+    fn from(s: &str) -> Self {
+        match s.to_ascii_uppercase().as_str() {
+            "NEW" => AssetTxStatus::New,
+            "CONFIRM" => AssetTxStatus::Confirm,
+            "PROCESSING" => AssetTxStatus::Processing,
+            "PENDING_REBALANCE" => AssetTxStatus::PendingRebalance,
+            "COMPLETED" => AssetTxStatus::Completed,
+            "FAILED" => AssetTxStatus::Failed,
+            _ => AssetTxStatus::Unknown(s.to_string()),
+        }
+    }
+}
+
+impl Serialize for AssetTxStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for AssetTxStatus {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        Ok(AssetTxStatus::from(raw.as_str()))
+    }
+}
+
 #[derive(Serialize, Debug, Clone, Default)]
 pub struct GetAssetHistoryParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -748,13 +1476,24 @@ pub struct AssetHistoryEntry {
     pub amount: f64,
     pub fee: Option<f64>,
     pub transaction_hash: Option<String>,
-    pub chain_id: Option<String>, // Or u64?
+    pub chain_id: Option<ChainId>,
     pub chain_name: Option<String>,
+    #[serde(default)]
+    pub trans_status: Option<AssetTxStatus>,
     pub created_time: u64,
     pub updated_time: u64,
     // ... other fields like address, network etc.
 }
 
+impl AssetHistoryEntry {
+    /// Whether the transaction has reached a final state (`COMPLETED` or `FAILED`).
+    pub fn is_terminal(&self) -> bool {
+        self.trans_status
+            .as_ref()
+            .is_some_and(AssetTxStatus::is_terminal)
+    }
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct GetAssetHistoryResponseData {
     pub rows: Vec<AssetHistoryEntry>,
@@ -777,7 +1516,9 @@ pub struct GetTradesParams {
     pub page: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub size: Option<u32>,
-    // Add order_id, source filters if applicable
+    /// Only return fills of this order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<u64>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -791,57 +1532,288 @@ pub struct Trade {
     pub executed_quantity: f64,
     pub fee: f64,
     pub fee_asset: String,
+    #[serde(deserialize_with = "deserialize_bool_or_int")]
     pub is_maker: bool,
     pub executed_timestamp: u64, // Also called transaction_time?
                                  // ... other fields
 }
 
 #[derive(Deserialize, Debug, Clone)]
-pub struct GetTradesResponseData {
-    pub rows: Vec<Trade>,
-    pub meta: Option<PaginationMeta>,
+pub struct GetTradesResponseData {
+    pub rows: Vec<Trade>,
+    pub meta: Option<PaginationMeta>,
+}
+
+pub type GetTradesResponse = SuccessResponse<GetTradesResponseData>;
+
+/// Response for GET /v1/trade/{trade_id}
+pub type GetTradeResponse = SuccessResponse<Trade>;
+
+// --- Client Statistics ---
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ClientStatistics {
+    pub account_id: String,
+    pub total_trading_volume_30_d: f64, // Assuming f64 for volume
+    pub futures_trading_volume_30_d: f64,
+    pub spot_trading_volume_30_d: f64,
+    pub total_fee_30_d: f64,
+    pub vip_tier: Option<u32>, // Or String?
+                               // ... other stats fields
+}
+
+pub type GetClientStatisticsResponse = SuccessResponse<ClientStatistics>;
+
+/// Trading volume for a single UTC day, from GET /v1/volume/user/daily
+#[derive(Deserialize, Debug, Clone)]
+pub struct DailyVolume {
+    pub date: String, // YYYY-MM-DD
+    #[serde(default)]
+    pub perp_volume: Option<f64>,
+}
+
+pub type GetUserDailyVolumeResponse = SuccessResponse<Vec<DailyVolume>>;
+
+/// Daily and aggregate trading volume over a custom window.
+#[derive(Debug, Clone)]
+pub struct VolumeStats {
+    /// Start of the requested window in ms
+    pub start_t: u64,
+    /// End of the requested window in ms
+    pub end_t: u64,
+    /// One entry per UTC day in the window
+    pub daily: Vec<DailyVolume>,
+    /// Sum of `perp_volume` over `daily`
+    pub total_perp_volume: f64,
+}
+
+impl VolumeStats {
+    /// Builds the stats for a window from its daily rows.
+    pub fn from_daily(start_t: u64, end_t: u64, daily: Vec<DailyVolume>) -> Self {
+        let total_perp_volume = daily.iter().filter_map(|d| d.perp_volume).sum();
+        Self {
+            start_t,
+            end_t,
+            daily,
+            total_perp_volume,
+        }
+    }
+}
+
+// --- Add other account-related structs as needed (e.g., Algo Orders, Liquidations) ---
+
+// --- Withdrawals ---
+
+// Note: Withdrawal history is often fetched via get_asset_history using AssetHistoryType::Withdrawal
+
+#[derive(Serialize, Debug, Clone)]
+pub struct WithdrawRequest<'a> {
+    #[serde(serialize_with = "serialize_chain_id_as_string")]
+    pub chain_id: ChainId,
+    pub token: &'a str,
+    pub amount: f64,
+    pub withdraw_address: &'a str,
+    pub message: Option<&'a str>, // Optional message/memo
+                                  // Add other fields like twoFactorCode if required by API
+}
+
+/// Parameters for a signed withdrawal to an EVM wallet via `OrderlyService::withdraw_evm`.
+///
+/// `verifying_contract` is the Orderly EIP-712 domain contract, e.g.
+/// `eth::abi::ORDERLY_MAINNET_VERIFYING_CONTRACT`.
+#[derive(Debug, Clone)]
+pub struct EvmWithdrawParams<'a> {
+    pub broker_id: &'a str,
+    pub chain_id: ChainId,
+    pub receiver: &'a str,
+    pub token: &'a str,
+    pub amount: u64, // In the token's smallest unit
+    pub verifying_contract: &'a str,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct WithdrawResponseData {
+    pub withdraw_id: u64, // Or String?
+                          // Other potential fields confirming withdrawal request
+}
+
+pub type WithdrawResponse = SuccessResponse<WithdrawResponseData>;
+
+// --- Convert ---
+
+/// Query for a token conversion quote, sent to GET /v1/convert/quote
+#[derive(Serialize, Debug, Clone)]
+pub struct ConvertQuoteRequest<'a> {
+    pub from_token: &'a str,
+    pub to_token: &'a str,
+    pub amount: f64, // Amount of `from_token` to convert
+}
+
+/// A conversion quote. Execute it with `OrderlyService::execute_convert` before `expire_time`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConvertQuote {
+    pub quote_id: String,
+    pub from_token: String,
+    pub to_token: String,
+    pub from_amount: f64,
+    pub to_amount: f64,
+    pub rate: f64,        // Units of `to_token` per unit of `from_token`
+    pub expire_time: u64, // ms
+}
+
+impl ConvertQuote {
+    /// Whether the quote has expired at `now_ms`.
+    pub fn is_expired_at(&self, now_ms: u64) -> bool {
+        now_ms >= self.expire_time
+    }
+}
+
+pub type GetConvertQuoteResponse = SuccessResponse<ConvertQuote>;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ExecuteConvertRequest<'a> {
+    pub quote_id: &'a str,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ConvertResult {
+    pub convert_id: u64,
+    pub quote_id: String,
+    pub from_token: String,
+    pub to_token: String,
+    pub from_amount: f64,
+    pub to_amount: f64,
+    pub status: String, // e.g. "COMPLETED", "PENDING"
+}
+
+pub type ExecuteConvertResponse = SuccessResponse<ConvertResult>;
+
+// --- Account Mode ---
+
+/// Trading mode of an account
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccountMode {
+    /// Futures-only account
+    Futures,
+    /// Unified account sharing collateral across products
+    Unified,
+    /// A mode not known to this client; it cannot be requested
+    #[serde(other)]
+    Unknown,
+}
+
+/// Account mode currently in effect
+#[derive(Deserialize, Debug, Clone)]
+pub struct AccountModeInfo {
+    pub account_mode: AccountMode,
+}
+
+pub type GetAccountModeResponse = SuccessResponse<AccountModeInfo>;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SetAccountModeRequest {
+    pub account_mode: AccountMode,
+}
+
+pub type SetAccountModeResponse = SuccessResponse<AccountModeInfo>;
+
+// --- Leverage / Margin Mode ---
+
+/// How margin is allocated to a symbol's position
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MarginMode {
+    /// Margin is shared across all cross-margin positions
+    #[default]
+    Cross,
+    /// Margin is ring-fenced to this symbol's position
+    Isolated,
+}
+
+/// Effective leverage and margin mode for a symbol
+#[derive(Deserialize, Debug, Clone)]
+pub struct SymbolLeverage {
+    pub symbol: String,
+    pub leverage: f64,
+    #[serde(default)]
+    pub margin_mode: MarginMode,
+}
+
+pub type GetSymbolLeverageResponse = SuccessResponse<SymbolLeverage>;
+
+#[derive(Serialize, Debug, Clone)]
+pub struct SetSymbolMarginModeRequest<'a> {
+    pub symbol: &'a str,
+    pub margin_mode: MarginMode,
+}
+
+pub type SetSymbolMarginModeResponse = SuccessResponse<SymbolLeverage>;
+
+// --- Trading Rewards ---
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct GetRewardHistoryParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_t: Option<u64>, // Timestamp ms
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_t: Option<u64>, // Timestamp ms
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+}
+
+/// A reward distributed to the account, e.g. trading rewards for a finished epoch.
+#[derive(Deserialize, Debug, Clone)]
+pub struct RewardHistoryEntry {
+    #[serde(default)]
+    pub epoch_id: Option<u64>,
+    pub token: String,
+    pub amount: f64,
+    #[serde(rename = "type", default)]
+    pub reward_type: Option<String>, // e.g., "TRADING_REWARDS"
+    #[serde(default)]
+    pub status: Option<String>,
+    pub created_time: u64, // Timestamp ms
+    #[serde(default)]
+    pub updated_time: Option<u64>,
 }
 
-pub type GetTradesResponse = SuccessResponse<GetTradesResponseData>;
-
-// --- Client Statistics ---
-
 #[derive(Deserialize, Debug, Clone)]
-pub struct ClientStatistics {
-    pub account_id: String,
-    pub total_trading_volume_30_d: f64, // Assuming f64 for volume
-    pub futures_trading_volume_30_d: f64,
-    pub spot_trading_volume_30_d: f64,
-    pub total_fee_30_d: f64,
-    pub vip_tier: Option<u32>, // Or String?
-                               // ... other stats fields
+pub struct GetRewardHistoryResponseData {
+    pub rows: Vec<RewardHistoryEntry>,
+    pub meta: Option<PaginationMeta>,
 }
 
-pub type GetClientStatisticsResponse = SuccessResponse<ClientStatistics>;
-
-// --- Add other account-related structs as needed (e.g., Algo Orders, Liquidations) ---
-
-// --- Withdrawals ---
+pub type GetRewardHistoryResponse = SuccessResponse<GetRewardHistoryResponseData>;
 
-// Note: Withdrawal history is often fetched via get_asset_history using AssetHistoryType::Withdrawal
+/// A trading rewards epoch.
+#[derive(Deserialize, Debug, Clone)]
+pub struct EpochInfo {
+    pub epoch_id: u64,
+    pub start_time: u64, // Timestamp ms
+    pub end_time: u64,   // Timestamp ms
+    /// Total rewards distributed over the epoch
+    #[serde(default)]
+    pub max_reward_amount: Option<f64>,
+    #[serde(default)]
+    pub token: Option<String>,
+}
 
-#[derive(Serialize, Debug, Clone)]
-pub struct WithdrawRequest<'a> {
-    pub chain_id: &'a str, // Or u64? API specific
-    pub token: &'a str,
-    pub amount: f64,
-    pub withdraw_address: &'a str,
-    pub message: Option<&'a str>, // Optional message/memo
-                                  // Add other fields like twoFactorCode if required by API
+impl EpochInfo {
+    /// Returns `true` if `timestamp_ms` falls within `[start_time, end_time)`.
+    pub fn contains(&self, timestamp_ms: u64) -> bool {
+        self.start_time <= timestamp_ms && timestamp_ms < self.end_time
+    }
 }
 
 #[derive(Deserialize, Debug, Clone)]
-pub struct WithdrawResponseData {
-    pub withdraw_id: u64, // Or String?
-                          // Other potential fields confirming withdrawal request
+pub struct GetEpochInfoResponseData {
+    pub rows: Vec<EpochInfo>,
 }
 
-pub type WithdrawResponse = SuccessResponse<WithdrawResponseData>;
+pub type GetEpochInfoResponse = SuccessResponse<GetEpochInfoResponseData>;
 
 // --- Fee Rates ---
 
@@ -864,6 +1836,40 @@ pub struct GetFeeRatesResponseData {
     pub vip_level: Option<u32>,           // VIP level if applicable
 }
 
+/// A maker/taker fee rate pair, from a per-symbol entry or the account-wide rates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MakerTakerFeeRate {
+    pub maker_fee_rate: f64,
+    pub taker_fee_rate: f64,
+}
+
+impl GetFeeRatesResponseData {
+    /// Returns the per-symbol fee rate entry for `symbol`, if any.
+    pub fn for_symbol(&self, symbol: &str) -> Option<&FeeRate> {
+        self.fee_rates.iter().find(|rate| rate.symbol == symbol)
+    }
+
+    /// Returns the account-wide 30 day maker/taker rates, or `None` unless both are present.
+    pub fn default_rate(&self) -> Option<MakerTakerFeeRate> {
+        Some(MakerTakerFeeRate {
+            maker_fee_rate: self.maker_fee_rate_30_d?,
+            taker_fee_rate: self.taker_fee_rate_30_d?,
+        })
+    }
+
+    /// Returns the rates that apply to `symbol`: its per-symbol entry if there is one,
+    /// otherwise [`Self::default_rate`].
+    pub fn effective_rate(&self, symbol: &str) -> Option<MakerTakerFeeRate> {
+        match self.for_symbol(symbol) {
+            Some(rate) => Some(MakerTakerFeeRate {
+                maker_fee_rate: rate.maker_fee_rate,
+                taker_fee_rate: rate.taker_fee_rate,
+            }),
+            None => self.default_rate(),
+        }
+    }
+}
+
 pub type GetFeeRatesResponse = SuccessResponse<GetFeeRatesResponseData>;
 
 // --- Liquidations ---
@@ -903,6 +1909,30 @@ pub struct GetLiquidationsResponseData {
 
 pub type GetLiquidationsResponse = SuccessResponse<GetLiquidationsResponseData>;
 
+// --- Klines ---
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Kline {
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub interval: KlineInterval,
+    pub open: f64,
+    pub close: f64,
+    pub high: f64,
+    pub low: f64,
+    pub volume: f64,
+    pub amount: f64,
+    pub start_timestamp: u64, // Timestamp ms
+    pub end_timestamp: u64,   // Timestamp ms
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct GetKlineResponseData {
+    pub rows: Vec<Kline>,
+}
+
+pub type GetKlineResponse = SuccessResponse<GetKlineResponseData>;
+
 // --- PnL Settlement ---
 
 #[derive(Serialize, Debug, Clone, Default)]
@@ -935,6 +1965,13 @@ pub struct GetSettlementsResponseData {
     pub meta: Option<PaginationMeta>,
 }
 
+impl GetSettlementsResponseData {
+    /// Sums `settlement_pnl` across all rows.
+    pub fn total_pnl(&self) -> f64 {
+        self.rows.iter().map(|r| r.settlement_pnl).sum()
+    }
+}
+
 pub type GetSettlementsResponse = SuccessResponse<GetSettlementsResponseData>;
 
 // ===== Funding Fee =====
@@ -983,6 +2020,22 @@ pub struct GetFundingFeeHistoryResponseData {
     pub meta: Option<PaginationMeta>,
 }
 
+impl GetFundingFeeHistoryResponseData {
+    /// Sums `funding_fee` across all rows.
+    pub fn total_fee(&self) -> f64 {
+        self.rows.iter().map(|r| r.funding_fee).sum()
+    }
+
+    /// Sums `funding_fee` per symbol.
+    pub fn net_funding_by_symbol(&self) -> std::collections::HashMap<String, f64> {
+        let mut totals = std::collections::HashMap::new();
+        for row in &self.rows {
+            *totals.entry(row.symbol.clone()).or_insert(0.0) += row.funding_fee;
+        }
+        totals
+    }
+}
+
 // Define a proper response struct for GetFundingFeeHistoryResponse
 pub type GetFundingFeeHistoryResponse = SuccessResponse<GetFundingFeeHistoryResponseData>;
 
@@ -1036,6 +2089,37 @@ impl IntoIterator for GetFundingRateHistoryResponseData {
     }
 }
 
+/// Query parameters for `OrderlyService::get_symbol_funding_history`
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct GetSymbolFundingHistoryParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_t: Option<u64>, // Timestamp ms
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_t: Option<u64>, // Timestamp ms
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u32>,
+}
+
+/// A single funding rate settlement for one symbol
+#[derive(Deserialize, Debug, Clone)]
+pub struct SymbolFundingRate {
+    pub symbol: String,
+    pub funding_rate: f64,
+    pub funding_rate_timestamp: u64,
+    #[serde(default)]
+    pub next_funding_time: Option<u64>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct GetSymbolFundingHistoryResponseData {
+    pub rows: Vec<SymbolFundingRate>,
+    pub meta: Option<PaginationMeta>,
+}
+
+pub type GetSymbolFundingHistoryResponse = SuccessResponse<GetSymbolFundingHistoryResponseData>;
+
 // Reference iterator implementation for response data
 impl<'a> IntoIterator for &'a GetFundingRateHistoryResponseData {
     type Item = &'a FundingRateHistory;
@@ -1419,7 +2503,8 @@ fn default_subscription_success() -> bool {
     false
 }
 
-/// WebSocket liquidation message
+/// WebSocket liquidation message, pushed on the `liquidation` topic of the account-scoped
+/// EVM public stream (`wss://ws-evm.orderly.org/ws/stream/{account_id}`).
 #[derive(Deserialize, Debug, Clone)]
 pub struct WebSocketLiquidationMessage {
     pub topic: String,
@@ -1427,6 +2512,7 @@ pub struct WebSocketLiquidationMessage {
     pub data: Vec<WebSocketLiquidationData>,
 }
 
+/// A single liquidation event within a [`WebSocketLiquidationMessage`].
 #[derive(Deserialize, Debug, Clone)]
 pub struct WebSocketLiquidationData {
     #[serde(default)]
@@ -1457,7 +2543,7 @@ pub struct WebSocketPositionByPerp {
 #[derive(Debug, Deserialize, Clone)]
 pub struct PublicTradeData {
     pub symbol: String,
-    pub side: String,
+    pub side: Side,
     pub executed_price: f64,
     pub executed_quantity: f64,
     pub executed_timestamp: u64,
@@ -1482,7 +2568,7 @@ pub struct TradeData {
     pub symbol: String,
     pub price: f64,
     pub size: f64,
-    pub side: String,
+    pub side: Side,
 }
 
 /// Optional parameters for querying positions under liquidation.
@@ -1498,12 +2584,28 @@ pub struct GetPositionsUnderLiquidationParams {
 // --- WebSocket Message Structs ---
 
 /// Represents a single level in the order book (price and quantity).
-#[derive(Debug, Clone)]
+///
+/// Deserializes from either `[price, quantity]` or `{"price", "quantity"}`, and
+/// serializes to the compact `[price, quantity]` array form.
+#[derive(Debug, Clone, PartialEq)]
 pub struct OrderbookLevel {
     pub price: f64,
     pub quantity: f64,
 }
 
+impl Serialize for OrderbookLevel {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeTuple;
+        let mut tuple = serializer.serialize_tuple(2)?;
+        tuple.serialize_element(&self.price)?;
+        tuple.serialize_element(&self.quantity)?;
+        tuple.end()
+    }
+}
+
 impl<'de> Deserialize<'de> for OrderbookLevel {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -1537,8 +2639,8 @@ impl<'de> Deserialize<'de> for OrderbookLevel {
             {
                 let mut price = None;
                 let mut quantity = None;
-                while let Some(key) = map.next_key::<&str>()? {
-                    match key {
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.as_str() {
                         "price" => price = Some(map.next_value()?),
                         "quantity" => quantity = Some(map.next_value()?),
                         _ => {
@@ -1574,9 +2676,14 @@ pub struct OrderbookData {
     pub bids: Vec<OrderbookLevel>, // List of bid levels
     #[serde(default)]
     pub asks: Vec<OrderbookLevel>, // List of ask levels
+    /// Checksum sent by the server, passed through unverified.
+    ///
+    /// Orderly does not publish how it is computed (level depth, ordering, number
+    /// formatting), so the crate cannot validate it; use
+    /// `websocket::OrderbookSequenceValidator` to detect missed updates instead.
     #[serde(rename = "checksum")]
     #[serde(default)]
-    pub checksum: Option<u32>, // Optional checksum for verification
+    pub checksum: Option<u32>,
     #[serde(rename = "lastUpdateId")]
     #[serde(default)]
     pub last_update_id: Option<u64>, // Identifier for the update sequence
@@ -1594,34 +2701,192 @@ pub struct Ticker {
     pub data: TickerData,
 }
 
+/// 24h ticker of one symbol, as streamed over WebSocket or returned by
+/// `OrderlyService::get_all_tickers`.
+///
+/// The REST market info rows name these fields `24h_open`, `24h_close` etc., which are accepted
+/// as aliases. They carry no trade count, so `trade_count` is `None` for REST snapshots.
 #[derive(Deserialize, Debug, Clone)]
 pub struct TickerData {
     pub symbol: String,
-    #[serde(rename = "open")]
+    #[serde(rename = "open", alias = "24h_open")]
     pub open_price: Option<f64>,
-    #[serde(rename = "high")]
+    #[serde(rename = "high", alias = "24h_high")]
     pub high_price: Option<f64>,
-    #[serde(rename = "low")]
+    #[serde(rename = "low", alias = "24h_low")]
     pub low_price: Option<f64>,
-    #[serde(rename = "close")]
+    #[serde(rename = "close", alias = "24h_close")]
     pub close_price: f64, // Last traded price
-    #[serde(rename = "volume")]
+    #[serde(rename = "volume", alias = "24h_volume")]
     pub volume: Option<f64>, // 24h volume in base asset
-    #[serde(rename = "amount")]
+    #[serde(rename = "amount", alias = "24h_amount")]
     pub amount: Option<f64>, // 24h volume in quote asset
     #[serde(rename = "count")]
     pub trade_count: Option<u64>, // Number of trades in 24h
                                   // Add other relevant fields like mark_price, index_price, funding_rate if included
 }
 
-/// Represents different types of parsed WebSocket messages from public streams.
+/// Data of GET /v1/public/futures read as 24h tickers; price and funding fields are ignored.
+#[derive(Deserialize, Debug, Clone)]
+pub struct GetTickersResponseData {
+    pub rows: Vec<TickerData>,
+}
+
+/// Type alias for the REST 24h tickers response.
+pub type GetTickersResponse = SuccessResponse<GetTickersResponseData>;
+
+// --- Private WebSocket Message Structs ---
+
+/// Represents an order execution report pushed on the private `executionreport` topic.
+///
+/// Reference: https://orderly.network/docs/build-on-omnichain/evm-api/websocket-api/private/execution-report
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionReport {
+    pub symbol: String,
+    #[serde(default)]
+    pub client_order_id: Option<String>,
+    pub order_id: u64,
+    #[serde(rename = "type")]
+    pub order_type: String, // e.g., "LIMIT", "MARKET"
+    pub side: Side,
+    #[serde(default)]
+    pub quantity: Option<f64>,
+    #[serde(default)]
+    pub price: Option<f64>, // May be null for market orders
+    #[serde(default)]
+    pub trade_id: Option<u64>,
+    #[serde(default)]
+    pub executed_price: Option<f64>,
+    #[serde(default)]
+    pub executed_quantity: Option<f64>,
+    #[serde(default)]
+    pub fee: Option<f64>,
+    #[serde(default)]
+    pub fee_asset: Option<String>,
+    #[serde(default)]
+    pub total_executed_quantity: Option<f64>,
+    #[serde(default)]
+    pub avg_price: Option<f64>,
+    pub status: OrderStatus,
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub order_tag: Option<String>,
+    #[serde(default)]
+    pub total_fee: Option<f64>,
+    #[serde(default)]
+    pub visible: Option<f64>,
+    pub timestamp: u64,
+    #[serde(default)]
+    pub reduce_only: Option<bool>,
+    #[serde(default)]
+    pub maker: Option<bool>,
+}
+
+/// A single position entry pushed on the private `position` topic.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionUpdateEntry {
+    pub symbol: String,
+    pub position_qty: f64,
+    #[serde(default)]
+    pub cost_position: Option<f64>,
+    #[serde(default)]
+    pub last_sum_unitary_funding: Option<f64>,
+    #[serde(default)]
+    pub pending_long_qty: Option<f64>,
+    #[serde(default)]
+    pub pending_short_qty: Option<f64>,
+    #[serde(default)]
+    pub settle_price: Option<f64>,
+    #[serde(default)]
+    pub average_open_price: Option<f64>,
+    #[serde(default)]
+    pub unsettled_pnl: Option<f64>,
+    #[serde(default)]
+    pub mark_price: Option<f64>,
+    #[serde(default)]
+    pub est_liq_price: Option<f64>,
+    #[serde(default)]
+    pub timestamp: Option<u64>,
+    #[serde(default)]
+    pub imr: Option<f64>,
+    #[serde(default)]
+    pub mmr: Option<f64>,
+    #[serde(default, rename = "IMRwithOrders")]
+    pub imr_with_orders: Option<f64>,
+    #[serde(default, rename = "MMRwithOrders")]
+    pub mmr_with_orders: Option<f64>,
+    #[serde(default, rename = "pnl24H")]
+    pub pnl_24_h: Option<f64>,
+    #[serde(default, rename = "fee24H")]
+    pub fee_24_h: Option<f64>,
+}
+
+/// Payload of the private `position` topic.
+#[derive(Deserialize, Debug, Clone)]
+pub struct PositionUpdate {
+    #[serde(default)]
+    pub positions: Vec<PositionUpdateEntry>,
+}
+
+/// Balance details for a single token pushed on the private `balance` topic.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceUpdateEntry {
+    pub holding: f64,
+    #[serde(default)]
+    pub frozen: Option<f64>,
+    #[serde(default)]
+    pub interest: Option<f64>,
+    #[serde(default)]
+    pub pending_short_qty: Option<f64>,
+    #[serde(default)]
+    pub pending_exposure: Option<f64>,
+    #[serde(default)]
+    pub pending_long_qty: Option<f64>,
+    #[serde(default)]
+    pub pending_long_exposure: Option<f64>,
+    #[serde(default)]
+    pub version: Option<u64>,
+    #[serde(default)]
+    pub staked: Option<f64>,
+    #[serde(default)]
+    pub unbonding: Option<f64>,
+    #[serde(default)]
+    pub vault: Option<f64>,
+    #[serde(default)]
+    pub average_open_price: Option<f64>,
+    #[serde(default, rename = "pnl24H")]
+    pub pnl_24_h: Option<f64>,
+    #[serde(default, rename = "fee24H")]
+    pub fee_24_h: Option<f64>,
+    #[serde(default)]
+    pub mark_price: Option<f64>,
+}
+
+/// Payload of the private `balance` topic, keyed by token (e.g., "USDC").
+#[derive(Deserialize, Debug, Clone)]
+pub struct BalanceUpdate {
+    #[serde(default)]
+    pub balances: std::collections::HashMap<String, BalanceUpdateEntry>,
+}
+
+/// Represents different types of parsed WebSocket messages from public and private streams.
 #[derive(Debug, Clone)]
 pub enum WebSocketMessage {
     Orderbook(OrderbookData),
     Ticker(TickerData),
     Trade(TradeData),
-    Liquidation(WebSocketLiquidationData),
-    Ping { ts: u64 },
+    /// Every liquidation event carried by one `liquidation` push frame.
+    Liquidation(Vec<WebSocketLiquidationData>),
+    ExecutionReport(ExecutionReport),
+    PositionUpdate(PositionUpdate),
+    BalanceUpdate(BalanceUpdate),
+    Ping {
+        ts: u64,
+    },
     Other,
 }
 
@@ -1665,6 +2930,15 @@ impl<'de> serde::Deserialize<'de> for WebSocketMessage {
                         serde_json::from_value(value.clone()).map_err(D::Error::custom)?;
                     Ok(WebSocketMessage::Ticker(data.data))
                 }
+                t if t.ends_with("@ticker") => {
+                    // Per-symbol 24h ticker, as subscribed by `subscribe_ticker`
+                    let data = value
+                        .get("data")
+                        .ok_or_else(|| D::Error::custom("missing data field"))?;
+                    let ticker: TickerData =
+                        serde_json::from_value(data.clone()).map_err(D::Error::custom)?;
+                    Ok(WebSocketMessage::Ticker(ticker))
+                }
                 t if t.starts_with("trade:") => {
                     let data: WebSocketTradeData =
                         serde_json::from_value(value.clone()).map_err(D::Error::custom)?;
@@ -1673,11 +2947,31 @@ impl<'de> serde::Deserialize<'de> for WebSocketMessage {
                 t if t.starts_with("liquidation") => {
                     let data: WebSocketLiquidationMessage =
                         serde_json::from_value(value.clone()).map_err(D::Error::custom)?;
-                    if let Some(first) = data.data.into_iter().next() {
-                        Ok(WebSocketMessage::Liquidation(first))
-                    } else {
-                        Ok(WebSocketMessage::Other)
-                    }
+                    Ok(WebSocketMessage::Liquidation(data.data))
+                }
+                "executionreport" | "execution" => {
+                    let data = value
+                        .get("data")
+                        .ok_or_else(|| D::Error::custom("missing data field"))?;
+                    let report: ExecutionReport =
+                        serde_json::from_value(data.clone()).map_err(D::Error::custom)?;
+                    Ok(WebSocketMessage::ExecutionReport(report))
+                }
+                "position" => {
+                    let data = value
+                        .get("data")
+                        .ok_or_else(|| D::Error::custom("missing data field"))?;
+                    let update: PositionUpdate =
+                        serde_json::from_value(data.clone()).map_err(D::Error::custom)?;
+                    Ok(WebSocketMessage::PositionUpdate(update))
+                }
+                "balance" => {
+                    let data = value
+                        .get("data")
+                        .ok_or_else(|| D::Error::custom("missing data field"))?;
+                    let update: BalanceUpdate =
+                        serde_json::from_value(data.clone()).map_err(D::Error::custom)?;
+                    Ok(WebSocketMessage::BalanceUpdate(update))
                 }
                 _ => Ok(WebSocketMessage::Other),
             }
@@ -1687,7 +2981,55 @@ impl<'de> serde::Deserialize<'de> for WebSocketMessage {
     }
 }
 
+/// A parsed [`WebSocketMessage`] together with the frame's server timestamp and the local
+/// time at which it was parsed.
+///
+/// Use [`latency`](Self::latency) to monitor feed health: a steadily growing value means
+/// the feed is lagging behind wall clock.
+#[derive(Debug, Clone)]
+pub struct TimestampedMessage {
+    pub message: WebSocketMessage,
+    /// Server timestamp (ms) from the frame's top-level `ts` field, if present
+    pub ts: Option<u64>,
+    /// Local wall-clock time (ms) at which the frame was parsed
+    pub received_at: u64,
+}
+
+impl TimestampedMessage {
+    /// Returns `received_at - ts`, or `None` if the frame carried no `ts`.
+    ///
+    /// Clamped to zero when the local clock is behind the server's.
+    pub fn latency(&self) -> Option<std::time::Duration> {
+        self.ts
+            .map(|ts| std::time::Duration::from_millis(self.received_at.saturating_sub(ts)))
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TimestampedMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use serde::de::Error;
+        let received_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let ts = value.get("ts").and_then(|v| v.as_u64());
+        let message = WebSocketMessage::deserialize(value).map_err(D::Error::custom)?;
+        Ok(TimestampedMessage {
+            message,
+            ts,
+            received_at,
+        })
+    }
+}
+
 /// Represents the REST orderbook snapshot response data.
+///
+/// Orderly does not document the order of `asks` and `bids`. Responses are observed
+/// best-first, but callers that rely on ordering should call [`Self::normalize`].
 #[derive(Deserialize, Debug, Clone)]
 pub struct OrderbookSnapshotData {
     pub asks: Vec<OrderbookLevel>,
@@ -1695,6 +3037,71 @@ pub struct OrderbookSnapshotData {
     pub timestamp: u64,
 }
 
+/// Drops zero-quantity levels, keeps the last level seen at each price and sorts by
+/// price, ascending or descending.
+fn normalize_levels(levels: &mut Vec<OrderbookLevel>, descending: bool) {
+    let mut by_price: Vec<OrderbookLevel> = Vec::with_capacity(levels.len());
+    for level in levels.drain(..) {
+        match by_price.iter_mut().find(|l| l.price == level.price) {
+            Some(existing) => existing.quantity = level.quantity,
+            None => by_price.push(level),
+        }
+    }
+    by_price.retain(|l| l.quantity > 0.0);
+    by_price.sort_by(|a, b| a.price.total_cmp(&b.price));
+    if descending {
+        by_price.reverse();
+    }
+    *levels = by_price;
+}
+
+impl OrderbookSnapshotData {
+    /// Sorts asks ascending and bids descending by price, so index 0 is the best level
+    /// on each side, and removes zero-quantity and duplicate price levels.
+    ///
+    /// When a price appears more than once on a side, the last level wins.
+    pub fn normalize(&mut self) {
+        normalize_levels(&mut self.asks, false);
+        normalize_levels(&mut self.bids, true);
+    }
+
+    /// Returns the snapshot after [`Self::normalize`].
+    pub fn normalized(mut self) -> Self {
+        self.normalize();
+        self
+    }
+
+    /// Returns the lowest ask price with a non-zero quantity.
+    pub fn best_ask(&self) -> Option<f64> {
+        self.asks
+            .iter()
+            .filter(|l| l.quantity > 0.0)
+            .map(|l| l.price)
+            .min_by(f64::total_cmp)
+    }
+
+    /// Returns the highest bid price with a non-zero quantity.
+    pub fn best_bid(&self) -> Option<f64> {
+        self.bids
+            .iter()
+            .filter(|l| l.quantity > 0.0)
+            .map(|l| l.price)
+            .max_by(f64::total_cmp)
+    }
+
+    /// Returns best ask minus best bid, or `None` if either side is empty.
+    ///
+    /// A negative value means the book is crossed. Does not depend on level order.
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.best_ask()? - self.best_bid()?)
+    }
+
+    /// Returns the midpoint of the best ask and best bid, or `None` if either side is empty.
+    pub fn mid_price(&self) -> Option<f64> {
+        Some((self.best_ask()? + self.best_bid()?) / 2.0)
+    }
+}
+
 /// Type alias for the REST orderbook snapshot response.
 pub type GetOrderbookSnapshotResponse = SuccessResponse<OrderbookSnapshotData>;
 
@@ -1713,6 +3120,16 @@ pub struct WalletRegisteredData {
     // Add other fields if the API returns more info
 }
 
+/// Account id registered for a wallet address under a broker
+#[derive(Deserialize, Debug, Clone)]
+pub struct AccountIdData {
+    #[serde(default)]
+    pub user_id: Option<u64>,
+    pub account_id: String,
+}
+
+pub type GetAccountIdResponse = SuccessResponse<AccountIdData>;
+
 // Response for GET /v1/registration_nonce
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct RegistrationNonceResponse {
@@ -1742,7 +3159,7 @@ pub struct RegisterAccountMessage<'a> {
     #[serde(rename = "brokerId")]
     pub broker_id: &'a str,
     #[serde(rename = "chainId")]
-    pub chain_id: u64,
+    pub chain_id: ChainId,
     #[serde(rename = "chainType")]
     pub chain_type: &'a str, // Should be "SOL"
     pub timestamp: u64,
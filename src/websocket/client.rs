@@ -1,17 +1,23 @@
 use crate::auth; // Import the auth module
 use crate::error::{OrderlyError, Result};
-use futures_util::{SinkExt, StreamExt};
+use crate::types::{KlineInterval, TimestampedMessage, WebSocketMessage};
+use crate::websocket::dedup::{ExecutionReportDeduplicator, DEFAULT_DEDUP_CAPACITY};
+use futures_util::{SinkExt, Stream, StreamExt};
 use log::*;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
-use tokio::sync::{mpsc, Mutex};
+use std::task::{Context, Poll};
+use std::time::Instant;
+use tokio::sync::mpsc::error::{SendTimeoutError, TrySendError};
+use tokio::sync::{mpsc, watch, Mutex};
 use tokio::task::JoinHandle;
-use tokio::time::{sleep, Duration};
+use tokio::time::{interval, sleep, timeout, Duration};
 use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
 use url::Url;
-use uuid;
 
 // EVM endpoints for Orderly public WebSocket
 const MAINNET_WS_PUBLIC_URL: &str = "wss://ws-evm.orderly.org/ws/stream";
@@ -21,6 +27,12 @@ const TESTNET_WS_PRIVATE_URL: &str =
     "wss://testnet-ws-private.orderly.network/v2/ws/private/stream";
 const MAX_RETRIES: u32 = 30; // Max number of consecutive reconnect attempts
 const RETRY_DELAY_SECS: u64 = 5; // Delay between reconnect attempts
+const DEFAULT_HEARTBEAT_TIMEOUT_SECS: u64 = 30; // Silence after which a connection is treated as stale
+const DEFAULT_PING_INTERVAL_SECS: u64 = 10; // Interval between client-initiated pings
+const DEFAULT_CHANNEL_CAPACITY: usize = 32; // Outbound message buffer size
+const SEND_TIMEOUT_MS: u64 = 1000; // Max wait for outbound channel capacity
+const STOP_GRACE_PERIOD_SECS: u64 = 5; // Max wait for the socket to close on stop()
+const STREAM_BUFFER_SIZE: usize = 1024; // Parsed messages buffered for a WebsocketMessageStream
 
 /// WebSocket client implementation for the Orderly Network API.
 ///
@@ -47,7 +59,7 @@ const RETRY_DELAY_SECS: u64 = 5; // Delay between reconnect attempts
 ///
 /// Messages are handled through two callback mechanisms:
 /// - `on_message`: Called for each received message
-/// - `on_close`: Called when the connection is closed
+/// - `on_close`: Called with a [`CloseReason`] when the connection is closed
 ///
 /// # Examples
 ///
@@ -62,8 +74,8 @@ const RETRY_DELAY_SECS: u64 = 5; // Delay between reconnect attempts
 ///         println!("Received: {}", msg);
 ///     });
 ///
-///     let close_handler = Arc::new(|| {
-///         println!("Connection closed");
+///     let close_handler = Arc::new(|reason| {
+///         println!("Connection closed: {:?}", reason);
 ///     });
 ///
 ///     let client = WebsocketPublicClient::connect(
@@ -78,7 +90,7 @@ const RETRY_DELAY_SECS: u64 = 5; // Delay between reconnect attempts
 ///
 ///     // Keep the connection alive
 ///     tokio::signal::ctrl_c().await.expect("Failed to listen for ctrl+c");
-///     client.stop().await;
+///     client.stop().await.expect("Failed to stop client");
 /// }
 /// ```
 ///
@@ -93,8 +105,8 @@ const RETRY_DELAY_SECS: u64 = 5; // Delay between reconnect attempts
 ///         println!("Received: {}", msg);
 ///     });
 ///
-///     let close_handler = Arc::new(|| {
-///         println!("Connection closed");
+///     let close_handler = Arc::new(|reason| {
+///         println!("Connection closed: {:?}", reason);
 ///     });
 ///
 ///     let client = WebsocketPrivateClient::connect(
@@ -111,7 +123,7 @@ const RETRY_DELAY_SECS: u64 = 5; // Delay between reconnect attempts
 ///
 ///     // Keep the connection alive
 ///     tokio::signal::ctrl_c().await.expect("Failed to listen for ctrl+c");
-///     client.stop().await;
+///     client.stop().await.expect("Failed to stop client");
 /// }
 /// ```
 
@@ -127,6 +139,18 @@ const RETRY_DELAY_SECS: u64 = 5; // Delay between reconnect attempts
 /// * `orderly_secret` - Optional Orderly API secret (required for private endpoints)
 /// * `orderly_account_id` - Your Orderly account ID
 /// * `wss_id` - Optional WebSocket request ID for message tracking
+/// * `heartbeat_timeout_secs` - Optional number of seconds without any inbound message
+///   before the connection is dropped and re-established (defaults to 30)
+/// * `channel_capacity` - Optional size of the outbound message buffer (defaults to 32)
+/// * `max_subscriptions` - Optional cap on the number of topics subscribed on one
+///   connection; subscribing beyond it fails locally (unlimited if `None`)
+/// * `ping_interval_secs` - Optional interval between client-initiated
+///   `{"event":"ping"}` frames (defaults to 10; `Some(0)` disables them)
+/// * `dedup_execution_reports` - Optional number of recent execution report events
+///   remembered so redeliveries after a reconnect are dropped (private streams only)
+///
+/// Connections are always uncompressed: the `tokio-tungstenite` version this crate
+/// depends on does not implement permessage-deflate.
 ///
 /// # Examples
 ///
@@ -139,43 +163,701 @@ const RETRY_DELAY_SECS: u64 = 5; // Delay between reconnect attempts
 ///     orderly_secret: None,
 ///     orderly_account_id: "your_account_id".to_string(),
 ///     wss_id: None,
+///     heartbeat_timeout_secs: Some(20),
+///     channel_capacity: None,
+///     max_subscriptions: Some(50),
+///     ping_interval_secs: Some(15),
+///     dedup_execution_reports: None,
 /// };
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct WebsocketClientConfig {
     /// The WebSocket URL to connect to (public/private, mainnet/testnet)
     pub base_url: String,
     /// Optional Orderly API key (required for private endpoints)
     pub orderly_key: Option<String>,
-    /// Optional Orderly API secret (required for private endpoints)
+    /// Optional Orderly API secret (required for private endpoints).
+    ///
+    /// Read when deserializing but never serialized, so a saved config holds no secret.
+    #[serde(skip_serializing)]
     pub orderly_secret: Option<String>,
     /// Your Orderly account ID
     pub orderly_account_id: String,
     /// Optional WebSocket request ID for message tracking
     pub wss_id: Option<String>,
+    /// Seconds without any inbound message (including pings) before the connection
+    /// is considered stale and forcibly closed. Defaults to 30 seconds.
+    #[serde(default)]
+    pub heartbeat_timeout_secs: Option<u64>,
+    /// Capacity of the outbound message channel feeding the writer task. Defaults to 32.
+    #[serde(default)]
+    pub channel_capacity: Option<usize>,
+    /// Maximum number of topics subscribed on one connection. Orderly silently drops
+    /// subscriptions beyond its per-connection cap, so `subscribe_*` calls that would exceed
+    /// this limit return `OrderlyError::ValidationError` instead. Unlimited if `None`.
+    #[serde(default)]
+    pub max_subscriptions: Option<usize>,
+    /// Seconds between application-level `{"event":"ping","ts":...}` frames sent by the
+    /// client, which keep NAT mappings and idle timeouts from dropping a quiet connection.
+    ///
+    /// `None` (the `Default`) keeps the client's existing behaviour of pinging every 10
+    /// seconds. Those pings draw the replies that stop the heartbeat watchdog from
+    /// reconnecting a quiet connection, so pinging stays on unless `Some(0)` disables it.
+    #[serde(default)]
+    pub ping_interval_secs: Option<u64>,
+    /// Number of recent execution report events remembered to drop redeliveries after a
+    /// reconnect, before messages reach `on_message` or a message stream. Private streams
+    /// only; disabled if `None`.
+    #[serde(default)]
+    pub dedup_execution_reports: Option<usize>,
+}
+
+impl std::fmt::Debug for WebsocketClientConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never print the key or secret
+        let redact = |value: &Option<String>| value.as_ref().map(|_| auth::REDACTED);
+        f.debug_struct("WebsocketClientConfig")
+            .field("base_url", &self.base_url)
+            .field("orderly_key", &redact(&self.orderly_key))
+            .field("orderly_secret", &redact(&self.orderly_secret))
+            .field("orderly_account_id", &self.orderly_account_id)
+            .field("wss_id", &self.wss_id)
+            .field("heartbeat_timeout_secs", &self.heartbeat_timeout_secs)
+            .field("channel_capacity", &self.channel_capacity)
+            .field("max_subscriptions", &self.max_subscriptions)
+            .field("ping_interval_secs", &self.ping_interval_secs)
+            .field("dedup_execution_reports", &self.dedup_execution_reports)
+            .finish()
+    }
+}
+
+/// Optional callbacks passed to `connect_with` alongside `on_message` and `on_close`.
+///
+/// Both default to `None`, so only the callbacks of interest need to be set.
+///
+/// # Examples
+///
+/// ```no_run
+/// use orderly_connector_rs::websocket::{
+///     ClientHandlers, WebsocketClientConfig, WebsocketPrivateClient,
+/// };
+/// use std::sync::Arc;
+///
+/// # async fn run(config: WebsocketClientConfig) {
+/// let handlers = ClientHandlers {
+///     on_error: Some(Arc::new(|e| eprintln!("Connection error: {}", e))),
+///     on_resync: Some(Arc::new(|| {
+///         tokio::spawn(async {
+///             // Fetch open orders and positions over REST and replace local state
+///         });
+///     })),
+/// };
+/// let client = WebsocketPrivateClient::connect_with(
+///     config,
+///     Arc::new(|msg: String| println!("Received: {}", msg)),
+///     Arc::new(|reason| println!("Connection closed: {:?}", reason)),
+///     handlers,
+/// )
+/// .await
+/// .expect("Failed to connect");
+/// # }
+/// ```
+#[derive(Clone, Default)]
+pub struct ClientHandlers {
+    /// Called with the cause whenever a connection fails: failing to connect, a read or
+    /// write error, or a heartbeat timeout. Transport failures arrive as
+    /// `OrderlyError::NetworkError`, protocol failures as `OrderlyError::WebsocketError`
+    /// and heartbeat timeouts as `OrderlyError::Timeout`. On the private stream, failing
+    /// to send the authentication message is reported too, and the server rejecting it
+    /// arrives as `OrderlyError::AuthenticationError`. A normal close by the server is
+    /// not reported.
+    pub on_error: Option<Arc<dyn Fn(OrderlyError) + Send + Sync + 'static>>,
+    /// Called after every successful connect, including reconnects, once the connection
+    /// is authenticated (private streams) and all tracked subscriptions have been replayed.
+    ///
+    /// Updates sent while the connection was down are lost, so local state built from
+    /// the stream alone is stale after a reconnect; use this to rebuild it from a REST
+    /// snapshot (e.g. `OrderlyService::get_orders` and `OrderlyService::get_positions`)
+    /// and keep applying deltas on top of it. The callback runs on the connection manager
+    /// task and must not block; spawn a task for any REST calls.
+    pub on_resync: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+}
+
+impl std::fmt::Debug for ClientHandlers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientHandlers")
+            .field("on_error", &self.on_error.is_some())
+            .field("on_resync", &self.on_resync.is_some())
+            .finish()
+    }
 }
 
 // Type alias for shared subscription state
 type SubscriptionState = Arc<Mutex<HashSet<String>>>;
 // Type alias for shared sender channel
 type SharedSender = Arc<Mutex<Option<mpsc::Sender<Message>>>>;
+// Type alias for the shared subscription message id -> topic mapping
+type SubscriptionIds = Arc<Mutex<HashMap<String, String>>>;
+// Type alias for the shared topic -> live SubscriptionHandle count
+type HandleCounts = Arc<Mutex<HashMap<String, HandleCount>>>;
+
+/// Live [`SubscriptionHandle`]s on one topic.
+#[derive(Debug)]
+struct HandleCount {
+    // Distinguishes this run of handles from ones made before an explicit unsubscribe
+    generation: u64,
+    handles: usize,
+}
+
+/// Source of `HandleCount::generation` values.
+static HANDLE_GENERATIONS: AtomicU64 = AtomicU64::new(1);
+
+/// Connection counters shared between a client and its manager task.
+#[derive(Debug, Default)]
+struct ConnectionStats {
+    connections: AtomicU64, // Successful connections, including the first
+    connected_since: std::sync::Mutex<Option<Instant>>, // Start of the live session, if any
+}
+
+impl ConnectionStats {
+    fn record_connected(&self) {
+        self.connections.fetch_add(1, Ordering::Relaxed);
+        *self.lock_connected_since() = Some(Instant::now());
+    }
+
+    fn record_disconnected(&self) {
+        *self.lock_connected_since() = None;
+    }
+
+    fn reconnect_count(&self) -> u64 {
+        self.connections.load(Ordering::Relaxed).saturating_sub(1)
+    }
+
+    fn connected_since(&self) -> Option<Instant> {
+        *self.lock_connected_since()
+    }
+
+    fn lock_connected_since(&self) -> std::sync::MutexGuard<'_, Option<Instant>> {
+        // The guarded value is a plain Option, so a poisoned lock is still usable
+        self.connected_since
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Generates a unique `id` for an outgoing request message.
+fn new_request_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// Builds the `@kline_*` topic for `symbol` at `interval`, e.g. `PERP_ETH_USDC@kline_1h`.
+fn kline_topic(symbol: &str, interval: KlineInterval) -> String {
+    format!("{}@kline_{}", symbol, interval)
+}
+
+/// Removes every stored subscription message whose `topic` matches the given topic.
+///
+/// Subscribe and unsubscribe messages carry different `id`/`event` fields, so the
+/// persisted subscribe message has to be located by topic rather than by exact text.
+fn remove_topic(subscriptions: &mut HashSet<String>, topic: &str) {
+    subscriptions.retain(|msg_str| {
+        serde_json::from_str::<Value>(msg_str)
+            .ok()
+            .and_then(|v| v.get("topic").and_then(|t| t.as_str()).map(|t| t != topic))
+            .unwrap_or(true)
+    });
+}
+
+/// Fails if `subscriptions` already holds `max_subscriptions` topics.
+///
+/// Called after any earlier message for the topic being subscribed has been removed, so
+/// re-subscribing to a tracked topic never counts against the limit.
+fn check_subscription_limit(
+    subscriptions: &HashSet<String>,
+    max_subscriptions: Option<usize>,
+) -> Result<()> {
+    match max_subscriptions {
+        Some(max) if subscriptions.len() >= max => Err(OrderlyError::ValidationError(format!(
+            "Subscription limit of {} topics per connection reached",
+            max
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Builds the `{"event":"pong"}` reply for an application-level `{"event":"ping"}` frame.
+///
+/// The server's `ts` is echoed back so it can match the reply to its ping. Returns `None`
+/// for any other message.
+fn pong_reply(text: &str) -> Option<String> {
+    let value: Value = serde_json::from_str(text).ok()?;
+    if value.get("event").and_then(|e| e.as_str()) != Some("ping") {
+        return None;
+    }
+    let ts = value
+        .get("ts")
+        .and_then(|ts| ts.as_u64())
+        .unwrap_or_else(|| auth::get_timestamp_ms().unwrap_or_default());
+    Some(json!({ "event": "pong", "ts": ts }).to_string())
+}
+
+/// Enqueues `text` on the live connection, failing if the client is disconnected.
+async fn send_text(shared_tx: &SharedSender, text: &str) -> Result<()> {
+    let guard = shared_tx.lock().await;
+    if let Some(tx) = guard.as_ref() {
+        enqueue(tx, Message::Text(text.to_string())).await
+    } else {
+        Err(OrderlyError::WebsocketError("Not connected".to_string()))
+    }
+}
+
+/// Removes the message's topic from the persisted subscriptions, then sends it.
+async fn send_unsubscribe(
+    shared_tx: &SharedSender,
+    subscriptions: &SubscriptionState,
+    subscription_ids: &SubscriptionIds,
+    topic_msg: Value,
+) -> Result<()> {
+    let msg_str = serde_json::to_string(&topic_msg)?;
+    // Remove from subscriptions *before* sending
+    if let Some(topic) = topic_msg.get("topic").and_then(|t| t.as_str()) {
+        remove_topic(&mut *subscriptions.lock().await, topic);
+        subscription_ids.lock().await.retain(|_, t| t != topic);
+    }
+    send_text(shared_tx, &msg_str).await
+}
+
+/// Releases one [`SubscriptionHandle`] on `topic`, unsubscribing once no other handle
+/// holds the topic.
+///
+/// Does nothing if the topic was unsubscribed explicitly after the handle was made, so
+/// the handle cannot remove a later subscription of the same topic.
+async fn release_handle(
+    shared_tx: &SharedSender,
+    subscriptions: &SubscriptionState,
+    subscription_ids: &SubscriptionIds,
+    handle_counts: &HandleCounts,
+    topic: &str,
+    generation: u64,
+) -> Result<()> {
+    let mut counts = handle_counts.lock().await;
+    match counts.get_mut(topic) {
+        Some(count) if count.generation == generation && count.handles > 1 => {
+            count.handles -= 1;
+            return Ok(());
+        }
+        Some(count) if count.generation == generation => {
+            counts.remove(topic);
+        }
+        _ => return Ok(()),
+    }
+    send_unsubscribe(
+        shared_tx,
+        subscriptions,
+        subscription_ids,
+        unsubscribe_message(topic),
+    )
+    .await
+}
+
+/// Builds the unsubscribe message for `topic`.
+fn unsubscribe_message(topic: &str) -> Value {
+    json!({
+        "id": new_request_id(),
+        "topic": topic,
+        "event": "unsubscribe"
+    })
+}
+
+/// Owns a subscription made with `subscribe_with_handle` and unsubscribes when dropped.
+///
+/// Dropping the handle spawns the unsubscribe on the current Tokio runtime, so a
+/// component that goes away cannot leak its subscription. Call [`Self::unsubscribe`] to
+/// unsubscribe and observe the result, or [`Self::detach`] to keep the subscription
+/// without a handle. A handle dropped outside a runtime only logs a warning.
+///
+/// Handles on the same topic are counted: the subscribe message is sent for the first
+/// one and the unsubscribe only once the last one is released. A detached handle keeps
+/// its count, so the topic then stays subscribed. An explicit `unsubscribe_*` call for
+/// the topic releases all of its handles at once; dropping them afterwards sends nothing.
+///
+/// The last handle removes its topic regardless of how else it was subscribed, so do
+/// not mix handles with the client's own `subscribe_*` calls for the same topic.
+#[derive(Debug)]
+pub struct SubscriptionHandle {
+    topic: String,
+    shared_tx: SharedSender,
+    subscriptions: SubscriptionState,
+    subscription_ids: SubscriptionIds,
+    handle_counts: HandleCounts,
+    generation: u64,
+    // Cleared once the subscription has been released or detached
+    armed: bool,
+}
+
+impl SubscriptionHandle {
+    /// Returns the subscribed topic.
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+
+    /// Releases the handle now, unsubscribing if no other handle holds the topic, and
+    /// returns the result of sending the unsubscribe message.
+    ///
+    /// The topic is removed from the persisted subscription set even if sending fails.
+    pub async fn unsubscribe(mut self) -> Result<()> {
+        self.armed = false;
+        release_handle(
+            &self.shared_tx,
+            &self.subscriptions,
+            &self.subscription_ids,
+            &self.handle_counts,
+            &self.topic,
+            self.generation,
+        )
+        .await
+    }
+
+    /// Drops the handle without unsubscribing.
+    pub fn detach(mut self) {
+        self.armed = false;
+    }
+}
+
+impl Drop for SubscriptionHandle {
+    fn drop(&mut self) {
+        if !self.armed {
+            return;
+        }
+        match tokio::runtime::Handle::try_current() {
+            Ok(runtime) => {
+                let shared_tx = Arc::clone(&self.shared_tx);
+                let subscriptions = Arc::clone(&self.subscriptions);
+                let subscription_ids = Arc::clone(&self.subscription_ids);
+                let handle_counts = Arc::clone(&self.handle_counts);
+                let generation = self.generation;
+                let topic = std::mem::take(&mut self.topic);
+                runtime.spawn(async move {
+                    if let Err(e) = release_handle(
+                        &shared_tx,
+                        &subscriptions,
+                        &subscription_ids,
+                        &handle_counts,
+                        &topic,
+                        generation,
+                    )
+                    .await
+                    {
+                        debug!("Unsubscribe of dropped handle for {} failed: {}", topic, e);
+                    }
+                });
+            }
+            Err(_) => warn!(
+                "SubscriptionHandle for {} dropped outside a Tokio runtime; topic left subscribed",
+                self.topic
+            ),
+        }
+    }
+}
+
+/// Yielded by a [`WebsocketMessageStream`] in place of messages dropped because its
+/// buffer was full, carrying the number of messages lost at that point in the stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lagged(pub u64);
+
+impl std::fmt::Display for Lagged {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "message stream lagged; {} messages dropped", self.0)
+    }
+}
+
+impl std::error::Error for Lagged {}
+
+type StreamItem = std::result::Result<TimestampedMessage, Lagged>;
+
+/// A stream of parsed messages returned by the `connect_stream` constructors.
+///
+/// Messages are buffered in a bounded channel of 1024 entries between the connection's
+/// reader task and the consumer. The reader must keep up with pings to hold the
+/// connection open, so it never waits for a slow consumer: once the buffer is full,
+/// newly received messages are dropped and the stream yields `Err(Lagged(n))` where
+/// they would have appeared, before the next message that fits. Consumers that keep
+/// local state (an order book, positions) should resynchronise on `Lagged`. Raw
+/// messages that cannot be parsed into a [`WebSocketMessage`] are skipped.
+///
+/// The stream ends once the client is stopped and its connection tasks have finished.
+///
+/// Call [`timestamped`](Self::timestamped) to receive each message with its server `ts`
+/// and latency instead.
+#[derive(Debug)]
+pub struct WebsocketMessageStream {
+    rx: mpsc::Receiver<StreamItem>,
+}
+
+impl WebsocketMessageStream {
+    /// Converts this into a stream of [`TimestampedMessage`]s.
+    ///
+    /// The local receive time is taken when the reader parses the frame, so
+    /// [`TimestampedMessage::latency`] does not include time spent in the buffer.
+    pub fn timestamped(self) -> TimestampedMessageStream {
+        TimestampedMessageStream { rx: self.rx }
+    }
+}
+
+impl Stream for WebsocketMessageStream {
+    type Item = std::result::Result<WebSocketMessage, Lagged>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx
+            .poll_recv(cx)
+            .map(|item| item.map(|item| item.map(|timestamped| timestamped.message)))
+    }
+}
+
+/// A [`WebsocketMessageStream`] that yields each message with its timestamps, returned by
+/// [`WebsocketMessageStream::timestamped`]. Dropped messages are reported the same way.
+#[derive(Debug)]
+pub struct TimestampedMessageStream {
+    rx: mpsc::Receiver<StreamItem>,
+}
+
+impl Stream for TimestampedMessageStream {
+    type Item = StreamItem;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.rx.poll_recv(cx)
+    }
+}
+
+/// Creates an `on_message` callback that parses messages and feeds them to a
+/// [`WebsocketMessageStream`].
+fn message_stream() -> (
+    Arc<dyn Fn(String) + Send + Sync + 'static>,
+    WebsocketMessageStream,
+) {
+    let (tx, rx) = mpsc::channel::<StreamItem>(STREAM_BUFFER_SIZE);
+    // Messages dropped since the last item that made it into the buffer
+    let dropped = AtomicU64::new(0);
+    let on_message = Arc::new(move |text: String| {
+        let msg = match serde_json::from_str::<TimestampedMessage>(&text) {
+            Ok(msg) => msg,
+            Err(e) => {
+                debug!("Skipping unparseable WebSocket message ({}): {}", e, text);
+                return;
+            }
+        };
+        let lagged = dropped.load(Ordering::Relaxed);
+        if lagged > 0 {
+            if let Err(TrySendError::Full(_)) = tx.try_send(Err(Lagged(lagged))) {
+                dropped.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+            dropped.store(0, Ordering::Relaxed);
+        }
+        if let Err(TrySendError::Full(_)) = tx.try_send(Ok(msg)) {
+            if dropped.fetch_add(1, Ordering::Relaxed) == 0 {
+                warn!("Message stream buffer full; dropping messages.");
+            }
+        }
+    });
+    (on_message, WebsocketMessageStream { rx })
+}
+
+/// Wraps `on_message` so that execution reports already delivered are dropped before
+/// reaching it, whether it is a user callback or the feed of a message stream.
+///
+/// The deduplicator outlives individual connections, so events redelivered after a
+/// reconnect are recognised.
+fn dedup_execution_reports(
+    on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
+    capacity: usize,
+) -> Arc<dyn Fn(String) + Send + Sync + 'static> {
+    let dedup = std::sync::Mutex::new(ExecutionReportDeduplicator::with_capacity(capacity));
+    Arc::new(move |text: String| {
+        if let Ok(WebSocketMessage::ExecutionReport(report)) =
+            serde_json::from_str::<WebSocketMessage>(&text)
+        {
+            let mut dedup = dedup.lock().unwrap_or_else(|e| e.into_inner());
+            if dedup.is_duplicate(&report) {
+                debug!(
+                    "Dropping redelivered execution report for order {}",
+                    report.order_id
+                );
+                return;
+            }
+        }
+        on_message(text);
+    })
+}
+
+/// Wraps a private stream's `on_message` so that the server rejecting the authentication
+/// message is reported through `on_error` as well.
+fn report_auth_rejection(
+    on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
+    on_error: Arc<dyn Fn(OrderlyError) + Send + Sync + 'static>,
+) -> Arc<dyn Fn(String) + Send + Sync + 'static> {
+    Arc::new(move |text: String| {
+        if let Some(message) = auth_rejection(&text) {
+            error!("Private WebSocket authentication rejected: {}", message);
+            on_error(OrderlyError::AuthenticationError(message));
+        }
+        on_message(text);
+    })
+}
+
+/// Returns the server's error message if `text` is a failed `auth` response.
+fn auth_rejection(text: &str) -> Option<String> {
+    if !text.contains("auth") {
+        return None;
+    }
+    let value: Value = serde_json::from_str(text).ok()?;
+    if value.get("event")?.as_str()? != "auth" || value.get("success")?.as_bool()? {
+        return None;
+    }
+    let message = value
+        .get("errorMsg")
+        .and_then(|m| m.as_str())
+        .unwrap_or("authentication rejected");
+    Some(message.to_string())
+}
+
+/// Queues an outbound message for the writer task.
+///
+/// Waits up to `SEND_TIMEOUT_MS` for buffer space so momentary backpressure does not fail
+/// the send, and reports a full channel separately from a closed one (writer task exited).
+async fn enqueue(tx: &mpsc::Sender<Message>, message: Message) -> Result<()> {
+    tx.send_timeout(message, Duration::from_millis(SEND_TIMEOUT_MS))
+        .await
+        .map_err(|e| match e {
+            SendTimeoutError::Timeout(_) => OrderlyError::WebsocketError(format!(
+                "Outbound channel full: no capacity freed within {}ms",
+                SEND_TIMEOUT_MS
+            )),
+            SendTimeoutError::Closed(_) => {
+                OrderlyError::WebsocketError("Writer task closed".to_string())
+            }
+        })
+}
+
+/// Shuts down a managed connection: aborts and awaits the manager task, then sends a
+/// Close frame and waits up to `STOP_GRACE_PERIOD_SECS` for the writer task to exit.
+///
+/// The manager handle doubles as the shutdown flag: only the first call finds it, so
+/// later calls return `Ok(())` without doing anything. The manager is cancelled whether
+/// or not a connection is live, so a client stopped during reconnect backoff does not
+/// reconnect afterwards. `on_close` is called with `CloseReason::Stopped` exactly once.
+async fn shutdown(
+    manager_handle: &Mutex<Option<JoinHandle<()>>>,
+    shared_tx: &SharedSender,
+    on_close: &Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
+) -> Result<()> {
+    // Stop the manager first so the closing socket does not trigger a reconnect
+    let handle = match manager_handle.lock().await.take() {
+        Some(handle) => handle,
+        None => {
+            debug!("Client already stopped.");
+            return Ok(());
+        }
+    };
+    handle.abort();
+    let _ = handle.await;
+
+    let tx = shared_tx.lock().await.take();
+    on_close(CloseReason::Stopped);
+    let tx = match tx {
+        Some(tx) => tx,
+        // Between connections (e.g. in reconnect backoff): nothing left to close
+        None => return Ok(()),
+    };
+    enqueue(&tx, Message::Close(None)).await?;
+
+    // The writer drops its receiver once the socket has closed and all senders are gone
+    timeout(Duration::from_secs(STOP_GRACE_PERIOD_SECS), tx.closed())
+        .await
+        .map_err(|_| {
+            OrderlyError::WebsocketError(format!(
+                "Connection did not close within {} seconds",
+                STOP_GRACE_PERIOD_SECS
+            ))
+        })
+}
+
+/// Waits until the manager task owning the matching `watch::Sender` has exited.
+///
+/// Nothing is ever sent on the channel; `changed()` only returns once the sender is dropped.
+async fn wait_terminated(mut terminated: watch::Receiver<()>) {
+    while terminated.changed().await.is_ok() {}
+}
+
+/// Converts a socket error into an `OrderlyError` for the `on_error` callback.
+///
+/// Transport failures (I/O, TLS, connection closed) map to `OrderlyError::NetworkError`;
+/// everything else, such as protocol violations or oversized frames, maps to
+/// `OrderlyError::WebsocketError`.
+fn classify_ws_error(err: &tokio_tungstenite::tungstenite::Error) -> OrderlyError {
+    use tokio_tungstenite::tungstenite::Error as WsError;
+    match err {
+        WsError::Io(_) | WsError::Tls(_) | WsError::ConnectionClosed | WsError::AlreadyClosed => {
+            OrderlyError::NetworkError(err.to_string())
+        }
+        _ => OrderlyError::WebsocketError(err.to_string()),
+    }
+}
+
+/// Why a WebSocket connection closed, passed to the `on_close` callback.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CloseReason {
+    /// The server closed the connection with a Close frame.
+    ///
+    /// A Close frame without a payload is reported with code 1005 ("no status received").
+    Frame {
+        /// The close code, e.g. 1000 for a normal closure or 1001 for "going away"
+        code: u16,
+        /// The reason text sent by the server, possibly empty
+        reason: String,
+    },
+    /// The connection failed or was lost without a Close frame: read and write errors,
+    /// heartbeat timeouts, or a failed connection attempt.
+    NetworkError(String),
+    /// The client was stopped with `stop()`.
+    Stopped,
+}
 
 /// Spawns tasks to manage a WebSocket connection, returning handles.
 ///
+/// Alongside the reader and writer, a ping task sends `{"event":"ping"}` every
+/// `config.ping_interval_secs` (default `DEFAULT_PING_INTERVAL_SECS`) unless that is 0.
+/// The reader answers both protocol-level Pings and the server's
+/// application-level `{"event":"ping"}` frames, the latter with `{"event":"pong"}`. The reader acts as a watchdog: if nothing arrives within the
+/// configured heartbeat timeout the connection is treated as stale and torn down, so
+/// the owning manager loop reconnects.
+///
+/// Read and write failures and heartbeat timeouts are reported through `on_error`
+/// before the connection is torn down.
+///
 /// Returns a tuple containing:
-/// - `JoinHandle<()>`: Handle for the combined reader/writer task group.
+/// - `JoinHandle<CloseReason>`: Handle for the combined reader/writer task group, resolving
+///   to the reason the connection ended.
 /// - `mpsc::Sender<Message>`: Channel sender to send outbound messages (Text, Pong, Close, etc.).
 async fn connect_managed(
     config: WebsocketClientConfig,
     on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
-    on_close: Arc<dyn Fn() + Send + Sync + 'static>,
-) -> Result<(JoinHandle<()>, mpsc::Sender<Message>)> {
+    on_error: Arc<dyn Fn(OrderlyError) + Send + Sync + 'static>,
+) -> Result<(JoinHandle<CloseReason>, mpsc::Sender<Message>)> {
     let url_obj = Url::parse(&config.base_url).map_err(OrderlyError::UrlParseError)?;
+    let heartbeat_timeout = Duration::from_secs(
+        config
+            .heartbeat_timeout_secs
+            .unwrap_or(DEFAULT_HEARTBEAT_TIMEOUT_SECS),
+    );
+    let ping_interval_secs = config
+        .ping_interval_secs
+        .unwrap_or(DEFAULT_PING_INTERVAL_SECS);
 
     info!("Connecting to WebSocket: {}", url_obj);
     let (ws_stream, response) = connect_async(url_obj.as_str())
         .await
-        .map_err(|e| OrderlyError::WebsocketError(format!("WebSocket connection failed: {}", e)))?;
+        .map_err(|e| classify_ws_error(&e))?;
     info!(
         "WebSocket connected successfully. Response: {:?}",
         response.status()
@@ -185,38 +867,116 @@ async fn connect_managed(
     let write = Arc::new(Mutex::new(write)); // Wrap writer in Arc<Mutex> for shared access
 
     // Channel for sending outbound messages to the writer task
-    let (tx, mut rx) = mpsc::channel::<Message>(32);
+    let capacity = config
+        .channel_capacity
+        .unwrap_or(DEFAULT_CHANNEL_CAPACITY)
+        .max(1);
+    let (tx, mut rx) = mpsc::channel::<Message>(capacity);
     let tx_clone_for_ping = tx.clone(); // Clone sender for the read task (to send pongs)
 
     // --- Writer Task ---
     // Reads messages from the channel and sends them to the WebSocket sink.
     let writer_handle = tokio::spawn({
         let write = Arc::clone(&write);
+        let on_error = Arc::clone(&on_error);
         async move {
             while let Some(message) = rx.recv().await {
                 trace!("Sending WS message: {:?}", message.to_string()); // Avoid logging sensitive data
+                let is_close = matches!(message, Message::Close(_));
                 let mut writer = write.lock().await;
                 if let Err(e) = writer.send(message).await {
                     error!("WebSocket send error: {}. Stopping writer task.", e);
+                    on_error(classify_ws_error(&e));
+                    return CloseReason::NetworkError(e.to_string());
+                }
+                if is_close {
+                    // Nothing may follow a Close frame; exiting also lets stop() observe it
                     break;
                 }
             }
             info!("WebSocket writer task finished.");
-            // Optionally notify closure if writer fails?
+            CloseReason::Stopped
+        }
+    });
+
+    // --- Ping Task ---
+    // Proactively pings the server so idle connections keep producing traffic.
+    let ping_handle = tokio::spawn({
+        let tx = tx.clone();
+        async move {
+            if ping_interval_secs == 0 {
+                debug!("Client-initiated pings disabled.");
+                return;
+            }
+            let mut ticker = interval(Duration::from_secs(ping_interval_secs));
+            ticker.tick().await; // The first tick completes immediately
+            loop {
+                ticker.tick().await;
+                let ts = auth::get_timestamp_ms().unwrap_or_default();
+                let ping = json!({ "event": "ping", "ts": ts }).to_string();
+                match tx
+                    .send_timeout(Message::Text(ping), Duration::from_millis(SEND_TIMEOUT_MS))
+                    .await
+                {
+                    Ok(()) => {}
+                    Err(SendTimeoutError::Timeout(_)) => {
+                        warn!("Outbound channel full; skipping ping.");
+                    }
+                    Err(SendTimeoutError::Closed(_)) => {
+                        debug!("Ping task stopping: writer channel closed.");
+                        break;
+                    }
+                }
+            }
         }
     });
 
     // --- Reader Task ---
     // Reads messages from the WebSocket stream, handles Pings, and calls callbacks.
+    // Doubles as the heartbeat watchdog: a read that times out ends the connection.
     let reader_handle = tokio::spawn({
         let on_message = Arc::clone(&on_message);
-        let on_close = Arc::clone(&on_close);
+        let on_error = Arc::clone(&on_error);
         async move {
-            loop {
-                match read.next().await {
+            let reason = loop {
+                let next = match timeout(heartbeat_timeout, read.next()).await {
+                    Ok(next) => next,
+                    Err(_) => {
+                        warn!(
+                            "No WebSocket message received for {:?}. Treating connection as stale.",
+                            heartbeat_timeout
+                        );
+                        let message =
+                            format!("no WebSocket message received for {:?}", heartbeat_timeout);
+                        on_error(OrderlyError::Timeout(message.clone()));
+                        break CloseReason::NetworkError(message);
+                    }
+                };
+                match next {
                     Some(Ok(msg)) => match msg {
                         Message::Text(text) => {
                             trace!("Received WS Text: {}", text);
+                            if let Some(pong) = pong_reply(&text) {
+                                trace!("Received application ping, sending pong via channel");
+                                match tx_clone_for_ping
+                                    .send_timeout(
+                                        Message::Text(pong),
+                                        Duration::from_millis(SEND_TIMEOUT_MS),
+                                    )
+                                    .await
+                                {
+                                    Ok(()) => {}
+                                    Err(SendTimeoutError::Timeout(_)) => {
+                                        warn!("Outbound channel full; dropping pong.");
+                                    }
+                                    Err(SendTimeoutError::Closed(_)) => {
+                                        error!("Failed to send pong: writer channel closed.");
+                                        break CloseReason::NetworkError(
+                                            "writer channel closed".to_string(),
+                                        );
+                                    }
+                                }
+                            }
                             on_message(text);
                         }
                         Message::Binary(bin) => {
@@ -224,13 +984,24 @@ async fn connect_managed(
                         }
                         Message::Ping(ping_data) => {
                             trace!("Received WS Ping, sending Pong via channel");
-                            if tx_clone_for_ping
-                                .send(Message::Pong(ping_data))
+                            match tx_clone_for_ping
+                                .send_timeout(
+                                    Message::Pong(ping_data),
+                                    Duration::from_millis(SEND_TIMEOUT_MS),
+                                )
                                 .await
-                                .is_err()
                             {
-                                error!("Failed to send Pong: writer channel closed.");
-                                break;
+                                Ok(()) => {}
+                                Err(SendTimeoutError::Timeout(_)) => {
+                                    // Transient backpressure: drop this Pong rather than reconnect
+                                    warn!("Outbound channel full; dropping Pong.");
+                                }
+                                Err(SendTimeoutError::Closed(_)) => {
+                                    error!("Failed to send Pong: writer channel closed.");
+                                    break CloseReason::NetworkError(
+                                        "writer channel closed".to_string(),
+                                    );
+                                }
                             }
                         }
                         Message::Pong(_) => {
@@ -238,24 +1009,34 @@ async fn connect_managed(
                         }
                         Message::Close(close_frame) => {
                             warn!("Received WS Close frame: {:?}", close_frame);
-                            break; // Exit loop
+                            break match close_frame {
+                                Some(frame) => CloseReason::Frame {
+                                    code: frame.code.into(),
+                                    reason: frame.reason.into_owned(),
+                                },
+                                None => CloseReason::Frame {
+                                    code: 1005,
+                                    reason: String::new(),
+                                },
+                            };
                         }
                         Message::Frame(_) => { /* Ignore */ }
                     },
                     Some(Err(e)) => {
                         error!("WebSocket read error: {}", e);
-                        break; // Exit loop on error
+                        on_error(classify_ws_error(&e));
+                        break CloseReason::NetworkError(e.to_string());
                     }
                     None => {
                         info!("WebSocket stream ended (read None).");
-                        break; // Stream closed
+                        break CloseReason::NetworkError("WebSocket stream ended".to_string());
                     }
                 }
-            }
+            };
             info!("WebSocket reader task finished.");
-            on_close(); // Notify external listener
-                        // Attempt to gracefully close the writer task by dropping the sender
+            // Attempt to gracefully close the writer task by dropping the sender
             drop(tx_clone_for_ping);
+            reason
         }
     });
 
@@ -263,11 +1044,14 @@ async fn connect_managed(
     // For simplicity now, just return the reader handle, assuming writer failure is handled internally.
     // A more robust approach might use select! or a dedicated manager task.
     let combined_handle = tokio::spawn(async move {
-        tokio::select! {
-            _ = reader_handle => { info!("Reader task completed."); },
-            _ = writer_handle => { info!("Writer task completed."); },
-        }
+        let finished = tokio::select! {
+            reason = reader_handle => { info!("Reader task completed."); reason },
+            reason = writer_handle => { info!("Writer task completed."); reason },
+        };
+        ping_handle.abort();
         info!("WebSocket combined task group finished.");
+        finished
+            .unwrap_or_else(|e| CloseReason::NetworkError(format!("connection task failed: {}", e)))
     });
 
     Ok((combined_handle, tx))
@@ -299,6 +1083,13 @@ async fn connect_managed(
 /// * Subscription state persistence and resubscription after reconnects
 /// * Ping/Pong message handling
 ///
+/// # Thread Safety
+///
+/// The client is `Send + Sync`: all shared state sits behind `Arc<tokio::sync::Mutex<_>>`
+/// and the callbacks are required to be `Send + Sync`. Every method takes `&self`, so an
+/// `Arc<WebsocketPublicClient>` can be shared between tasks that subscribe and unsubscribe
+/// concurrently. [`WebsocketPublicClient::spawn`] returns the client in that form.
+///
 /// # Examples
 ///
 /// Basic usage:
@@ -312,8 +1103,8 @@ async fn connect_managed(
 ///         println!("Received: {}", msg);
 ///     });
 ///
-///     let close_handler = Arc::new(|| {
-///         println!("Connection closed");
+///     let close_handler = Arc::new(|reason| {
+///         println!("Connection closed: {:?}", reason);
 ///     });
 ///
 ///     let client = WebsocketPublicClient::connect(
@@ -330,17 +1121,42 @@ async fn connect_managed(
 ///
 ///     // Keep the connection alive
 ///     tokio::signal::ctrl_c().await.expect("Failed to listen for ctrl+c");
-///     client.stop().await;
+///     client.stop().await.expect("Failed to stop client");
 /// }
 /// ```
-#[derive(Debug)]
 pub struct WebsocketPublicClient {
     // Shared sender to allow sending messages while connection task runs/reconnects
     shared_tx: SharedSender,
     // Shared state to store active subscriptions for resubscription
     subscriptions: SubscriptionState,
-    // Handle to the main connection management task
-    manager_handle: JoinHandle<()>,
+    subscription_ids: SubscriptionIds,
+    // Live SubscriptionHandle count per topic
+    handle_counts: HandleCounts,
+    // Handle to the main connection management task (taken on stop)
+    manager_handle: Mutex<Option<JoinHandle<()>>>,
+    // Close callback, also invoked with `CloseReason::Stopped` by stop()
+    on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
+    // Topic cap enforced by subscribe(), from `WebsocketClientConfig::max_subscriptions`
+    max_subscriptions: Option<usize>,
+    // Closed once the manager task has exited (stopped or out of retries)
+    terminated: watch::Receiver<()>,
+    // Reconnect count and session start, updated by the manager task
+    stats: Arc<ConnectionStats>,
+}
+
+impl std::fmt::Debug for WebsocketPublicClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // The close callback has no Debug representation
+        f.debug_struct("WebsocketPublicClient")
+            .field("shared_tx", &self.shared_tx)
+            .field("subscriptions", &self.subscriptions)
+            .field("subscription_ids", &self.subscription_ids)
+            .field("handle_counts", &self.handle_counts)
+            .field("manager_handle", &self.manager_handle)
+            .field("max_subscriptions", &self.max_subscriptions)
+            .field("stats", &self.stats)
+            .finish_non_exhaustive()
+    }
 }
 
 impl WebsocketPublicClient {
@@ -348,53 +1164,185 @@ impl WebsocketPublicClient {
         account_id: String,
         is_testnet: bool,
         on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
-        on_close: Arc<dyn Fn() + Send + Sync + 'static>,
+        on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
+    ) -> Result<Self> {
+        let base_url = if is_testnet {
+            TESTNET_WS_PUBLIC_URL
+        } else {
+            MAINNET_WS_PUBLIC_URL
+        };
+        Self::connect_with_url(base_url, account_id, on_message, on_close).await
+    }
+
+    /// Connects to an explicit public stream base URL instead of the mainnet/testnet one.
+    ///
+    /// The account ID is appended as a path segment, as with [`Self::connect`]. This allows
+    /// pointing the client at a local mock server or a custom gateway.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - The WebSocket base URL, e.g. `ws://127.0.0.1:9000/ws/stream`
+    /// * `account_id` - Your Orderly account ID
+    /// * `on_message` - Callback for each received message
+    /// * `on_close` - Callback for connection closure
+    pub async fn connect_with_url(
+        base_url: &str,
+        account_id: String,
+        on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
+        on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
+    ) -> Result<Self> {
+        let config = WebsocketClientConfig {
+            base_url: format!("{}/{}", base_url.trim_end_matches('/'), account_id),
+            orderly_account_id: account_id,
+            ..Default::default()
+        };
+        Self::connect_with_config(config, on_message, on_close).await
+    }
+
+    /// Connects and returns the client together with a stream of parsed messages.
+    ///
+    /// This is an alternative to [`Self::connect`] for consumers that prefer
+    /// `while let Some(item) = stream.next().await` over callbacks. See
+    /// [`WebsocketMessageStream`] for buffering behaviour when the consumer falls behind.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures_util::StreamExt;
+    /// use orderly_connector_rs::websocket::WebsocketPublicClient;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let (client, mut stream) =
+    ///         WebsocketPublicClient::connect_stream("your_account_id".to_string(), true)
+    ///             .await
+    ///             .expect("Failed to connect");
+    ///     client.subscribe_tickers().await.expect("Failed to subscribe");
+    ///
+    ///     while let Some(item) = stream.next().await {
+    ///         match item {
+    ///             Ok(msg) => println!("Received: {:?}", msg),
+    ///             Err(lagged) => eprintln!("{}", lagged),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn connect_stream(
+        account_id: String,
+        is_testnet: bool,
+    ) -> Result<(Self, WebsocketMessageStream)> {
+        let (on_message, stream) = message_stream();
+        let client = Self::connect(account_id, is_testnet, on_message, Arc::new(|_| {})).await?;
+        Ok((client, stream))
+    }
+
+    /// Connects using an explicit [`WebsocketClientConfig`].
+    ///
+    /// `config.base_url` must be the full public stream URL, including the trailing
+    /// account id segment. Use this to tune settings such as `heartbeat_timeout_secs`.
+    pub async fn connect_with_config(
+        config: WebsocketClientConfig,
+        on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
+        on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
     ) -> Result<Self> {
+        Self::connect_with(config, on_message, on_close, ClientHandlers::default()).await
+    }
+
+    /// Connects like [`Self::connect_with_config`] and returns the client behind an `Arc`
+    /// for sharing across tasks.
+    ///
+    /// The returned `JoinHandle` completes once the client has terminated for good: after
+    /// [`Self::stop`] or when reconnect attempts are exhausted. Await it from the task that
+    /// owns the client's lifetime, and clone the `Arc` into any task that subscribes.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use orderly_connector_rs::websocket::{WebsocketClientConfig, WebsocketPublicClient};
+    /// use std::sync::Arc;
+    ///
+    /// # async fn run(config: WebsocketClientConfig) {
+    /// let (client, handle) = WebsocketPublicClient::spawn(
+    ///     config,
+    ///     Arc::new(|msg: String| println!("Received: {}", msg)),
+    ///     Arc::new(|reason| println!("Connection closed: {:?}", reason)),
+    /// )
+    /// .await
+    /// .expect("Failed to connect");
+    ///
+    /// for symbol in ["PERP_ETH_USDC", "PERP_BTC_USDC"] {
+    ///     let client = Arc::clone(&client);
+    ///     tokio::spawn(async move { client.subscribe_trades(symbol).await });
+    /// }
+    ///
+    /// handle.await.expect("Client task panicked");
+    /// # }
+    /// ```
+    pub async fn spawn(
+        config: WebsocketClientConfig,
+        on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
+        on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
+    ) -> Result<(Arc<Self>, JoinHandle<()>)> {
+        let client = Self::connect_with_config(config, on_message, on_close).await?;
+        let handle = tokio::spawn(wait_terminated(client.terminated.clone()));
+        Ok((Arc::new(client), handle))
+    }
+
+    /// Connects like [`Self::connect_with_config`] with the optional callbacks in
+    /// `handlers`; see [`ClientHandlers`] for when each is called.
+    pub async fn connect_with(
+        config: WebsocketClientConfig,
+        on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
+        on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
+        handlers: ClientHandlers,
+    ) -> Result<Self> {
+        let on_error = handlers
+            .on_error
+            .unwrap_or_else(|| Arc::new(|_: OrderlyError| {}));
+        let on_resync = handlers.on_resync.unwrap_or_else(|| Arc::new(|| {}));
+        let max_subscriptions = config.max_subscriptions;
         let shared_tx: SharedSender = Arc::new(Mutex::new(None));
         let subscriptions: SubscriptionState = Arc::new(Mutex::new(HashSet::new()));
+        let (terminated_tx, terminated) = watch::channel(());
+        let stats = Arc::new(ConnectionStats::default());
 
         let manager_handle = tokio::spawn({
             // Clone Arcs for the manager task
             let shared_tx = Arc::clone(&shared_tx);
             let subscriptions = Arc::clone(&subscriptions);
+            let stats = Arc::clone(&stats);
             let on_message = Arc::clone(&on_message);
             let on_close = Arc::clone(&on_close);
-            let account_id = account_id.clone(); // Clone needed data
+            let on_error = Arc::clone(&on_error);
 
             async move {
+                // Dropped when the manager finishes or is aborted, waking `wait_terminated`
+                let _terminated_tx = terminated_tx;
                 let mut retries = 0;
                 loop {
-                    let base_url = (if is_testnet {
-                        TESTNET_WS_PUBLIC_URL
-                    } else {
-                        MAINNET_WS_PUBLIC_URL
-                    })
-                    .to_string();
-                    let url_with_account = format!("{}/{}", base_url, account_id);
-                    let config = WebsocketClientConfig {
-                        base_url: url_with_account,
-                        orderly_key: None,
-                        orderly_secret: None,
-                        orderly_account_id: account_id.clone(),
-                        wss_id: None,
-                    };
-
                     info!("[Manager] Attempting connection (Retry {})...", retries);
-                    match connect_managed(config, Arc::clone(&on_message), Arc::clone(&on_close))
-                        .await
+                    let reason = match connect_managed(
+                        config.clone(),
+                        Arc::clone(&on_message),
+                        Arc::clone(&on_error),
+                    )
+                    .await
                     {
                         Ok((handle, tx)) => {
                             info!("[Manager] Connection established successfully.");
                             retries = 0; // Reset retries on successful connection
                                          // Store the new sender
                             *shared_tx.lock().await = Some(tx.clone());
+                            stats.record_connected();
 
                             // --- Resubscribe to existing topics ---
                             let subs_guard = subscriptions.lock().await;
                             if !subs_guard.is_empty() {
                                 info!("[Manager] Resubscribing to {} topics...", subs_guard.len());
                                 for msg_str in subs_guard.iter() {
-                                    if let Err(e) = tx.send(Message::Text(msg_str.clone())).await {
+                                    if let Err(e) =
+                                        enqueue(&tx, Message::Text(msg_str.clone())).await
+                                    {
                                         error!("[Manager] Failed to send resubscription message '{}': {}. Aborting resubscribe.", msg_str, e);
                                         // Decide if we should break or continue?
                                         break; // Break resubscribe loop for this connection attempt
@@ -403,21 +1351,28 @@ impl WebsocketPublicClient {
                             }
                             drop(subs_guard);
                             // --- End Resubscribe ---
+                            on_resync();
 
                             // Wait for this connection to end (disconnect/error)
-                            handle.await.unwrap_or_else(|e| {
+                            let reason = handle.await.unwrap_or_else(|e| {
                                 error!("[Manager] Connection task panicked: {}", e);
+                                CloseReason::NetworkError(format!("connection task failed: {}", e))
                             });
-                            info!("[Manager] Connection task ended.");
+                            info!("[Manager] Connection task ended: {:?}", reason);
+                            reason
                         }
                         Err(e) => {
                             error!("[Manager] Failed to establish connection: {}", e);
+                            let reason = CloseReason::NetworkError(e.to_string());
+                            on_error(e);
+                            reason
                         }
-                    }
+                    };
 
                     // Connection failed or handle finished, prepare for retry
                     *shared_tx.lock().await = None; // Clear sender
-                    on_close(); // Notify external listener about disconnection
+                    stats.record_disconnected();
+                    on_close(reason); // Notify external listener about disconnection
 
                     retries += 1;
                     if retries >= MAX_RETRIES {
@@ -442,7 +1397,13 @@ impl WebsocketPublicClient {
         Ok(Self {
             shared_tx,
             subscriptions,
-            manager_handle,
+            subscription_ids: Arc::new(Mutex::new(HashMap::new())),
+            handle_counts: Arc::new(Mutex::new(HashMap::new())),
+            manager_handle: Mutex::new(Some(manager_handle)),
+            on_close,
+            max_subscriptions,
+            terminated,
+            stats,
         })
     }
 
@@ -455,30 +1416,41 @@ impl WebsocketPublicClient {
 
     /// Sends a raw text message to the WebSocket server if connected.
     async fn send_str(&self, text: &str) -> Result<()> {
-        let guard = self.shared_tx.lock().await;
-        if let Some(tx) = guard.as_ref() {
-            tx.send(Message::Text(text.to_string()))
-                .await
-                .map_err(|e| OrderlyError::WebsocketError(format!("Failed to send message: {}", e)))
-        } else {
-            Err(OrderlyError::WebsocketError("Not connected".to_string()))
-        }
+        send_text(&self.shared_tx, text).await
     }
 
     /// Helper to add subscription and send message.
     async fn subscribe(&self, topic_msg: Value) -> Result<()> {
         let msg_str = serde_json::to_string(&topic_msg)?;
-        // Add to subscriptions *before* sending
-        self.subscriptions.lock().await.insert(msg_str.clone());
+        // Add to subscriptions *before* sending, replacing any earlier message for the topic
+        if let Some(topic) = topic_msg.get("topic").and_then(|t| t.as_str()) {
+            let mut subscriptions = self.subscriptions.lock().await;
+            remove_topic(&mut subscriptions, topic);
+            check_subscription_limit(&subscriptions, self.max_subscriptions)?;
+            subscriptions.insert(msg_str.clone());
+            if let Some(id) = topic_msg.get("id").and_then(|i| i.as_str()) {
+                let mut ids = self.subscription_ids.lock().await;
+                ids.retain(|_, t| t != topic);
+                ids.insert(id.to_string(), topic.to_string());
+            }
+        }
         self.send_str(&msg_str).await
     }
 
     /// Helper to remove subscription and send message.
+    ///
+    /// Also releases any [`SubscriptionHandle`]s on the topic.
     async fn unsubscribe(&self, topic_msg: Value) -> Result<()> {
-        let msg_str = serde_json::to_string(&topic_msg)?;
-        // Remove from subscriptions *before* sending
-        self.subscriptions.lock().await.remove(&msg_str);
-        self.send_str(&msg_str).await
+        if let Some(topic) = topic_msg.get("topic").and_then(|t| t.as_str()) {
+            self.handle_counts.lock().await.remove(topic);
+        }
+        send_unsubscribe(
+            &self.shared_tx,
+            &self.subscriptions,
+            &self.subscription_ids,
+            topic_msg,
+        )
+        .await
     }
 
     /// Subscribe to real-time ticker updates for all trading pairs.
@@ -505,14 +1477,14 @@ impl WebsocketPublicClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.subscribe_tickers().await.expect("Failed to subscribe to tickers");
     /// # }
     /// ```
     pub async fn subscribe_tickers(&self) -> Result<()> {
         let msg = json!({
-            "id": "subscribe_tickers",
+            "id": new_request_id(),
             "topic": "tickers",
             "event": "subscribe"
         });
@@ -537,14 +1509,14 @@ impl WebsocketPublicClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.unsubscribe_tickers().await.expect("Failed to unsubscribe from tickers");
     /// # }
     /// ```
     pub async fn unsubscribe_tickers(&self) -> Result<()> {
         let msg = json!({
-            "id": "unsubscribe_tickers",
+            "id": new_request_id(),
             "topic": "tickers",
             "event": "unsubscribe"
         });
@@ -578,7 +1550,7 @@ impl WebsocketPublicClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.subscribe_orderbook("PERP_ETH_USDC").await.expect("Failed to subscribe to orderbook");
     /// # }
@@ -586,13 +1558,53 @@ impl WebsocketPublicClient {
     pub async fn subscribe_orderbook(&self, symbol: &str) -> Result<()> {
         let topic = format!("{}@orderbookupdate", symbol);
         let msg = json!({
-            "id": format!("subscribe_orderbook_{}", symbol),
+            "id": new_request_id(),
             "topic": topic,
             "event": "subscribe"
         });
         self.subscribe(msg).await
     }
 
+    /// Subscribe to real-time orderbook updates for several trading pairs at once.
+    ///
+    /// Sends one subscription message per symbol (the protocol has no multi-topic
+    /// subscribe) without waiting for acknowledgements, and persists each one for
+    /// resubscription after reconnects, like [`Self::subscribe_orderbook`].
+    ///
+    /// # Returns
+    ///
+    /// One result per entry of `symbols`, in the same order, so failures for
+    /// individual symbols are visible.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use orderly_connector_rs::websocket::WebsocketPublicClient;
+    /// # use std::sync::Arc;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let client = WebsocketPublicClient::connect(
+    /// #     "account_id".to_string(),
+    /// #     true,
+    /// #     Arc::new(|msg| println!("{}", msg)),
+    /// #     Arc::new(|_| println!("Closed")),
+    /// # ).await.unwrap();
+    /// let results = client
+    ///     .subscribe_orderbooks(&["PERP_ETH_USDC", "PERP_BTC_USDC"])
+    ///     .await;
+    /// for result in results {
+    ///     result.expect("Failed to subscribe to orderbook");
+    /// }
+    /// # }
+    /// ```
+    pub async fn subscribe_orderbooks(&self, symbols: &[&str]) -> Vec<Result<()>> {
+        let mut results = Vec::with_capacity(symbols.len());
+        for symbol in symbols {
+            results.push(self.subscribe_orderbook(symbol).await);
+        }
+        results
+    }
+
     /// Unsubscribe from real-time orderbook updates for a specific trading pair.
     ///
     /// # Arguments
@@ -615,7 +1627,7 @@ impl WebsocketPublicClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.unsubscribe_orderbook("PERP_ETH_USDC").await.expect("Failed to unsubscribe from orderbook");
     /// # }
@@ -623,7 +1635,7 @@ impl WebsocketPublicClient {
     pub async fn unsubscribe_orderbook(&self, symbol: &str) -> Result<()> {
         let topic = format!("{}@orderbookupdate", symbol);
         let msg = json!({
-            "id": format!("unsubscribe_orderbook_{}", symbol),
+            "id": new_request_id(),
             "topic": topic,
             "event": "unsubscribe"
         });
@@ -657,7 +1669,7 @@ impl WebsocketPublicClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.subscribe_open_interest("PERP_ETH_USDC").await.expect("Failed to subscribe to open interest");
     /// # }
@@ -665,7 +1677,7 @@ impl WebsocketPublicClient {
     pub async fn subscribe_open_interest(&self, symbol: &str) -> Result<()> {
         let topic = format!("{}@openinterest", symbol);
         let msg = json!({
-            "id": format!("subscribe_openinterest_{}", symbol),
+            "id": new_request_id(),
             "topic": topic,
             "event": "subscribe"
         });
@@ -683,6 +1695,12 @@ impl WebsocketPublicClient {
     /// - Position sizes
     /// - Affected trading pairs
     ///
+    /// The topic is served on the account-scoped EVM public stream that [`Self::connect`]
+    /// opens. Frames parse into
+    /// [`WebSocketMessage::Liquidation`](crate::types::WebSocketMessage::Liquidation),
+    /// which carries every [`WebSocketLiquidationData`](crate::types::WebSocketLiquidationData)
+    /// entry of the push.
+    ///
     /// # Returns
     ///
     /// Returns `Ok(())` if the subscription request was sent successfully,
@@ -691,23 +1709,31 @@ impl WebsocketPublicClient {
     /// # Examples
     ///
     /// ```no_run
-    /// # use orderly_connector_rs::websocket::WebsocketPublicClient;
-    /// # use std::sync::Arc;
+    /// use futures_util::StreamExt;
+    /// use orderly_connector_rs::types::WebSocketMessage;
+    /// use orderly_connector_rs::websocket::WebsocketPublicClient;
+    ///
     /// # #[tokio::main]
     /// # async fn main() {
-    /// # let client = WebsocketPublicClient::connect(
-    /// #     "account_id".to_string(),
-    /// #     true,
-    /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
-    /// # ).await.unwrap();
+    /// let (client, mut stream) =
+    ///     WebsocketPublicClient::connect_stream("account_id".to_string(), true)
+    ///         .await
+    ///         .expect("Failed to connect");
     /// client.subscribe_liquidations().await.expect("Failed to subscribe to liquidations");
+    ///
+    /// while let Some(item) = stream.next().await {
+    ///     if let Ok(WebSocketMessage::Liquidation(events)) = item {
+    ///         for event in events {
+    ///             println!("Liquidation {}: {:?}", event.liquidation_id, event.positions_by_perp);
+    ///         }
+    ///     }
+    /// }
     /// # }
     /// ```
     pub async fn subscribe_liquidations(&self) -> Result<()> {
         // Use a descriptive ID, or consider making it configurable/dynamic
         let msg = json!({
-            "id": "sub_liquidations",
+            "id": new_request_id(),
             "event": "subscribe",
             "topic": "liquidation"
         });
@@ -736,7 +1762,7 @@ impl WebsocketPublicClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.unsubscribe_open_interest("PERP_ETH_USDC").await.expect("Failed to unsubscribe from open interest");
     /// # }
@@ -744,7 +1770,7 @@ impl WebsocketPublicClient {
     pub async fn unsubscribe_open_interest(&self, symbol: &str) -> Result<()> {
         let topic = format!("{}@openinterest", symbol);
         let msg = json!({
-            "id": format!("unsubscribe_openinterest_{}", symbol),
+            "id": new_request_id(),
             "topic": topic,
             "event": "unsubscribe"
         });
@@ -769,14 +1795,14 @@ impl WebsocketPublicClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.unsubscribe_liquidations().await.expect("Failed to unsubscribe from liquidations");
     /// # }
     /// ```
     pub async fn unsubscribe_liquidations(&self) -> Result<()> {
         let msg = json!({
-            "id": "unsub_liquidations",
+            "id": new_request_id(),
             "event": "unsubscribe",
             "topic": "liquidation"
         });
@@ -812,7 +1838,7 @@ impl WebsocketPublicClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.subscribe_trades("PERP_ETH_USDC").await.expect("Failed to subscribe to trades");
     /// # }
@@ -820,13 +1846,50 @@ impl WebsocketPublicClient {
     pub async fn subscribe_trades(&self, symbol: &str) -> Result<()> {
         let topic = format!("{}@trade", symbol);
         let msg = json!({
-            "id": format!("subscribe_trade_{}", symbol),
+            "id": new_request_id(),
             "topic": topic,
             "event": "subscribe"
         });
         self.subscribe(msg).await
     }
 
+    /// Unsubscribe from real-time trade updates for a specific trading pair.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The trading pair symbol (e.g., "PERP_ETH_USDC")
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the unsubscription request was sent successfully,
+    /// or an error if the request failed or the connection is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use orderly_connector_rs::websocket::WebsocketPublicClient;
+    /// # use std::sync::Arc;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let client = WebsocketPublicClient::connect(
+    /// #     "account_id".to_string(),
+    /// #     true,
+    /// #     Arc::new(|msg| println!("{}", msg)),
+    /// #     Arc::new(|_| println!("Closed")),
+    /// # ).await.unwrap();
+    /// client.unsubscribe_trades("PERP_ETH_USDC").await.expect("Failed to unsubscribe from trades");
+    /// # }
+    /// ```
+    pub async fn unsubscribe_trades(&self, symbol: &str) -> Result<()> {
+        let topic = format!("{}@trade", symbol);
+        let msg = json!({
+            "id": new_request_id(),
+            "topic": topic,
+            "event": "unsubscribe"
+        });
+        self.unsubscribe(msg).await
+    }
+
     /// Subscribe to real-time 24h ticker updates for a specific trading pair (symbol).
     ///
     /// This sends a per-symbol ticker subscription message to the Orderly WebSocket API, matching the protocol:
@@ -848,23 +1911,146 @@ impl WebsocketPublicClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.subscribe_ticker("PERP_ETH_USDC").await.expect("Failed to subscribe to ticker");
     /// # }
     /// ```
     pub async fn subscribe_ticker(&self, symbol: &str) -> Result<()> {
-        use uuid::Uuid;
-        let client_id = Uuid::new_v4().to_string();
         let topic = format!("{}@ticker", symbol);
         let msg = serde_json::json!({
-            "id": client_id,
+            "id": new_request_id(),
+            "topic": topic,
+            "event": "subscribe"
+        });
+        self.subscribe(msg).await
+    }
+
+    /// Subscribe to 24h ticker updates for a chosen set of trading pairs.
+    ///
+    /// Sits between [`Self::subscribe_tickers`], which streams every symbol, and
+    /// [`Self::subscribe_ticker`]. Sends one `{symbol}@ticker` subscription per symbol and
+    /// persists each one for resubscription after reconnects, like
+    /// [`Self::subscribe_orderbooks`].
+    ///
+    /// # Returns
+    ///
+    /// One result per entry of `symbols`, in the same order, so failures for
+    /// individual symbols are visible.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use orderly_connector_rs::websocket::WebsocketPublicClient;
+    /// # use std::sync::Arc;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let client = WebsocketPublicClient::connect(
+    /// #     "account_id".to_string(),
+    /// #     true,
+    /// #     Arc::new(|msg| println!("{}", msg)),
+    /// #     Arc::new(|_| println!("Closed")),
+    /// # ).await.unwrap();
+    /// let results = client
+    ///     .subscribe_tickers_for(&["PERP_ETH_USDC", "PERP_BTC_USDC"])
+    ///     .await;
+    /// for result in results {
+    ///     result.expect("Failed to subscribe to ticker");
+    /// }
+    /// # }
+    /// ```
+    pub async fn subscribe_tickers_for(&self, symbols: &[&str]) -> Vec<Result<()>> {
+        let mut results = Vec::with_capacity(symbols.len());
+        for symbol in symbols {
+            results.push(self.subscribe_ticker(symbol).await);
+        }
+        results
+    }
+
+    /// Unsubscribe from 24h ticker updates for a specific trading pair (symbol).
+    ///
+    /// # Arguments
+    /// * `symbol` - The trading symbol to unsubscribe from (e.g., "PERP_ETH_USDC").
+    ///
+    /// # Returns
+    /// Returns `Ok(())` if the unsubscription request was sent successfully, or an error if the request failed or the connection is closed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use orderly_connector_rs::websocket::WebsocketPublicClient;
+    /// # use std::sync::Arc;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let client = WebsocketPublicClient::connect(
+    /// #     "account_id".to_string(),
+    /// #     true,
+    /// #     Arc::new(|msg| println!("{}", msg)),
+    /// #     Arc::new(|_| println!("Closed")),
+    /// # ).await.unwrap();
+    /// client.unsubscribe_ticker("PERP_ETH_USDC").await.expect("Failed to unsubscribe from ticker");
+    /// # }
+    /// ```
+    pub async fn unsubscribe_ticker(&self, symbol: &str) -> Result<()> {
+        let topic = format!("{}@ticker", symbol);
+        let msg = json!({
+            "id": new_request_id(),
             "topic": topic,
+            "event": "unsubscribe"
+        });
+        self.unsubscribe(msg).await
+    }
+
+    /// Subscribe to candlestick updates for a trading pair at the given interval.
+    ///
+    /// Sends a subscription for the `{symbol}@kline_{interval}` topic, e.g.
+    /// `PERP_ETH_USDC@kline_1m`.
+    ///
+    /// # Arguments
+    /// * `symbol` - The trading symbol to subscribe to (e.g., "PERP_ETH_USDC").
+    /// * `interval` - Candlestick interval
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use orderly_connector_rs::types::KlineInterval;
+    /// # use orderly_connector_rs::websocket::WebsocketPublicClient;
+    /// # use std::sync::Arc;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let client = WebsocketPublicClient::connect(
+    /// #     "account_id".to_string(),
+    /// #     true,
+    /// #     Arc::new(|msg| println!("{}", msg)),
+    /// #     Arc::new(|_| println!("Closed")),
+    /// # ).await.unwrap();
+    /// client
+    ///     .subscribe_kline("PERP_ETH_USDC", KlineInterval::OneMinute)
+    ///     .await
+    ///     .expect("Failed to subscribe to klines");
+    /// # }
+    /// ```
+    pub async fn subscribe_kline(&self, symbol: &str, interval: KlineInterval) -> Result<()> {
+        let msg = json!({
+            "id": new_request_id(),
+            "topic": kline_topic(symbol, interval),
             "event": "subscribe"
         });
         self.subscribe(msg).await
     }
 
+    /// Unsubscribe from candlestick updates for a trading pair at the given interval.
+    ///
+    /// # Arguments
+    /// * `symbol` - The trading symbol to unsubscribe from (e.g., "PERP_ETH_USDC").
+    /// * `interval` - Candlestick interval used when subscribing
+    pub async fn unsubscribe_kline(&self, symbol: &str, interval: KlineInterval) -> Result<()> {
+        let msg = json!({
+            "id": new_request_id(),
+            "topic": kline_topic(symbol, interval),
+            "event": "unsubscribe"
+        });
+        self.unsubscribe(msg).await
+    }
+
     /// Subscribes to index prices for all symbols.
     ///
     /// # Returns
@@ -883,8 +2069,8 @@ impl WebsocketPublicClient {
     ///         println!("Received: {}", msg);
     ///     });
     ///
-    ///     let close_handler = Arc::new(|| {
-    ///         println!("Connection closed");
+    ///     let close_handler = Arc::new(|reason| {
+    ///         println!("Connection closed: {:?}", reason);
     ///     });
     ///
     ///     let client = WebsocketPublicClient::connect(
@@ -899,12 +2085,12 @@ impl WebsocketPublicClient {
     ///
     ///     // Keep the connection alive
     ///     tokio::signal::ctrl_c().await.expect("Failed to listen for ctrl+c");
-    ///     client.stop().await;
+    ///     client.stop().await.expect("Failed to stop client");
     /// }
     /// ```
     pub async fn subscribe_index_prices(&self) -> Result<()> {
         let topic_msg = json!({
-            "id": uuid::Uuid::new_v4().to_string(),
+            "id": new_request_id(),
             "event": "subscribe",
             "topic": "indexprices"
         });
@@ -933,8 +2119,8 @@ impl WebsocketPublicClient {
     ///         println!("Received: {}", msg);
     ///     });
     ///
-    ///     let close_handler = Arc::new(|| {
-    ///         println!("Connection closed");
+    ///     let close_handler = Arc::new(|reason| {
+    ///         println!("Connection closed: {:?}", reason);
     ///     });
     ///
     ///     let client = WebsocketPublicClient::connect(
@@ -949,12 +2135,12 @@ impl WebsocketPublicClient {
     ///
     ///     // Keep the connection alive
     ///     tokio::signal::ctrl_c().await.expect("Failed to listen for ctrl+c");
-    ///     client.stop().await;
+    ///     client.stop().await.expect("Failed to stop client");
     /// }
     /// ```
     pub async fn subscribe_mark_prices(&self) -> Result<()> {
         let topic_msg = json!({
-            "id": uuid::Uuid::new_v4().to_string(),
+            "id": new_request_id(),
             "event": "subscribe",
             "topic": "markprices"
         });
@@ -969,7 +2155,7 @@ impl WebsocketPublicClient {
     /// A `Result` indicating success or failure of the unsubscription.
     pub async fn unsubscribe_mark_prices(&self) -> Result<()> {
         let topic_msg = json!({
-            "id": uuid::Uuid::new_v4().to_string(),
+            "id": new_request_id(),
             "event": "unsubscribe",
             "topic": "markprices"
         });
@@ -980,7 +2166,7 @@ impl WebsocketPublicClient {
     /// Unsubscribes from index prices.
     pub async fn unsubscribe_index_prices(&self) -> Result<()> {
         let topic_msg = json!({
-            "id": uuid::Uuid::new_v4().to_string(),
+            "id": new_request_id(),
             "event": "unsubscribe",
             "topic": "indexprices"
         });
@@ -988,17 +2174,156 @@ impl WebsocketPublicClient {
         self.unsubscribe(topic_msg).await
     }
 
+    /// Subscribe to an arbitrary topic by name.
+    ///
+    /// This is an escape hatch for streams that don't have a dedicated helper yet.
+    /// It sends the standard `{id, topic, event}` subscription message and persists
+    /// it so the topic is resubscribed after reconnects, like the typed helpers.
+    ///
+    /// # Arguments
+    ///
+    /// * `topic` - The raw topic name (e.g., "PERP_ETH_USDC@kline_1m")
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the subscription request was sent successfully,
+    /// or an error if the request failed or the connection is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use orderly_connector_rs::websocket::WebsocketPublicClient;
+    /// # use std::sync::Arc;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let client = WebsocketPublicClient::connect(
+    /// #     "account_id".to_string(),
+    /// #     true,
+    /// #     Arc::new(|msg| println!("{}", msg)),
+    /// #     Arc::new(|_| println!("Closed")),
+    /// # ).await.unwrap();
+    /// client.subscribe_raw("PERP_ETH_USDC@kline_1m").await.expect("Failed to subscribe");
+    /// # }
+    /// ```
+    pub async fn subscribe_raw(&self, topic: &str) -> Result<()> {
+        let msg = json!({
+            "id": new_request_id(),
+            "topic": topic,
+            "event": "subscribe"
+        });
+        self.subscribe(msg).await
+    }
+
+    /// Unsubscribe from an arbitrary topic by name.
+    ///
+    /// Removes the topic from the persisted subscription set, whether it was
+    /// subscribed via [`Self::subscribe_raw`] or a typed helper.
+    ///
+    /// # Arguments
+    ///
+    /// * `topic` - The raw topic name (e.g., "PERP_ETH_USDC@kline_1m")
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the unsubscription request was sent successfully,
+    /// or an error if the request failed or the connection is closed.
+    pub async fn unsubscribe_raw(&self, topic: &str) -> Result<()> {
+        let msg = json!({
+            "id": new_request_id(),
+            "topic": topic,
+            "event": "unsubscribe"
+        });
+        self.unsubscribe(msg).await
+    }
+
+    /// Subscribes to `topic` like `subscribe_raw` and returns a [`SubscriptionHandle`]
+    /// that unsubscribes when dropped.
+    ///
+    /// Further handles on an already-held topic share its subscription; the topic is
+    /// unsubscribed once every handle has been released.
+    ///
+    /// # Arguments
+    ///
+    /// * `topic` - The raw topic name (e.g., "PERP_ETH_USDC@trade")
+    pub async fn subscribe_with_handle(&self, topic: &str) -> Result<SubscriptionHandle> {
+        let mut counts = self.handle_counts.lock().await;
+        if !counts.contains_key(topic) {
+            self.subscribe_raw(topic).await?;
+        }
+        let count = counts
+            .entry(topic.to_string())
+            .or_insert_with(|| HandleCount {
+                generation: HANDLE_GENERATIONS.fetch_add(1, Ordering::Relaxed),
+                handles: 0,
+            });
+        count.handles += 1;
+        Ok(SubscriptionHandle {
+            topic: topic.to_string(),
+            shared_tx: Arc::clone(&self.shared_tx),
+            subscriptions: Arc::clone(&self.subscriptions),
+            subscription_ids: Arc::clone(&self.subscription_ids),
+            handle_counts: Arc::clone(&self.handle_counts),
+            generation: count.generation,
+            armed: true,
+        })
+    }
+
+    // --- Connection State ---
+    /// Returns `true` if the client currently holds a live connection.
+    ///
+    /// This is `false` while the manager is reconnecting and after [`Self::stop`].
+    pub async fn is_connected(&self) -> bool {
+        self.shared_tx.lock().await.is_some()
+    }
+
+    /// Returns how many times the client has reconnected since it was created.
+    ///
+    /// Counts successful connections after the first; failed attempts are not counted.
+    pub fn reconnect_count(&self) -> u64 {
+        self.stats.reconnect_count()
+    }
+
+    /// Returns when the current connection was established, or `None` while
+    /// disconnected and after [`Self::stop`].
+    ///
+    /// Reset on every successful (re)connection, so `connected_since()?.elapsed()` is
+    /// the uptime of the current session.
+    pub fn connected_since(&self) -> Option<Instant> {
+        self.stats.connected_since()
+    }
+
+    /// Returns a snapshot of the subscription messages that are replayed after a reconnect.
+    pub async fn active_subscriptions(&self) -> Vec<String> {
+        self.subscriptions.lock().await.iter().cloned().collect()
+    }
+
+    /// Returns the topic of the active subscription whose request carried `id`.
+    ///
+    /// Every subscription message is sent with a unique `id`, which the server echoes
+    /// in its response; use this to correlate those responses with topics.
+    pub async fn subscription_topic(&self, id: &str) -> Option<String> {
+        self.subscription_ids.lock().await.get(id).cloned()
+    }
+
     // --- Stop Method ---
-    pub async fn stop(&self) {
+    /// Stops the client and waits for the connection to shut down.
+    ///
+    /// Reconnection is halted before a Close frame is sent, and the call waits up to five
+    /// seconds for the socket to close. If no connection is live, for example during
+    /// reconnect backoff, reconnection is cancelled and nothing is sent. `on_close` is
+    /// called with [`CloseReason::Stopped`] once; calling `stop` again is a no-op.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` once the client has stopped, or an error if the Close frame could
+    /// not be sent or the socket did not close in time.
+    pub async fn stop(&self) -> Result<()> {
         info!("Stopping WebSocket client...");
-        // Abort the manager task
-        self.manager_handle.abort();
-        // Optionally try sending a Close frame if connected
-        let guard = self.shared_tx.lock().await;
-        if let Some(tx) = guard.as_ref() {
-            let _ = tx.send(Message::Close(None)).await;
-        }
-        info!("Stop signal sent.");
+        let result = shutdown(&self.manager_handle, &self.shared_tx, &self.on_close).await;
+        self.stats.record_disconnected();
+        result?;
+        info!("WebSocket client stopped.");
+        Ok(())
     }
 }
 
@@ -1019,8 +2344,10 @@ impl WebsocketPublicClient {
 /// # Available Subscriptions
 ///
 /// * Execution Reports: Real-time order execution updates
+/// * Algo Execution Reports: Algo (stop / take-profit) order updates and triggers
 /// * Positions: Position changes and updates
 /// * Balance: Account balance updates
+/// * Account: Leverage and margin mode changes
 ///
 /// # Authentication
 ///
@@ -1029,6 +2356,13 @@ impl WebsocketPublicClient {
 /// * Re-authentication after reconnects
 /// * Secure message signing
 ///
+/// Deltas sent while the connection is down are not replayed. Pass an `on_resync`
+/// callback to [`WebsocketPrivateClient::connect_with`] to be notified after each
+/// reconnect so local order/position state can be rebuilt from a REST snapshot.
+///
+/// Like [`WebsocketPublicClient`], the client is `Send + Sync` and can be shared between
+/// tasks through an `Arc`; see [`WebsocketPrivateClient::spawn`].
+///
 /// # Examples
 ///
 /// Basic usage with multiple subscriptions:
@@ -1042,8 +2376,8 @@ impl WebsocketPublicClient {
 ///         println!("Received: {}", msg);
 ///     });
 ///
-///     let close_handler = Arc::new(|| {
-///         println!("Connection closed");
+///     let close_handler = Arc::new(|reason| {
+///         println!("Connection closed: {:?}", reason);
 ///     });
 ///
 ///     let client = WebsocketPrivateClient::connect(
@@ -1062,13 +2396,19 @@ impl WebsocketPublicClient {
 ///
 ///     // Keep the connection alive
 ///     tokio::signal::ctrl_c().await.expect("Failed to listen for ctrl+c");
-///     client.stop().await;
+///     client.stop().await.expect("Failed to stop client");
 /// }
 /// ```
 pub struct WebsocketPrivateClient {
     shared_tx: SharedSender,
     subscriptions: SubscriptionState,
-    manager_handle: JoinHandle<()>, // Handle to the manager task
+    subscription_ids: SubscriptionIds,
+    handle_counts: HandleCounts, // Live SubscriptionHandle count per topic
+    manager_handle: Mutex<Option<JoinHandle<()>>>, // Handle to the manager task (taken on stop)
+    on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>, // Also invoked by stop()
+    max_subscriptions: Option<usize>, // Topic cap enforced by subscribe()
+    terminated: watch::Receiver<()>, // Closed once the manager task has exited
+    stats: Arc<ConnectionStats>, // Reconnect count and session start
 }
 
 impl WebsocketPrivateClient {
@@ -1078,47 +2418,176 @@ impl WebsocketPrivateClient {
         account_id: String,
         is_testnet: bool,
         on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
-        on_close: Arc<dyn Fn() + Send + Sync + 'static>,
+        on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
     ) -> Result<Self> {
+        let base_url = if is_testnet {
+            TESTNET_WS_PRIVATE_URL
+        } else {
+            MAINNET_WS_PRIVATE_URL
+        };
+        Self::connect_with_url(
+            base_url,
+            orderly_key,
+            orderly_secret,
+            account_id,
+            on_message,
+            on_close,
+        )
+        .await
+    }
+
+    /// Connects to an explicit private stream URL instead of the mainnet/testnet one.
+    ///
+    /// This allows pointing the client at a local mock server or a custom gateway.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The full WebSocket URL of the private stream
+    /// * `orderly_key` - Your Orderly API key
+    /// * `orderly_secret` - Your Orderly API secret
+    /// * `account_id` - Your Orderly account ID
+    /// * `on_message` - Callback for each received message
+    /// * `on_close` - Callback for connection closure
+    pub async fn connect_with_url(
+        url: &str,
+        orderly_key: String,
+        orderly_secret: String,
+        account_id: String,
+        on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
+        on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
+    ) -> Result<Self> {
+        let config = WebsocketClientConfig {
+            base_url: url.to_string(),
+            orderly_key: Some(orderly_key),
+            orderly_secret: Some(orderly_secret),
+            orderly_account_id: account_id,
+            ..Default::default()
+        };
+        Self::connect_with_config(config, on_message, on_close).await
+    }
+
+    /// Connects and returns the client together with a stream of parsed messages.
+    ///
+    /// This is an alternative to [`Self::connect`] for consumers that prefer
+    /// `while let Some(item) = stream.next().await` over callbacks. See
+    /// [`WebsocketMessageStream`] for buffering behaviour when the consumer falls behind.
+    ///
+    /// Execution reports redelivered after a reconnect are dropped, remembering the last
+    /// [`DEFAULT_DEDUP_CAPACITY`] events (see `dedup_execution_reports` on
+    /// [`WebsocketClientConfig`]).
+    pub async fn connect_stream(
+        orderly_key: String,
+        orderly_secret: String,
+        account_id: String,
+        is_testnet: bool,
+    ) -> Result<(Self, WebsocketMessageStream)> {
+        let base_url = if is_testnet {
+            TESTNET_WS_PRIVATE_URL
+        } else {
+            MAINNET_WS_PRIVATE_URL
+        };
+        let config = WebsocketClientConfig {
+            base_url: base_url.to_string(),
+            orderly_key: Some(orderly_key),
+            orderly_secret: Some(orderly_secret),
+            orderly_account_id: account_id,
+            dedup_execution_reports: Some(DEFAULT_DEDUP_CAPACITY),
+            ..Default::default()
+        };
+        let (on_message, stream) = message_stream();
+        let client = Self::connect_with_config(config, on_message, Arc::new(|_| {})).await?;
+        Ok((client, stream))
+    }
+
+    /// Connects using an explicit [`WebsocketClientConfig`].
+    ///
+    /// `config.orderly_key` and `config.orderly_secret` are required for authentication;
+    /// `OrderlyError::MissingCredentials` is returned if either is absent. Use this to tune
+    /// settings such as `heartbeat_timeout_secs`.
+    pub async fn connect_with_config(
+        config: WebsocketClientConfig,
+        on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
+        on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
+    ) -> Result<Self> {
+        Self::connect_with(config, on_message, on_close, ClientHandlers::default()).await
+    }
+
+    /// Connects like [`Self::connect_with_config`] and returns the client behind an `Arc`
+    /// for sharing across tasks.
+    ///
+    /// The returned `JoinHandle` completes once the client has terminated for good; see
+    /// [`WebsocketPublicClient::spawn`].
+    pub async fn spawn(
+        config: WebsocketClientConfig,
+        on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
+        on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
+    ) -> Result<(Arc<Self>, JoinHandle<()>)> {
+        let client = Self::connect_with_config(config, on_message, on_close).await?;
+        let handle = tokio::spawn(wait_terminated(client.terminated.clone()));
+        Ok((Arc::new(client), handle))
+    }
+
+    /// Connects like [`Self::connect_with_config`] with the optional callbacks in
+    /// `handlers`; see [`ClientHandlers`] for when each is called.
+    ///
+    /// Private streams only carry deltas, so set `handlers.on_resync` to rebuild local
+    /// order/position state after each reconnect.
+    pub async fn connect_with(
+        config: WebsocketClientConfig,
+        on_message: Arc<dyn Fn(String) + Send + Sync + 'static>,
+        on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
+        handlers: ClientHandlers,
+    ) -> Result<Self> {
+        let (orderly_key, orderly_secret) =
+            match (config.orderly_key.clone(), config.orderly_secret.clone()) {
+                (Some(key), Some(secret)) => (key, secret),
+                _ => return Err(OrderlyError::MissingCredentials),
+            };
+        let on_error = handlers
+            .on_error
+            .unwrap_or_else(|| Arc::new(|_: OrderlyError| {}));
+        let on_resync = handlers.on_resync.unwrap_or_else(|| Arc::new(|| {}));
+        let on_message = report_auth_rejection(on_message, Arc::clone(&on_error));
+        let on_message = match config.dedup_execution_reports {
+            Some(capacity) => dedup_execution_reports(on_message, capacity),
+            None => on_message,
+        };
+        let max_subscriptions = config.max_subscriptions;
         let shared_tx: SharedSender = Arc::new(Mutex::new(None));
         let subscriptions: SubscriptionState = Arc::new(Mutex::new(HashSet::new()));
+        let (terminated_tx, terminated) = watch::channel(());
+        let stats = Arc::new(ConnectionStats::default());
 
         let manager_handle = tokio::spawn({
-            // Clone Arcs and credentials
+            // Clone Arcs for the manager task
             let shared_tx = Arc::clone(&shared_tx);
             let subscriptions = Arc::clone(&subscriptions);
+            let stats = Arc::clone(&stats);
             let on_message = Arc::clone(&on_message);
             let on_close = Arc::clone(&on_close);
-            let orderly_key = orderly_key.clone();
-            let orderly_secret = orderly_secret.clone();
-            let account_id = account_id.clone();
+            let on_resync = Arc::clone(&on_resync);
+            let on_error = Arc::clone(&on_error);
 
             async move {
+                // Dropped when the manager finishes or is aborted, waking `wait_terminated`
+                let _terminated_tx = terminated_tx;
                 let mut retries = 0;
                 loop {
-                    let base_url = if is_testnet {
-                        TESTNET_WS_PRIVATE_URL
-                    } else {
-                        MAINNET_WS_PRIVATE_URL
-                    };
-                    let config = WebsocketClientConfig {
-                        base_url: base_url.to_string(),
-                        orderly_key: Some(orderly_key.clone()), // Needed by connect_managed if it logged them
-                        orderly_secret: Some(orderly_secret.clone()),
-                        orderly_account_id: account_id.clone(),
-                        wss_id: None,
-                    };
-
                     info!(
                         "[Manager-Priv] Attempting connection (Retry {})...",
                         retries
                     );
-                    match connect_managed(config, Arc::clone(&on_message), Arc::clone(&on_close))
-                        .await
+                    let reason = match connect_managed(
+                        config.clone(),
+                        Arc::clone(&on_message),
+                        Arc::clone(&on_error),
+                    )
+                    .await
                     {
                         Ok((handle, tx)) => {
                             info!("[Manager-Priv] Connection established. Authenticating...");
                             retries = 0;
+                            let mut auth_error = None;
 
                             // --- Authenticate ---
                             let auth_success = match Self::authenticate(
@@ -1137,6 +2606,8 @@ impl WebsocketPrivateClient {
                                 }
                                 Err(e) => {
                                     error!("[Manager-Priv] Failed to send auth message: {}. Cannot proceed.", e);
+                                    auth_error = Some(e.to_string());
+                                    on_error(e);
                                     false
                                 }
                             };
@@ -1144,6 +2615,7 @@ impl WebsocketPrivateClient {
                             if auth_success {
                                 // Store sender *after* attempting auth
                                 *shared_tx.lock().await = Some(tx.clone());
+                                stats.record_connected();
 
                                 // --- Resubscribe ---
                                 let subs_guard = subscriptions.lock().await;
@@ -1154,7 +2626,7 @@ impl WebsocketPrivateClient {
                                     );
                                     for msg_str in subs_guard.iter() {
                                         if let Err(e) =
-                                            tx.send(Message::Text(msg_str.clone())).await
+                                            enqueue(&tx, Message::Text(msg_str.clone())).await
                                         {
                                             error!("[Manager-Priv] Failed to send resubscription '{}': {}. Aborting.", msg_str, e);
                                             break;
@@ -1163,6 +2635,10 @@ impl WebsocketPrivateClient {
                                 }
                                 drop(subs_guard);
                                 // --- End Resubscribe ---
+
+                                // Deltas missed while disconnected are gone; let the
+                                // consumer rebuild state from a snapshot.
+                                on_resync();
                             } else {
                                 // Auth failed, don't store sender, proceed to retry logic
                                 error!(
@@ -1172,21 +2648,35 @@ impl WebsocketPrivateClient {
 
                             // Wait for connection task to end (if auth succeeded)
                             if auth_success {
-                                handle.await.unwrap_or_else(|e| {
+                                let reason = handle.await.unwrap_or_else(|e| {
                                     error!("[Manager-Priv] Connection task panicked: {}", e);
+                                    CloseReason::NetworkError(format!(
+                                        "connection task failed: {}",
+                                        e
+                                    ))
                                 });
-                                info!("[Manager-Priv] Connection task ended.");
+                                info!("[Manager-Priv] Connection task ended: {:?}", reason);
+                                reason
+                            } else {
+                                // If auth failed, handle is implicitly dropped, proceed to retry.
+                                CloseReason::NetworkError(
+                                    auth_error
+                                        .unwrap_or_else(|| "authentication failed".to_string()),
+                                )
                             }
-                            // If auth failed, handle is implicitly dropped, proceed to retry.
                         }
                         Err(e) => {
                             error!("[Manager-Priv] Failed to establish connection: {}", e);
+                            let reason = CloseReason::NetworkError(e.to_string());
+                            on_error(e);
+                            reason
                         }
-                    }
+                    };
 
                     // Connection failed, auth failed, or handle finished
                     *shared_tx.lock().await = None; // Clear sender
-                    on_close();
+                    stats.record_disconnected();
+                    on_close(reason);
 
                     retries += 1;
                     if retries >= MAX_RETRIES {
@@ -1210,7 +2700,13 @@ impl WebsocketPrivateClient {
         Ok(Self {
             shared_tx,
             subscriptions,
-            manager_handle,
+            subscription_ids: Arc::new(Mutex::new(HashMap::new())),
+            handle_counts: Arc::new(Mutex::new(HashMap::new())),
+            manager_handle: Mutex::new(Some(manager_handle)),
+            on_close,
+            max_subscriptions,
+            terminated,
+            stats,
         })
     }
 
@@ -1228,9 +2724,7 @@ impl WebsocketPrivateClient {
             }
         });
         let auth_msg_str = serde_json::to_string(&auth_msg)?;
-        tx.send(Message::Text(auth_msg_str)).await.map_err(|e| {
-            OrderlyError::WebsocketError(format!("Failed to send auth message: {}", e))
-        })
+        enqueue(tx, Message::Text(auth_msg_str)).await
     }
 
     /// Sends a raw JSON message (helper).
@@ -1242,28 +2736,41 @@ impl WebsocketPrivateClient {
 
     /// Sends a raw text message (helper).
     async fn send_str(&self, text: &str) -> Result<()> {
-        let guard = self.shared_tx.lock().await;
-        if let Some(tx) = guard.as_ref() {
-            tx.send(Message::Text(text.to_string()))
-                .await
-                .map_err(|e| OrderlyError::WebsocketError(format!("Failed to send message: {}", e)))
-        } else {
-            Err(OrderlyError::WebsocketError("Not connected".to_string()))
-        }
+        send_text(&self.shared_tx, text).await
     }
 
     /// Helper to add subscription and send message.
     async fn subscribe(&self, topic_msg: Value) -> Result<()> {
         let msg_str = serde_json::to_string(&topic_msg)?;
-        self.subscriptions.lock().await.insert(msg_str.clone());
+        // Add to subscriptions *before* sending, replacing any earlier message for the topic
+        if let Some(topic) = topic_msg.get("topic").and_then(|t| t.as_str()) {
+            let mut subscriptions = self.subscriptions.lock().await;
+            remove_topic(&mut subscriptions, topic);
+            check_subscription_limit(&subscriptions, self.max_subscriptions)?;
+            subscriptions.insert(msg_str.clone());
+            if let Some(id) = topic_msg.get("id").and_then(|i| i.as_str()) {
+                let mut ids = self.subscription_ids.lock().await;
+                ids.retain(|_, t| t != topic);
+                ids.insert(id.to_string(), topic.to_string());
+            }
+        }
         self.send_str(&msg_str).await
     }
 
     /// Helper to remove subscription and send message.
+    ///
+    /// Also releases any [`SubscriptionHandle`]s on the topic.
     async fn unsubscribe(&self, topic_msg: Value) -> Result<()> {
-        let msg_str = serde_json::to_string(&topic_msg)?;
-        self.subscriptions.lock().await.remove(&msg_str);
-        self.send_str(&msg_str).await
+        if let Some(topic) = topic_msg.get("topic").and_then(|t| t.as_str()) {
+            self.handle_counts.lock().await.remove(topic);
+        }
+        send_unsubscribe(
+            &self.shared_tx,
+            &self.subscriptions,
+            &self.subscription_ids,
+            topic_msg,
+        )
+        .await
     }
 
     /// Subscribe to real-time execution reports for your orders.
@@ -1296,14 +2803,14 @@ impl WebsocketPrivateClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.subscribe_execution_reports().await.expect("Failed to subscribe to execution reports");
     /// # }
     /// ```
     pub async fn subscribe_execution_reports(&self) -> Result<()> {
         let msg = json!({
-            "id": "subscribe_execution",
+            "id": new_request_id(),
             "topic": "execution",
             "event": "subscribe"
         });
@@ -1340,14 +2847,14 @@ impl WebsocketPrivateClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.subscribe_positions().await.expect("Failed to subscribe to positions");
     /// # }
     /// ```
     pub async fn subscribe_positions(&self) -> Result<()> {
         let msg = json!({
-            "id": "subscribe_position",
+            "id": new_request_id(),
             "topic": "position",
             "event": "subscribe"
         });
@@ -1384,14 +2891,14 @@ impl WebsocketPrivateClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.subscribe_balance().await.expect("Failed to subscribe to balance updates");
     /// # }
     /// ```
     pub async fn subscribe_balance(&self) -> Result<()> {
         let msg = json!({
-            "id": "subscribe_balance",
+            "id": new_request_id(),
             "topic": "balance",
             "event": "subscribe"
         });
@@ -1418,14 +2925,14 @@ impl WebsocketPrivateClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.unsubscribe_positions().await.expect("Failed to unsubscribe from positions");
     /// # }
     /// ```
     pub async fn unsubscribe_positions(&self) -> Result<()> {
         let msg = json!({
-            "id": "unsub_positions",
+            "id": new_request_id(),
             "event": "unsubscribe",
             "topic": "position"
         });
@@ -1452,14 +2959,14 @@ impl WebsocketPrivateClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.unsubscribe_execution_reports().await.expect("Failed to unsubscribe from execution reports");
     /// # }
     /// ```
     pub async fn unsubscribe_execution_reports(&self) -> Result<()> {
         let msg = json!({
-            "id": "unsub_execution_reports",
+            "id": new_request_id(),
             "event": "unsubscribe",
             "topic": "executionreport"
         });
@@ -1486,28 +2993,489 @@ impl WebsocketPrivateClient {
     /// #     "account_id".to_string(),
     /// #     true,
     /// #     Arc::new(|msg| println!("{}", msg)),
-    /// #     Arc::new(|| println!("Closed")),
+    /// #     Arc::new(|_| println!("Closed")),
     /// # ).await.unwrap();
     /// client.unsubscribe_balance().await.expect("Failed to unsubscribe from balance updates");
     /// # }
     /// ```
     pub async fn unsubscribe_balance(&self) -> Result<()> {
         let msg = json!({
-            "id": "unsub_balance",
+            "id": new_request_id(),
             "event": "unsubscribe",
             "topic": "balance"
         });
         self.unsubscribe(msg).await
     }
 
+    /// Subscribe to real-time algo order execution reports.
+    ///
+    /// Algo execution reports cover the lifecycle of algorithmic orders:
+    /// - Stop / take-profit orders being placed and cancelled
+    /// - Trigger events (e.g., a stop loss firing)
+    /// - Fills of the child orders created when an algo order triggers
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication with valid API credentials.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the subscription request was sent successfully,
+    /// or an error if the request failed, authentication failed, or the connection is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use orderly_connector_rs::websocket::WebsocketPrivateClient;
+    /// # use std::sync::Arc;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let client = WebsocketPrivateClient::connect(
+    /// #     "api_key".to_string(),
+    /// #     "secret".to_string(),
+    /// #     "account_id".to_string(),
+    /// #     true,
+    /// #     Arc::new(|msg| println!("{}", msg)),
+    /// #     Arc::new(|_| println!("Closed")),
+    /// # ).await.unwrap();
+    /// client.subscribe_algo_orders().await.expect("Failed to subscribe to algo orders");
+    /// # }
+    /// ```
+    pub async fn subscribe_algo_orders(&self) -> Result<()> {
+        let msg = json!({
+            "id": new_request_id(),
+            "topic": "algoexecutionreportv2",
+            "event": "subscribe"
+        });
+        self.subscribe(msg).await
+    }
+
+    /// Unsubscribe from algo order execution reports.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the unsubscription request was sent successfully,
+    /// or an error if the request failed or the connection is closed.
+    pub async fn unsubscribe_algo_orders(&self) -> Result<()> {
+        let msg = json!({
+            "id": new_request_id(),
+            "event": "unsubscribe",
+            "topic": "algoexecutionreportv2"
+        });
+        self.unsubscribe(msg).await
+    }
+
+    /// Subscribe to account configuration updates.
+    ///
+    /// The `account` topic pushes changes to account-level settings such as
+    /// leverage and margin mode.
+    ///
+    /// # Authentication
+    ///
+    /// This endpoint requires authentication with valid API credentials.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the subscription request was sent successfully,
+    /// or an error if the request failed, authentication failed, or the connection is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use orderly_connector_rs::websocket::WebsocketPrivateClient;
+    /// # use std::sync::Arc;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let client = WebsocketPrivateClient::connect(
+    /// #     "api_key".to_string(),
+    /// #     "secret".to_string(),
+    /// #     "account_id".to_string(),
+    /// #     true,
+    /// #     Arc::new(|msg| println!("{}", msg)),
+    /// #     Arc::new(|_| println!("Closed")),
+    /// # ).await.unwrap();
+    /// client.subscribe_account().await.expect("Failed to subscribe to account updates");
+    /// # }
+    /// ```
+    pub async fn subscribe_account(&self) -> Result<()> {
+        let msg = json!({
+            "id": new_request_id(),
+            "topic": "account",
+            "event": "subscribe"
+        });
+        self.subscribe(msg).await
+    }
+
+    /// Unsubscribe from account configuration updates.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the unsubscription request was sent successfully,
+    /// or an error if the request failed or the connection is closed.
+    pub async fn unsubscribe_account(&self) -> Result<()> {
+        let msg = json!({
+            "id": new_request_id(),
+            "event": "unsubscribe",
+            "topic": "account"
+        });
+        self.unsubscribe(msg).await
+    }
+
+    /// Subscribe to an arbitrary topic by name.
+    ///
+    /// This is an escape hatch for streams that don't have a dedicated helper yet.
+    /// It sends the standard `{id, topic, event}` subscription message and persists
+    /// it so the topic is resubscribed after reconnects, like the typed helpers.
+    ///
+    /// # Arguments
+    ///
+    /// * `topic` - The raw topic name (e.g., "algoexecutionreportv2")
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the subscription request was sent successfully,
+    /// or an error if the request failed or the connection is closed.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use orderly_connector_rs::websocket::WebsocketPrivateClient;
+    /// # use std::sync::Arc;
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// # let client = WebsocketPrivateClient::connect(
+    /// #     "api_key".to_string(),
+    /// #     "secret".to_string(),
+    /// #     "account_id".to_string(),
+    /// #     true,
+    /// #     Arc::new(|msg| println!("{}", msg)),
+    /// #     Arc::new(|_| println!("Closed")),
+    /// # ).await.unwrap();
+    /// client.subscribe_raw("algoexecutionreportv2").await.expect("Failed to subscribe");
+    /// # }
+    /// ```
+    pub async fn subscribe_raw(&self, topic: &str) -> Result<()> {
+        let msg = json!({
+            "id": new_request_id(),
+            "topic": topic,
+            "event": "subscribe"
+        });
+        self.subscribe(msg).await
+    }
+
+    /// Unsubscribe from an arbitrary topic by name.
+    ///
+    /// Removes the topic from the persisted subscription set, whether it was
+    /// subscribed via [`Self::subscribe_raw`] or a typed helper.
+    ///
+    /// # Arguments
+    ///
+    /// * `topic` - The raw topic name (e.g., "algoexecutionreportv2")
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the unsubscription request was sent successfully,
+    /// or an error if the request failed or the connection is closed.
+    pub async fn unsubscribe_raw(&self, topic: &str) -> Result<()> {
+        let msg = json!({
+            "id": new_request_id(),
+            "topic": topic,
+            "event": "unsubscribe"
+        });
+        self.unsubscribe(msg).await
+    }
+
+    /// Subscribes to `topic` like `subscribe_raw` and returns a [`SubscriptionHandle`]
+    /// that unsubscribes when dropped.
+    ///
+    /// Further handles on an already-held topic share its subscription; the topic is
+    /// unsubscribed once every handle has been released.
+    ///
+    /// # Arguments
+    ///
+    /// * `topic` - The raw topic name (e.g., "executionreport")
+    pub async fn subscribe_with_handle(&self, topic: &str) -> Result<SubscriptionHandle> {
+        let mut counts = self.handle_counts.lock().await;
+        if !counts.contains_key(topic) {
+            self.subscribe_raw(topic).await?;
+        }
+        let count = counts
+            .entry(topic.to_string())
+            .or_insert_with(|| HandleCount {
+                generation: HANDLE_GENERATIONS.fetch_add(1, Ordering::Relaxed),
+                handles: 0,
+            });
+        count.handles += 1;
+        Ok(SubscriptionHandle {
+            topic: topic.to_string(),
+            shared_tx: Arc::clone(&self.shared_tx),
+            subscriptions: Arc::clone(&self.subscriptions),
+            subscription_ids: Arc::clone(&self.subscription_ids),
+            handle_counts: Arc::clone(&self.handle_counts),
+            generation: count.generation,
+            armed: true,
+        })
+    }
+
+    // --- Connection State ---
+    /// Returns `true` if the client currently holds a live connection.
+    ///
+    /// This is `false` while the manager is reconnecting and after [`Self::stop`].
+    pub async fn is_connected(&self) -> bool {
+        self.shared_tx.lock().await.is_some()
+    }
+
+    /// Returns how many times the client has reconnected since it was created.
+    ///
+    /// Counts successful connections after the first; failed attempts are not counted.
+    pub fn reconnect_count(&self) -> u64 {
+        self.stats.reconnect_count()
+    }
+
+    /// Returns when the current connection was established, or `None` while
+    /// disconnected and after [`Self::stop`].
+    ///
+    /// Reset on every successful (re)connection, so `connected_since()?.elapsed()` is
+    /// the uptime of the current session.
+    pub fn connected_since(&self) -> Option<Instant> {
+        self.stats.connected_since()
+    }
+
+    /// Returns a snapshot of the subscription messages that are replayed after a reconnect.
+    pub async fn active_subscriptions(&self) -> Vec<String> {
+        self.subscriptions.lock().await.iter().cloned().collect()
+    }
+
+    /// Returns the topic of the active subscription whose request carried `id`.
+    ///
+    /// Every subscription message is sent with a unique `id`, which the server echoes
+    /// in its response; use this to correlate those responses with topics.
+    pub async fn subscription_topic(&self, id: &str) -> Option<String> {
+        self.subscription_ids.lock().await.get(id).cloned()
+    }
+
     // --- Stop Method ---
-    pub async fn stop(&self) {
+    /// Stops the client and waits for the connection to shut down.
+    ///
+    /// See [`WebsocketPublicClient::stop`] for details.
+    pub async fn stop(&self) -> Result<()> {
         info!("Stopping WebSocket client...");
-        self.manager_handle.abort();
-        let guard = self.shared_tx.lock().await;
-        if let Some(tx) = guard.as_ref() {
-            let _ = tx.send(Message::Close(None)).await;
+        let result = shutdown(&self.manager_handle, &self.shared_tx, &self.on_close).await;
+        self.stats.record_disconnected();
+        result?;
+        info!("WebSocket client stopped.");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::OrderStatus;
+
+    #[test]
+    fn test_remove_topic_matches_subscribe_message() {
+        let mut subs = HashSet::new();
+        subs.insert(
+            json!({"id": "subscribe_trade_PERP_ETH_USDC", "topic": "PERP_ETH_USDC@trade", "event": "subscribe"})
+                .to_string(),
+        );
+        subs.insert(
+            json!({"id": "subscribe_tickers", "topic": "tickers", "event": "subscribe"})
+                .to_string(),
+        );
+
+        remove_topic(&mut subs, "PERP_ETH_USDC@trade");
+
+        assert_eq!(subs.len(), 1);
+        assert!(subs.iter().all(|s| s.contains("tickers")));
+    }
+
+    #[test]
+    fn test_remove_topic_ignores_unknown_topic() {
+        let mut subs = HashSet::new();
+        subs.insert(
+            json!({"id": "subscribe_tickers", "topic": "tickers", "event": "subscribe"})
+                .to_string(),
+        );
+
+        remove_topic(&mut subs, "PERP_BTC_USDC@ticker");
+
+        assert_eq!(subs.len(), 1);
+    }
+
+    #[test]
+    fn test_pong_reply_echoes_ping_ts() {
+        let pong = pong_reply(r#"{"event":"ping","ts":1618820361552}"#).expect("expected pong");
+        let value: Value = serde_json::from_str(&pong).unwrap();
+        assert_eq!(value["event"], "pong");
+        assert_eq!(value["ts"], 1618820361552u64);
+
+        assert!(pong_reply(r#"{"event":"pong","ts":1618820361552}"#).is_none());
+        assert!(pong_reply(r#"{"topic":"tickers","ts":1618820361552,"data":[]}"#).is_none());
+        assert!(pong_reply("not json").is_none());
+    }
+
+    #[test]
+    fn test_clients_are_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<WebsocketPublicClient>();
+        assert_send_sync::<WebsocketPrivateClient>();
+    }
+
+    #[tokio::test]
+    async fn test_message_stream_parses_and_skips_invalid() {
+        let (on_message, mut stream) = message_stream();
+
+        on_message("not json".to_string());
+        on_message(json!({"event": "ping", "ts": 1618820361552u64}).to_string());
+        drop(on_message);
+
+        match stream.next().await {
+            Some(Ok(WebSocketMessage::Ping { ts })) => assert_eq!(ts, 1618820361552),
+            other => panic!("Expected Ping, got {:?}", other),
+        }
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_timestamped_stream_reports_latency() {
+        let (on_message, stream) = message_stream();
+        let mut stream = stream.timestamped();
+
+        let ts = auth::get_timestamp_ms().unwrap() - 1500;
+        on_message(json!({"event": "ping", "ts": ts}).to_string());
+        on_message(json!({"topic": "unknown"}).to_string());
+        drop(on_message);
+
+        let msg = stream.next().await.unwrap().unwrap();
+        assert!(matches!(msg.message, WebSocketMessage::Ping { .. }));
+        assert_eq!(msg.ts, Some(ts));
+        let latency = msg.latency().unwrap();
+        assert!(latency >= Duration::from_millis(1500));
+        assert!(latency < Duration::from_secs(60));
+
+        let msg = stream.next().await.unwrap().unwrap();
+        assert!(matches!(msg.message, WebSocketMessage::Other));
+        assert!(msg.latency().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_message_stream_reports_lag_when_buffer_fills() {
+        let (on_message, mut stream) = message_stream();
+        let ping = |ts: u64| json!({"event": "ping", "ts": ts}).to_string();
+
+        // Fill the buffer, then overflow it by three messages
+        for ts in 0..STREAM_BUFFER_SIZE as u64 + 3 {
+            on_message(ping(ts));
+        }
+        for ts in 0..STREAM_BUFFER_SIZE as u64 {
+            match stream.next().await {
+                Some(Ok(WebSocketMessage::Ping { ts: got })) => assert_eq!(got, ts),
+                other => panic!("Expected Ping {}, got {:?}", ts, other),
+            }
+        }
+
+        // Once there is room, the gap is reported before the next message
+        on_message(ping(9999));
+        drop(on_message);
+        assert!(matches!(stream.next().await, Some(Err(Lagged(3)))));
+        match stream.next().await {
+            Some(Ok(WebSocketMessage::Ping { ts })) => assert_eq!(ts, 9999),
+            other => panic!("Expected Ping, got {:?}", other),
         }
-        info!("Stop signal sent.");
+        assert!(stream.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_stream_drops_redelivered_execution_reports() {
+        let (on_message, mut stream) = message_stream();
+        let on_message = dedup_execution_reports(on_message, DEFAULT_DEDUP_CAPACITY);
+        let report = |trade_id: u64, status: &str| {
+            json!({
+                "topic": "executionreport",
+                "ts": 1657515256140u64,
+                "data": {
+                    "symbol": "PERP_ETH_USDC",
+                    "orderId": 42,
+                    "type": "LIMIT",
+                    "side": "BUY",
+                    "tradeId": trade_id,
+                    "status": status,
+                    "timestamp": 1657515256140u64
+                }
+            })
+            .to_string()
+        };
+
+        on_message(report(0, "NEW"));
+        on_message(report(7, "PARTIAL_FILLED"));
+        // Redelivered after a reconnect
+        on_message(report(0, "NEW"));
+        on_message(report(7, "PARTIAL_FILLED"));
+        on_message(report(8, "FILLED"));
+        drop(on_message);
+
+        let mut delivered = Vec::new();
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(WebSocketMessage::ExecutionReport(report)) => {
+                    delivered.push((report.trade_id, report.status))
+                }
+                other => panic!("Expected ExecutionReport, got {:?}", other),
+            }
+        }
+        assert_eq!(
+            delivered,
+            vec![
+                (Some(0), OrderStatus::New),
+                (Some(7), OrderStatus::PartialFilled),
+                (Some(8), OrderStatus::Filled),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_auth_rejection_detection() {
+        let rejected = json!({
+            "id": "auth",
+            "event": "auth",
+            "success": false,
+            "ts": 1618820361552u64,
+            "errorMsg": "invalid signature"
+        });
+        assert_eq!(
+            auth_rejection(&rejected.to_string()).as_deref(),
+            Some("invalid signature")
+        );
+
+        let accepted = json!({"id": "auth", "event": "auth", "success": true});
+        assert!(auth_rejection(&accepted.to_string()).is_none());
+        assert!(auth_rejection(r#"{"topic":"executionreport"}"#).is_none());
+        assert!(auth_rejection("not json auth").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_reports_full_channel() {
+        let (tx, _rx) = mpsc::channel::<Message>(1);
+        enqueue(&tx, Message::Text("first".to_string()))
+            .await
+            .expect("first message should fit");
+
+        let err = enqueue(&tx, Message::Text("second".to_string()))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Outbound channel full"));
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_reports_closed_writer() {
+        let (tx, rx) = mpsc::channel::<Message>(1);
+        drop(rx);
+
+        let err = enqueue(&tx, Message::Text("msg".to_string()))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("Writer task closed"));
     }
 }
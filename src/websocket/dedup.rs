@@ -0,0 +1,199 @@
+use crate::types::{ExecutionReport, OrderStatus, WebSocketMessage};
+use std::collections::{HashSet, VecDeque};
+
+/// Default number of recent execution reports remembered by [`ExecutionReportDeduplicator`].
+pub const DEFAULT_DEDUP_CAPACITY: usize = 10_000;
+
+/// Identifies a single execution report event.
+///
+/// Fills are identified by `(order_id, trade_id)`. Reports without a trade (order
+/// accepted, cancelled, ...) are identified by `(order_id, status, timestamp)`; the
+/// server sends these with a `tradeId` of 0.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum EventKey {
+    Fill {
+        order_id: u64,
+        trade_id: u64,
+    },
+    Status {
+        order_id: u64,
+        status: OrderStatus,
+        timestamp: u64,
+    },
+}
+
+impl EventKey {
+    fn of(report: &ExecutionReport) -> Self {
+        match report.trade_id {
+            Some(trade_id) if trade_id != 0 => EventKey::Fill {
+                order_id: report.order_id,
+                trade_id,
+            },
+            _ => EventKey::Status {
+                order_id: report.order_id,
+                status: report.status.clone(),
+                timestamp: report.timestamp,
+            },
+        }
+    }
+}
+
+/// Drops execution reports that were already delivered.
+///
+/// After a reconnect the private stream may redeliver execution reports, which makes
+/// consumers that sum fills count them twice. The deduplicator remembers the most
+/// recent `capacity` events and reports any repeat as a duplicate.
+///
+/// # Examples
+///
+/// ```no_run
+/// use orderly_connector_rs::types::WebSocketMessage;
+/// use orderly_connector_rs::websocket::ExecutionReportDeduplicator;
+///
+/// let mut dedup = ExecutionReportDeduplicator::new();
+/// # let raw = "";
+/// let msg: WebSocketMessage = serde_json::from_str(raw).unwrap();
+/// if let Some(WebSocketMessage::ExecutionReport(report)) = dedup.filter(msg) {
+///     println!("New event for order {}", report.order_id);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExecutionReportDeduplicator {
+    capacity: usize,
+    seen: HashSet<EventKey>,
+    order: VecDeque<EventKey>,
+}
+
+impl Default for ExecutionReportDeduplicator {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_DEDUP_CAPACITY)
+    }
+}
+
+impl ExecutionReportDeduplicator {
+    /// Creates a deduplicator remembering [`DEFAULT_DEDUP_CAPACITY`] events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a deduplicator remembering the last `capacity` events (at least one).
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            seen: HashSet::with_capacity(capacity),
+            order: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Records `report` and returns `true` if the same event was already seen.
+    pub fn is_duplicate(&mut self, report: &ExecutionReport) -> bool {
+        let key = EventKey::of(report);
+        if self.seen.contains(&key) {
+            return true;
+        }
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(key.clone());
+        self.order.push_back(key);
+        false
+    }
+
+    /// Passes `msg` through unless it is an execution report that was already seen.
+    pub fn filter(&mut self, msg: WebSocketMessage) -> Option<WebSocketMessage> {
+        match &msg {
+            WebSocketMessage::ExecutionReport(report) if self.is_duplicate(report) => None,
+            _ => Some(msg),
+        }
+    }
+
+    /// Forgets all remembered events.
+    pub fn clear(&mut self) {
+        self.seen.clear();
+        self.order.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Side;
+
+    fn report(order_id: u64, trade_id: Option<u64>, status: OrderStatus) -> ExecutionReport {
+        ExecutionReport {
+            symbol: "PERP_ETH_USDC".to_string(),
+            client_order_id: None,
+            order_id,
+            order_type: "LIMIT".to_string(),
+            side: Side::Buy,
+            quantity: Some(1.0),
+            price: Some(2000.0),
+            trade_id,
+            executed_price: None,
+            executed_quantity: None,
+            fee: None,
+            fee_asset: None,
+            total_executed_quantity: None,
+            avg_price: None,
+            status,
+            reason: None,
+            order_tag: None,
+            total_fee: None,
+            visible: None,
+            timestamp: 1677721600000,
+            reduce_only: None,
+            maker: None,
+        }
+    }
+
+    #[test]
+    fn test_redelivered_fill_is_duplicate() {
+        let mut dedup = ExecutionReportDeduplicator::new();
+        assert!(!dedup.is_duplicate(&report(1, Some(10), OrderStatus::PartialFilled)));
+        assert!(!dedup.is_duplicate(&report(1, Some(11), OrderStatus::Filled)));
+        assert!(dedup.is_duplicate(&report(1, Some(10), OrderStatus::PartialFilled)));
+    }
+
+    #[test]
+    fn test_status_updates_without_trade() {
+        let mut dedup = ExecutionReportDeduplicator::new();
+        assert!(!dedup.is_duplicate(&report(1, None, OrderStatus::New)));
+        assert!(!dedup.is_duplicate(&report(1, None, OrderStatus::Cancelled)));
+        assert!(dedup.is_duplicate(&report(1, None, OrderStatus::New)));
+    }
+
+    #[test]
+    fn test_zero_trade_id_is_status_update() {
+        let mut dedup = ExecutionReportDeduplicator::new();
+        assert!(!dedup.is_duplicate(&report(1, Some(0), OrderStatus::New)));
+        assert!(!dedup.is_duplicate(&report(1, Some(0), OrderStatus::Cancelled)));
+        assert!(dedup.is_duplicate(&report(1, Some(0), OrderStatus::Cancelled)));
+    }
+
+    #[test]
+    fn test_filter_passes_other_messages_and_evicts_oldest() {
+        let mut dedup = ExecutionReportDeduplicator::with_capacity(1);
+        assert!(dedup.filter(WebSocketMessage::Other).is_some());
+        assert!(dedup
+            .filter(WebSocketMessage::ExecutionReport(report(
+                1,
+                Some(10),
+                OrderStatus::Filled
+            )))
+            .is_some());
+        assert!(dedup
+            .filter(WebSocketMessage::ExecutionReport(report(
+                1,
+                Some(10),
+                OrderStatus::Filled
+            )))
+            .is_none());
+
+        // Capacity 1: a new event evicts the first, so it is no longer recognised
+        assert!(!dedup.is_duplicate(&report(2, Some(20), OrderStatus::Filled)));
+        assert!(!dedup.is_duplicate(&report(1, Some(10), OrderStatus::Filled)));
+    }
+}
@@ -0,0 +1,262 @@
+//! A shared public WebSocket connection that fans messages out to per-topic handlers.
+
+use crate::error::{OrderlyError, Result};
+use crate::websocket::client::{CloseReason, WebsocketClientConfig, WebsocketPublicClient};
+use log::trace;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::Mutex;
+
+/// Callback receiving the raw JSON text of each message on a topic.
+pub type TopicHandler = Arc<dyn Fn(String) + Send + Sync + 'static>;
+
+type HandlerMap = Arc<std::sync::Mutex<HashMap<String, Vec<(u64, TopicHandler)>>>>;
+
+/// The only field the hub needs to route a message.
+#[derive(Deserialize)]
+struct TopicEnvelope {
+    topic: Option<String>,
+}
+
+/// Identifies one handler registered with [`WebsocketHub::subscribe`].
+///
+/// Pass it back to [`WebsocketHub::unsubscribe`] to remove the handler.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct HubSubscription {
+    topic: String,
+    id: u64,
+}
+
+impl HubSubscription {
+    /// Returns the topic the handler was registered for.
+    pub fn topic(&self) -> &str {
+        &self.topic
+    }
+}
+
+/// Serves many topic subscriptions over a single public WebSocket connection.
+///
+/// Each [`WebsocketPublicClient`] owns its own connection, so an application that
+/// creates one client per component opens one socket per component. The hub instead
+/// keeps one client and routes every incoming message to the handlers registered for
+/// its `topic`. Topics are reference counted: the server subscription is sent when the
+/// first handler for a topic is added and withdrawn when the last one is removed.
+///
+/// Reconnection and resubscription are handled by the underlying client. Messages
+/// without a `topic`, such as subscription acknowledgements, are not delivered.
+///
+/// # Examples
+///
+/// ```no_run
+/// use orderly_connector_rs::websocket::WebsocketHub;
+/// use std::sync::Arc;
+///
+/// # async fn run() -> orderly_connector_rs::Result<()> {
+/// let hub = WebsocketHub::connect("your_account_id".to_string(), true, Arc::new(|_| {})).await?;
+///
+/// // Two components interested in the same topic share one server subscription
+/// let chart = hub
+///     .subscribe("PERP_ETH_USDC@trade", Arc::new(|msg| println!("chart: {}", msg)))
+///     .await?;
+/// let tape = hub
+///     .subscribe("PERP_ETH_USDC@trade", Arc::new(|msg| println!("tape: {}", msg)))
+///     .await?;
+///
+/// hub.unsubscribe(chart).await?; // still subscribed for `tape`
+/// hub.unsubscribe(tape).await?; // unsubscribes from the server
+/// hub.stop().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct WebsocketHub {
+    client: WebsocketPublicClient,
+    handlers: HandlerMap,
+    next_id: AtomicU64,
+    // Serializes subscribe/unsubscribe so handler counts and server state stay in step
+    ops: Mutex<()>,
+}
+
+impl std::fmt::Debug for WebsocketHub {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Handlers have no Debug representation
+        f.debug_struct("WebsocketHub")
+            .field("client", &self.client)
+            .field("topics", &self.topics())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Builds the client message callback that routes each message to its topic's handlers.
+fn dispatcher(handlers: HandlerMap) -> TopicHandler {
+    Arc::new(move |msg: String| {
+        let topic = match serde_json::from_str::<TopicEnvelope>(&msg) {
+            Ok(TopicEnvelope { topic: Some(topic) }) => topic,
+            _ => {
+                trace!("Hub dropped message without a topic: {}", msg);
+                return;
+            }
+        };
+        // Call handlers outside the lock so a slow handler does not block registration
+        let targets: Vec<TopicHandler> = handlers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&topic)
+            .map(|entries| entries.iter().map(|(_, h)| Arc::clone(h)).collect())
+            .unwrap_or_default();
+        for handler in targets {
+            handler(msg.clone());
+        }
+    })
+}
+
+impl WebsocketHub {
+    /// Connects a hub to the mainnet or testnet public stream for `account_id`.
+    pub async fn connect(
+        account_id: String,
+        is_testnet: bool,
+        on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
+    ) -> Result<Self> {
+        let handlers = HandlerMap::default();
+        let client = WebsocketPublicClient::connect(
+            account_id,
+            is_testnet,
+            dispatcher(Arc::clone(&handlers)),
+            on_close,
+        )
+        .await?;
+        Ok(Self::from_parts(client, handlers))
+    }
+
+    /// Connects a hub using an explicit [`WebsocketClientConfig`].
+    ///
+    /// See [`WebsocketPublicClient::connect_with_config`] for the expected `base_url`.
+    pub async fn connect_with_config(
+        config: WebsocketClientConfig,
+        on_close: Arc<dyn Fn(CloseReason) + Send + Sync + 'static>,
+    ) -> Result<Self> {
+        let handlers = HandlerMap::default();
+        let client = WebsocketPublicClient::connect_with_config(
+            config,
+            dispatcher(Arc::clone(&handlers)),
+            on_close,
+        )
+        .await?;
+        Ok(Self::from_parts(client, handlers))
+    }
+
+    fn from_parts(client: WebsocketPublicClient, handlers: HandlerMap) -> Self {
+        Self {
+            client,
+            handlers,
+            next_id: AtomicU64::new(0),
+            ops: Mutex::new(()),
+        }
+    }
+
+    /// Registers `handler` for every message on `topic`.
+    ///
+    /// The server subscription is only sent for the first handler of a topic. If that
+    /// fails the handler is not registered and the error is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `topic` - The raw topic name (e.g., "PERP_ETH_USDC@orderbook")
+    /// * `handler` - Called with the raw JSON text of each message on the topic
+    pub async fn subscribe(&self, topic: &str, handler: TopicHandler) -> Result<HubSubscription> {
+        let _guard = self.ops.lock().await;
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let first = {
+            let mut handlers = self.handlers.lock().unwrap_or_else(|e| e.into_inner());
+            let entries = handlers.entry(topic.to_string()).or_default();
+            entries.push((id, handler));
+            entries.len() == 1
+        };
+        if first {
+            if let Err(err) = self.client.subscribe_raw(topic).await {
+                self.handlers
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .remove(topic);
+                // A failed send leaves the topic persisted in the client; drop it so it
+                // is not replayed on reconnect. Sending will fail for the same reason.
+                if !matches!(err, OrderlyError::ValidationError(_)) {
+                    let _ = self.client.unsubscribe_raw(topic).await;
+                }
+                return Err(err);
+            }
+        }
+        Ok(HubSubscription {
+            topic: topic.to_string(),
+            id,
+        })
+    }
+
+    /// Removes the handler identified by `subscription`.
+    ///
+    /// The server subscription is withdrawn once no handlers remain for the topic.
+    pub async fn unsubscribe(&self, subscription: HubSubscription) -> Result<()> {
+        let _guard = self.ops.lock().await;
+        let last = {
+            let mut handlers = self.handlers.lock().unwrap_or_else(|e| e.into_inner());
+            match handlers.get_mut(&subscription.topic) {
+                Some(entries) => {
+                    entries.retain(|(id, _)| *id != subscription.id);
+                    if entries.is_empty() {
+                        handlers.remove(&subscription.topic);
+                        true
+                    } else {
+                        false
+                    }
+                }
+                None => false,
+            }
+        };
+        if last {
+            self.client.unsubscribe_raw(&subscription.topic).await
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns the number of handlers registered for `topic`.
+    pub fn handler_count(&self, topic: &str) -> usize {
+        self.handlers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(topic)
+            .map_or(0, Vec::len)
+    }
+
+    /// Returns the topics that currently have at least one handler.
+    pub fn topics(&self) -> Vec<String> {
+        self.handlers
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect()
+    }
+
+    /// Returns `true` if the shared connection is currently live.
+    pub async fn is_connected(&self) -> bool {
+        self.client.is_connected().await
+    }
+
+    /// Returns how many times the shared connection has reconnected.
+    pub fn reconnect_count(&self) -> u64 {
+        self.client.reconnect_count()
+    }
+
+    /// Returns when the current shared connection was established.
+    pub fn connected_since(&self) -> Option<Instant> {
+        self.client.connected_since()
+    }
+
+    /// Stops the shared connection. See [`WebsocketPublicClient::stop`].
+    pub async fn stop(&self) -> Result<()> {
+        self.client.stop().await
+    }
+}
@@ -6,6 +6,9 @@
 //! - [`WebsocketPublicClient`]: For public market data streams (tickers, orderbook, trades)
 //! - [`WebsocketPrivateClient`]: For private, authenticated streams (orders, positions, balance)
 //!
+//! [`WebsocketHub`] shares one public connection between many per-topic handlers, for
+//! applications that would otherwise open a client per component.
+//!
 //! # Architecture
 //!
 //! The WebSocket implementation uses a robust connection management system that provides:
@@ -14,6 +17,7 @@
 //! - Subscription state persistence
 //! - Asynchronous message handling
 //! - Automatic ping/pong handling
+//! - Heartbeat watchdog that reconnects stale connections
 //! - Clean shutdown capabilities
 //!
 //! # Usage
@@ -30,8 +34,8 @@
 //!     let message_handler = Arc::new(|msg: String| {
 //!         println!("Received: {}", msg);
 //!     });
-//!     let close_handler = Arc::new(|| {
-//!         println!("Connection closed");
+//!     let close_handler = Arc::new(|reason| {
+//!         println!("Connection closed: {:?}", reason);
 //!     });
 //!
 //!     // Connect to public streams
@@ -48,7 +52,7 @@
 //!
 //!     // Keep alive until Ctrl+C
 //!     tokio::signal::ctrl_c().await.expect("Failed to listen for ctrl+c");
-//!     client.stop().await;
+//!     client.stop().await.expect("Failed to stop client");
 //! }
 //! ```
 //!
@@ -64,8 +68,8 @@
 //!     let message_handler = Arc::new(|msg: String| {
 //!         println!("Received: {}", msg);
 //!     });
-//!     let close_handler = Arc::new(|| {
-//!         println!("Connection closed");
+//!     let close_handler = Arc::new(|reason| {
+//!         println!("Connection closed: {:?}", reason);
 //!     });
 //!
 //!     // Connect to private streams with authentication
@@ -84,7 +88,7 @@
 //!
 //!     // Keep alive until Ctrl+C
 //!     tokio::signal::ctrl_c().await.expect("Failed to listen for ctrl+c");
-//!     client.stop().await;
+//!     client.stop().await.expect("Failed to stop client");
 //! }
 //! ```
 //!
@@ -102,7 +106,7 @@
 //!         "your_account_id".to_string(),
 //!         true,
 //!         Arc::new(|msg| println!("Received: {}", msg)),
-//!         Arc::new(|| println!("Closed")),
+//!         Arc::new(|_| println!("Closed")),
 //!     ).await;
 //!
 //!     match client {
@@ -125,16 +129,37 @@
 //! - Automatic resubscription to previous topics after reconnection
 //! - Authentication renewal for private streams
 //!
+//! Execution reports may be redelivered after a reconnect. Set `dedup_execution_reports`
+//! on the [`WebsocketClientConfig`] to have the private client drop them before they reach
+//! `on_message`; the private `connect_stream` does so by default. An
+//! [`ExecutionReportDeduplicator`] can also be applied to parsed messages directly.
+//!
 //! # Message Handling
 //!
 //! Messages are handled asynchronously through callback functions:
 //!
 //! - `on_message`: Called for each received message
-//! - `on_close`: Called when the connection is closed
+//! - `on_close`: Called with a [`CloseReason`] when the connection is closed
 //!
 //! These callbacks should be thread-safe and quick to execute to avoid blocking the WebSocket loop.
+//!
+//! Alternatively, `connect_stream` returns a [`WebsocketMessageStream`] of parsed
+//! [`WebSocketMessage`](crate::types::WebSocketMessage) values for use with `StreamExt`.
+//! Its `timestamped()` adapter yields [`TimestampedMessage`](crate::types::TimestampedMessage)s
+//! carrying each frame's server `ts` and latency, for monitoring feed lag. Items are
+//! `Result`s: a consumer that falls behind the stream's buffer receives `Err(Lagged(n))`
+//! in place of the messages that were dropped.
 
 pub mod client;
+pub mod dedup;
+pub mod hub;
+pub mod orderbook;
 
 // Re-export the client structs for easier access
-pub use client::{WebsocketClientConfig, WebsocketPrivateClient, WebsocketPublicClient};
+pub use client::{
+    ClientHandlers, CloseReason, Lagged, SubscriptionHandle, TimestampedMessageStream,
+    WebsocketClientConfig, WebsocketMessageStream, WebsocketPrivateClient, WebsocketPublicClient,
+};
+pub use dedup::ExecutionReportDeduplicator;
+pub use hub::{HubSubscription, TopicHandler, WebsocketHub};
+pub use orderbook::{OrderbookGap, OrderbookSequenceValidator};
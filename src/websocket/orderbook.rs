@@ -0,0 +1,163 @@
+use crate::types::OrderbookData;
+use std::collections::HashMap;
+
+/// Describes an incremental orderbook update that does not chain onto the previous one.
+///
+/// When a gap is reported the locally reconstructed book can no longer be trusted:
+/// fetch a fresh snapshot (e.g. via `OrderlyService::get_orderbook_snapshot`) and
+/// call [`OrderbookSequenceValidator::reset`] with its timestamp.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderbookGap {
+    /// The symbol whose update stream has a gap
+    pub symbol: String,
+    /// `ts` of the last update accepted for this symbol
+    pub last_ts: u64,
+    /// `ts` of the update that revealed the gap
+    pub ts: u64,
+    /// `prevTs` carried by that update, if any
+    pub prev_ts: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SequenceState {
+    ts: u64,
+    last_update_id: Option<u64>,
+}
+
+/// Validates that `@orderbookupdate` messages arrive in order and without gaps.
+///
+/// Each incremental update carries `prevTs`, the `ts` of the update that preceded it.
+/// The validator remembers the last accepted `ts` per symbol and reports an
+/// [`OrderbookGap`] when `prevTs` does not match it, or when `lastUpdateId` goes
+/// backwards. The first update seen for a symbol (or the first after a reset) starts
+/// a new chain.
+///
+/// # Examples
+///
+/// ```no_run
+/// use orderly_connector_rs::types::OrderbookUpdate;
+/// use orderly_connector_rs::websocket::OrderbookSequenceValidator;
+///
+/// let mut validator = OrderbookSequenceValidator::new();
+/// # let raw = "";
+/// let update: OrderbookUpdate = serde_json::from_str(raw).unwrap();
+/// if let Some(gap) = validator.check_update(update.ts, &update.data) {
+///     println!("Missed updates for {}; refetching snapshot", gap.symbol);
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OrderbookSequenceValidator {
+    states: HashMap<String, SequenceState>,
+}
+
+impl OrderbookSequenceValidator {
+    /// Creates a validator with no known sequence state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts a new chain for `symbol` at `ts`, typically the timestamp of a fresh snapshot.
+    pub fn reset(&mut self, symbol: &str, ts: u64) {
+        self.states.insert(
+            symbol.to_string(),
+            SequenceState {
+                ts,
+                last_update_id: None,
+            },
+        );
+    }
+
+    /// Forgets all sequence state for `symbol`.
+    pub fn clear(&mut self, symbol: &str) {
+        self.states.remove(symbol);
+    }
+
+    /// Checks an incremental update received at `ts` and records it as the latest.
+    ///
+    /// Returns `Some(OrderbookGap)` if the update does not chain onto the previously
+    /// accepted one. The update is still recorded, so checking continues from it.
+    pub fn check_update(&mut self, ts: u64, data: &OrderbookData) -> Option<OrderbookGap> {
+        let next = SequenceState {
+            ts,
+            last_update_id: data.last_update_id,
+        };
+        let previous = self.states.insert(data.symbol.clone(), next)?;
+
+        let prev_ts_mismatch = matches!(data.prev_ts, Some(prev_ts) if prev_ts != previous.ts);
+        let update_id_regressed = match (previous.last_update_id, data.last_update_id) {
+            (Some(last), Some(current)) => current <= last,
+            _ => false,
+        };
+
+        if prev_ts_mismatch || update_id_regressed {
+            Some(OrderbookGap {
+                symbol: data.symbol.clone(),
+                last_ts: previous.ts,
+                ts,
+                prev_ts: data.prev_ts,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update(prev_ts: Option<u64>, last_update_id: Option<u64>) -> OrderbookData {
+        OrderbookData {
+            symbol: "PERP_ETH_USDC".to_string(),
+            bids: vec![],
+            asks: vec![],
+            checksum: None,
+            last_update_id,
+            prev_ts,
+        }
+    }
+
+    #[test]
+    fn test_chained_updates_have_no_gap() {
+        let mut validator = OrderbookSequenceValidator::new();
+        assert!(validator
+            .check_update(100, &update(Some(90), None))
+            .is_none());
+        assert!(validator
+            .check_update(200, &update(Some(100), None))
+            .is_none());
+        assert!(validator
+            .check_update(300, &update(Some(200), None))
+            .is_none());
+    }
+
+    #[test]
+    fn test_missing_update_reports_gap() {
+        let mut validator = OrderbookSequenceValidator::new();
+        validator.reset("PERP_ETH_USDC", 100);
+
+        let gap = validator
+            .check_update(300, &update(Some(200), None))
+            .expect("expected a gap");
+        assert_eq!(gap.symbol, "PERP_ETH_USDC");
+        assert_eq!(gap.last_ts, 100);
+        assert_eq!(gap.ts, 300);
+        assert_eq!(gap.prev_ts, Some(200));
+
+        // Checking resumes from the update that revealed the gap
+        assert!(validator
+            .check_update(400, &update(Some(300), None))
+            .is_none());
+    }
+
+    #[test]
+    fn test_regressing_update_id_reports_gap() {
+        let mut validator = OrderbookSequenceValidator::new();
+        assert!(validator
+            .check_update(100, &update(None, Some(10)))
+            .is_none());
+        assert!(validator
+            .check_update(200, &update(None, Some(9)))
+            .is_some());
+    }
+}
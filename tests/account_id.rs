@@ -0,0 +1,127 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{
+    error::OrderlyError, rest::OrderlyService, solana::types::SolanaConfig, types::ChainId,
+};
+use serde_json::json;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+#[tokio::test]
+async fn test_get_account_id_for_solana_address() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/get_account")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded(
+                "address".into(),
+                "7Xh5Qw9pJ3kZ5yNf8v2cT1mR4sL6dB9eH2gK3aP1uVzQ".into(),
+            ),
+            Matcher::UrlEncoded("broker_id".into(), "raydium".into()),
+            Matcher::UrlEncoded("chain_type".into(), "SOL".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(
+            json!({"user_id": 12345, "account_id": "0xabc123"}),
+        ))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let account_id = client
+        .get_account_id("7Xh5Qw9pJ3kZ5yNf8v2cT1mR4sL6dB9eH2gK3aP1uVzQ", "raydium")
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(account_id, "0xabc123");
+}
+
+#[tokio::test]
+async fn test_get_account_id_for_evm_address() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/get_account")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("address".into(), "0x1234".into()),
+            Matcher::UrlEncoded("chain_type".into(), "EVM".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({"account_id": "0xdef456"})))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let account_id = client.get_account_id("0x1234", "woofi_pro").await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(account_id, "0xdef456");
+}
+
+#[tokio::test]
+async fn test_get_account_id_rejects_empty_address() {
+    let client = OrderlyService::with_base_url("http://127.0.0.1:1", None).unwrap();
+    let result = client.get_account_id("", "raydium").await;
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_register_solana_account_returns_existing_account_id() {
+    let mut server = Server::new_async().await;
+    let keypair = Keypair::new();
+    let address = keypair.pubkey().to_string();
+
+    let _registered = server
+        .mock("GET", "/v1/public/wallet_registered")
+        .match_query(Matcher::UrlEncoded("address".into(), address.clone()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "status": "ok",
+                "timestamp": 1677721600123_u64,
+                "data": {"is_registered": true}
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+    let lookup = server
+        .mock("GET", "/v1/get_account")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("address".into(), address.clone()),
+            Matcher::UrlEncoded("broker_id".into(), "raydium".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({"account_id": "0xexisting"})))
+        .create_async()
+        .await;
+    let register = server
+        .mock("POST", "/v1/register_account")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let config = SolanaConfig {
+        rpc_url: "http://127.0.0.1:8899".to_string(),
+        api_base_url: server.url(),
+        usdc_mint: Pubkey::new_unique(),
+        broker_id: "raydium".to_string(),
+        orderly_solana_chain_id: ChainId::SOLANA_DEVNET,
+    };
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let account_id = client
+        .register_solana_account(&config, &keypair)
+        .await
+        .unwrap();
+
+    lookup.assert_async().await;
+    register.assert_async().await;
+    assert_eq!(account_id, "0xexisting");
+}
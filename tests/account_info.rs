@@ -0,0 +1,55 @@
+use orderly_connector_rs::types::GetAccountInfoResponse;
+use serde_json::json;
+
+fn account_info_payload() -> serde_json::Value {
+    json!({
+        "success": true,
+        "timestamp": 1677721600123_u64,
+        "data": {
+            "account_id": "0x1234",
+            "email": null,
+            "account_mode": "FUTURES",
+            "maintenance_cancel_orders": true,
+            "taker_fee_rate": 5.0,
+            "maker_fee_rate": 2.0,
+            "max_leverage": 20.0,
+            "futures_taker_fee_rate": 5.0,
+            "futures_maker_fee_rate": 2.0,
+            "imr_factor": {"PERP_ETH_USDC": 0.0000000011},
+            "max_notional": {"PERP_ETH_USDC": 1000000}
+        }
+    })
+}
+
+#[test]
+fn test_account_info_margin_fields() {
+    let mut payload = account_info_payload();
+    let data = payload["data"].as_object_mut().unwrap();
+    data.insert("free_collateral".into(), json!(850.25));
+    data.insert("total_collateral_value".into(), json!(1000.5));
+    data.insert("margin_ratio".into(), json!(2.5));
+    data.insert("maintenance_margin_ratio".into(), json!(0.025));
+    data.insert("initial_margin_ratio".into(), json!(0.05));
+
+    let response: GetAccountInfoResponse = serde_json::from_value(payload).unwrap();
+    let info = response.data;
+
+    assert_eq!(info.free_collateral, Some(850.25));
+    assert_eq!(info.total_collateral_value, Some(1000.5));
+    assert_eq!(info.margin_ratio, Some(2.5));
+    assert_eq!(info.maintenance_margin_ratio, Some(0.025));
+    assert_eq!(info.initial_margin_ratio, Some(0.05));
+}
+
+#[test]
+fn test_account_info_without_margin_fields() {
+    let response: GetAccountInfoResponse = serde_json::from_value(account_info_payload()).unwrap();
+    let info = response.data;
+
+    assert_eq!(info.account_id, "0x1234");
+    assert!(info.free_collateral.is_none());
+    assert!(info.total_collateral_value.is_none());
+    assert!(info.margin_ratio.is_none());
+    assert!(info.maintenance_margin_ratio.is_none());
+    assert!(info.initial_margin_ratio.is_none());
+}
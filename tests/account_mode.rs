@@ -0,0 +1,78 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{error::OrderlyError, rest::OrderlyService, types::AccountMode};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_get_account_mode() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/v1/client/account_mode")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({"account_mode": "FUTURES"})))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let response = client
+        .get_account_mode(&common::test_credentials())
+        .await
+        .unwrap();
+
+    assert_eq!(response.data.account_mode, AccountMode::Futures);
+}
+
+#[tokio::test]
+async fn test_set_account_mode() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/client/account_mode")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .match_body(Matcher::Json(json!({"account_mode": "UNIFIED"})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({"account_mode": "UNIFIED"})))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let response = client
+        .set_account_mode(&common::test_credentials(), AccountMode::Unified)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.data.account_mode, AccountMode::Unified);
+}
+
+#[tokio::test]
+async fn test_set_account_mode_rejects_unknown() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/client/account_mode")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let result = client
+        .set_account_mode(&common::test_credentials(), AccountMode::Unknown)
+        .await;
+
+    mock.assert_async().await;
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+}
+
+#[test]
+fn test_unrecognised_account_mode_deserializes_as_unknown() {
+    let mode: AccountMode = serde_json::from_value(json!("PORTFOLIO")).unwrap();
+    assert_eq!(mode, AccountMode::Unknown);
+}
@@ -0,0 +1,35 @@
+use orderly_connector_rs::types::{GetFundingFeeHistoryResponseData, GetSettlementsResponseData};
+use serde_json::json;
+
+#[test]
+fn test_settlement_total_pnl() {
+    let data: GetSettlementsResponseData = serde_json::from_value(json!({
+        "rows": [
+            {"id": 1, "symbol": "PERP_ETH_USDC", "settlement_price": 2000.0, "settlement_pnl": 12.5, "timestamp": 1677721600000_u64},
+            {"id": 2, "symbol": "PERP_BTC_USDC", "settlement_price": 30000.0, "settlement_pnl": -2.5, "timestamp": 1677721600000_u64}
+        ],
+        "meta": null
+    }))
+    .unwrap();
+
+    assert_eq!(data.total_pnl(), 10.0);
+}
+
+#[test]
+fn test_funding_fee_totals() {
+    let data: GetFundingFeeHistoryResponseData = serde_json::from_value(json!({
+        "rows": [
+            {"id": 1, "symbol": "PERP_ETH_USDC", "funding_rate": 0.0001, "funding_fee": 1.5, "timestamp": 1677721600000_u64},
+            {"id": 2, "symbol": "PERP_ETH_USDC", "funding_rate": -0.0001, "funding_fee": -0.5, "timestamp": 1677750400000_u64},
+            {"id": 3, "symbol": "PERP_BTC_USDC", "funding_rate": 0.0002, "funding_fee": 2.0, "timestamp": 1677721600000_u64}
+        ],
+        "meta": null
+    }))
+    .unwrap();
+
+    assert_eq!(data.total_fee(), 3.0);
+    let by_symbol = data.net_funding_by_symbol();
+    assert_eq!(by_symbol.len(), 2);
+    assert_eq!(by_symbol["PERP_ETH_USDC"], 1.0);
+    assert_eq!(by_symbol["PERP_BTC_USDC"], 2.0);
+}
@@ -2,7 +2,9 @@ use env_logger;
 use mockito;
 use orderly_connector_rs::{
     rest::{client::Credentials, OrderlyService},
-    types::{AlgoOrderType, CreateAlgoOrderRequest, GetAlgoOrdersParams, Side},
+    types::{
+        AlgoOrderType, CreateAlgoOrderRequest, EditAlgoOrderRequest, GetAlgoOrdersParams, Side,
+    },
 };
 use serde_json::json;
 
@@ -60,6 +62,8 @@ async fn test_create_algo_order() {
         trigger_price: 50000.0,
         limit_price: None,
         trailing_delta: None,
+        trailing_type: None,
+        activation_price: None,
         client_order_id: Some("my_stop_loss_1".to_string()),
         reduce_only: Some(true),
     };
@@ -290,6 +294,8 @@ async fn test_validation_errors() {
         trigger_price: 50000.0,
         limit_price: None,
         trailing_delta: None,
+        trailing_type: None,
+        activation_price: None,
         client_order_id: None,
         reduce_only: None,
     };
@@ -341,3 +347,82 @@ async fn test_validation_errors() {
         ))
     ));
 }
+
+#[tokio::test]
+async fn test_edit_algo_order() {
+    setup_logger(); // Init logger
+    let mut server = mockito::Server::new_async().await;
+    let mock_response = json!({
+        "success": true,
+        "timestamp": 1677721600123_u64,
+        "data": {
+            "algo_order_id": "123456",
+            "client_order_id": null,
+            "symbol": "PERP_BTC_USDC",
+            "order_type": "STOP_MARKET",
+            "side": "SELL",
+            "quantity": 0.1,
+            "trigger_price": 51000.0,
+            "status": "NEW",
+            "reduce_only": true,
+            "created_time": 1677721600000_i64,
+            "updated_time": 1677721700000_i64
+        }
+    });
+
+    let _m = server
+        .mock("PUT", "/v1/algo-order")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-account-id", "test_account")
+        .match_header("orderly-timestamp", mockito::Matcher::Any)
+        .match_header("orderly-signature", mockito::Matcher::Any)
+        .match_body(mockito::Matcher::Json(json!({
+            "order_id": 123456,
+            "trigger_price": 51000.0
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response.to_string())
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = Credentials {
+        orderly_key: "test_key",
+        orderly_secret: "11111111111111111111111111111111",
+        orderly_account_id: "test_account",
+    };
+
+    let request = EditAlgoOrderRequest {
+        trigger_price: Some(51000.0),
+        ..Default::default()
+    };
+    let response = client
+        .edit_algo_order(&creds, "123456", request)
+        .await
+        .unwrap();
+
+    assert!(response.success);
+    assert_eq!(response.data.algo_order_id, "123456");
+    assert_eq!(response.data.trigger_price, 51000.0);
+}
+
+#[tokio::test]
+async fn test_edit_algo_order_requires_a_change() {
+    let client = OrderlyService::with_base_url("http://localhost", None).unwrap();
+    let creds = Credentials {
+        orderly_key: "test_key",
+        orderly_secret: "11111111111111111111111111111111",
+        orderly_account_id: "test_account",
+    };
+
+    let result = client
+        .edit_algo_order(&creds, "123456", EditAlgoOrderRequest::default())
+        .await;
+    assert!(matches!(
+        result,
+        Err(orderly_connector_rs::error::OrderlyError::ValidationError(
+            _
+        ))
+    ));
+}
@@ -1,19 +1,16 @@
+mod common;
+
 use mockito::{self, Server};
 use orderly_connector_rs::{
-    rest::{client::Credentials, OrderlyService},
-    types::{OrderStatus, OrderType, Side},
+    error::{OrderlyApiCode, OrderlyError},
+    rest::{
+        client::{estimate_order_margin, round_to_lot, round_to_tick, SymbolInfo},
+        OrderlyService,
+    },
+    types::{AccountInfo, CreateOrderRequest, OrderStatus, OrderType, OrderlyTimeInForce, Side},
 };
 use serde_json::json;
 
-// Helper function to create test credentials
-fn test_credentials() -> Credentials<'static> {
-    Credentials {
-        orderly_key: "test_key",
-        orderly_secret: "11111111111111111111111111111111",
-        orderly_account_id: "test_account",
-    }
-}
-
 #[tokio::test]
 async fn test_market_order_creation() {
     let mut server = Server::new_async().await;
@@ -47,7 +44,7 @@ async fn test_market_order_creation() {
         .await;
 
     let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
-    let creds = test_credentials();
+    let creds = common::test_credentials();
 
     let result = client
         .create_order(
@@ -62,6 +59,8 @@ async fn test_market_order_creation() {
                 client_order_id: None,
                 visible_quantity: None,
                 reduce_only: None,
+                time_in_force: None,
+                expire_time: None,
             },
         )
         .await;
@@ -106,7 +105,7 @@ async fn test_limit_order_creation() {
         .await;
 
     let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
-    let creds = test_credentials();
+    let creds = common::test_credentials();
 
     let result = client
         .create_order(
@@ -121,6 +120,8 @@ async fn test_limit_order_creation() {
                 client_order_id: None,
                 visible_quantity: None,
                 reduce_only: None,
+                time_in_force: None,
+                expire_time: None,
             },
         )
         .await;
@@ -157,7 +158,7 @@ async fn test_order_cancellation() {
         .await;
 
     let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
-    let creds = test_credentials();
+    let creds = common::test_credentials();
 
     let result = client.cancel_order(&creds, 12345, "PERP_ETH_USDC").await;
 
@@ -181,6 +182,10 @@ async fn test_order_status_monitoring() {
             OrderStatus::Rejected => "REJECTED",
             OrderStatus::Expired => "EXPIRED",
             OrderStatus::Accepted => "ACCEPTED",
+            OrderStatus::Replaced => "REPLACED",
+            OrderStatus::Incomplete => "INCOMPLETE",
+            OrderStatus::Completed => "COMPLETED",
+            OrderStatus::Unknown => "UNKNOWN",
         };
 
         json!({
@@ -249,7 +254,7 @@ async fn test_order_status_monitoring() {
         .await;
 
     let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
-    let creds = test_credentials();
+    let creds = common::test_credentials();
 
     // First check - New
     let result = client.get_order(&creds, 12345).await;
@@ -307,7 +312,7 @@ async fn test_error_handling() {
         .await;
 
     let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
-    let creds = test_credentials();
+    let creds = common::test_credentials();
 
     let result = client
         .create_order(
@@ -322,6 +327,8 @@ async fn test_error_handling() {
                 client_order_id: None,
                 visible_quantity: None,
                 reduce_only: None,
+                time_in_force: None,
+                expire_time: None,
             },
         )
         .await;
@@ -334,3 +341,663 @@ async fn test_error_handling() {
         _ => panic!("Expected error"),
     }
 }
+
+#[tokio::test]
+async fn test_error_api_code() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/v1/order/12345")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"success": false, "code": -1006, "message": "Order not found"}).to_string(),
+        )
+        .create_async()
+        .await;
+    let _unknown = server
+        .mock("GET", "/v1/order/67890")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"success": false, "code": 100001, "message": "Invalid order parameters"})
+                .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+
+    let err = client.get_order(&creds, 12345).await.unwrap_err();
+    assert_eq!(err.api_code(), Some(OrderlyApiCode::ResourceNotFound));
+    assert_eq!(err.api_code().map(|c| c.code()), Some(-1006));
+
+    // Undocumented codes are kept as-is
+    let err = client.get_order(&creds, 67890).await.unwrap_err();
+    assert_eq!(err.api_code(), Some(OrderlyApiCode::Unknown(100001)));
+}
+
+#[tokio::test]
+async fn test_rate_limited_error() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/v1/order/12345")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-account-id", "test_account")
+        .match_header("orderly-timestamp", mockito::Matcher::Any)
+        .match_header("orderly-signature", mockito::Matcher::Any)
+        .with_status(429)
+        .with_header("content-type", "application/json")
+        .with_header("retry-after", "2")
+        .with_body(
+            json!({"success": false, "code": -1003, "message": "Too many requests"}).to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+
+    let result = client.get_order(&creds, 12345).await;
+
+    match result {
+        Err(OrderlyError::RateLimited { retry_after, .. }) => {
+            assert_eq!(retry_after, Some(std::time::Duration::from_secs(2)));
+        }
+        other => panic!("Expected RateLimited error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_malformed_success_body_returns_serde_error() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/v1/order/12345")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-account-id", "test_account")
+        .match_header("orderly-timestamp", mockito::Matcher::Any)
+        .match_header("orderly-signature", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body("not json")
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+
+    let result = client.get_order(&creds, 12345).await;
+
+    assert!(matches!(result, Err(OrderlyError::Serde(_))));
+}
+
+#[tokio::test]
+async fn test_batch_order_cancellation() {
+    let mut server = Server::new_async().await;
+
+    let mock_response = json!({
+        "success": true,
+        "timestamp": 1677721600123_u64,
+        "data": {
+            "rows": [
+                {"order_id": 1, "status": "CANCEL_SENT"},
+                {"order_id": 2, "status": "FAILED", "code": -1006, "message": "order not found"}
+            ]
+        }
+    });
+
+    let _m = server
+        .mock("DELETE", "/v1/batch-order")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "order_ids".into(),
+            "1,2".into(),
+        ))
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-account-id", "test_account")
+        .match_header("orderly-timestamp", mockito::Matcher::Any)
+        .match_header("orderly-signature", mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response.to_string())
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+
+    let response = client
+        .cancel_batch_orders(&creds, vec![1, 2])
+        .await
+        .unwrap();
+
+    assert_eq!(response.data.rows.len(), 2);
+    assert_eq!(response.data.rows[0].status, "CANCEL_SENT");
+    assert_eq!(response.data.rows[1].code, Some(-1006));
+}
+
+#[tokio::test]
+async fn test_batch_order_cancellation_validation() {
+    let client = OrderlyService::with_base_url("http://localhost", None).unwrap();
+    let creds = common::test_credentials();
+
+    let empty = client.cancel_batch_orders(&creds, vec![]).await;
+    assert!(matches!(empty, Err(OrderlyError::ValidationError(_))));
+
+    let too_many = client.cancel_batch_orders(&creds, (1..=11).collect()).await;
+    assert!(matches!(too_many, Err(OrderlyError::ValidationError(_))));
+}
+
+fn eth_symbol_info() -> SymbolInfo {
+    SymbolInfo {
+        symbol: "PERP_ETH_USDC".to_string(),
+        quote_min: 0.0,
+        quote_max: 100000.0,
+        quote_tick: 0.01,
+        base_min: 0.001,
+        base_max: 1000.0,
+        base_tick: 0.001,
+        min_notional: 10.0,
+        price_range: 0.02,
+        created_time: 1677721600000,
+        updated_time: 1677721600000,
+        imr_factor: None,
+        liquidation_fee: None,
+        base_imr: None,
+    }
+}
+
+fn limit_order(price: f64, quantity: f64) -> CreateOrderRequest {
+    CreateOrderRequest {
+        symbol: "PERP_ETH_USDC".to_string(),
+        order_type: OrderType::Limit,
+        side: Side::Buy,
+        order_price: Some(price),
+        order_quantity: Some(quantity),
+        order_amount: None,
+        client_order_id: None,
+        visible_quantity: None,
+        reduce_only: None,
+        time_in_force: None,
+        expire_time: None,
+    }
+}
+
+#[test]
+fn test_symbol_info_validate_order() {
+    let info = eth_symbol_info();
+
+    assert!(info.validate_order(&limit_order(2000.01, 0.015)).is_ok());
+
+    let cases = [
+        limit_order(2000.005, 0.015), // price off tick
+        limit_order(2000.0, 0.0155),  // quantity off tick
+        limit_order(2000.0, 2000.0),  // quantity above base_max
+        limit_order(2000.0, 0.001),   // notional below min_notional
+    ];
+    for order in cases {
+        assert!(matches!(
+            info.validate_order(&order),
+            Err(OrderlyError::ValidationError(_))
+        ));
+    }
+
+    let mut other_symbol = limit_order(30000.0, 0.01);
+    other_symbol.symbol = "PERP_BTC_USDC".to_string();
+    assert!(info.validate_order(&other_symbol).is_err());
+}
+
+#[test]
+fn test_round_to_tick_and_lot() {
+    // Rounds down to the increment without float noise
+    assert_eq!(round_to_tick(2000.019, 0.01), 2000.01);
+    assert_eq!(round_to_tick(1.2345, 0.001), 1.234);
+    assert_eq!(round_to_lot(0.0155, 0.001), 0.015);
+    assert_eq!(round_to_tick(123.7, 0.5), 123.5);
+
+    // Values already on the grid are kept, even when f64 division lands just below
+    assert_eq!(round_to_tick(0.07, 0.01), 0.07);
+    assert_eq!(round_to_tick(0.3, 0.1), 0.3);
+    assert_eq!(round_to_lot(2.003, 0.001), 2.003);
+
+    // A non-positive tick leaves the value unchanged
+    assert_eq!(round_to_tick(1.2345, 0.0), 1.2345);
+
+    let info = eth_symbol_info();
+    assert_eq!(info.round_price(2000.016), 2000.01);
+    assert_eq!(info.round_quantity(0.0159), 0.015);
+    assert!(info
+        .validate_order(&limit_order(
+            info.round_price(2000.016),
+            info.round_quantity(0.0159)
+        ))
+        .is_ok());
+}
+
+#[test]
+fn test_estimate_order_margin() {
+    let account: AccountInfo = serde_json::from_value(json!({
+        "account_id": "test_account",
+        "account_mode": "FUTURES",
+        "taker_fee_rate": 5.0,
+        "maker_fee_rate": 2.0,
+        "max_leverage": 10.0,
+        "futures_taker_fee_rate": 5.0,
+        "futures_maker_fee_rate": 2.0,
+        "imr_factor": {"PERP_ETH_USDC": 0.0000002},
+        "max_notional": {"PERP_ETH_USDC": 1000000}
+    }))
+    .unwrap();
+    let info = eth_symbol_info();
+
+    // Small orders are bound by the account's max leverage
+    let estimate = estimate_order_margin(&info, &account, 2000.0, -5.0);
+    assert_eq!(estimate.notional, 10000.0);
+    assert!((estimate.imr - 0.1).abs() < 1e-12);
+    assert!((estimate.required_margin - 1000.0).abs() < 1e-9);
+
+    // Large orders pay the size-dependent rate: 2e-7 * (1e8)^0.8
+    let estimate = estimate_order_margin(&info, &account, 2000.0, 50000.0);
+    let expected_imr = 0.0000002 * 1e8_f64.powf(0.8);
+    assert!(expected_imr > 0.1);
+    assert!((estimate.imr - expected_imr).abs() < 1e-12);
+
+    // A symbol's base IMR is a floor
+    let info = SymbolInfo {
+        base_imr: Some(0.2),
+        ..eth_symbol_info()
+    };
+    let estimate = estimate_order_margin(&info, &account, 2000.0, 5.0);
+    assert_eq!(estimate.imr, 0.2);
+    assert!((estimate.required_margin - 2000.0).abs() < 1e-9);
+}
+
+#[tokio::test]
+async fn test_create_order_validated_rejects_locally() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("POST", "/v1/order")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+
+    let result = client
+        .create_order_validated(&creds, limit_order(2000.005, 0.015), &eth_symbol_info())
+        .await;
+
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_order_rejects_invalid_size_fields() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/order")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+
+    let both = CreateOrderRequest::new("PERP_ETH_USDC".to_string(), OrderType::Market, Side::Buy)
+        .with_quantity(0.5)
+        .with_amount(1000.0);
+    match client.create_order(&creds, both).await {
+        Err(OrderlyError::ValidationError(msg)) => {
+            assert!(msg.contains("both order_quantity and order_amount"))
+        }
+        other => panic!("Expected ValidationError, got {:?}", other),
+    }
+
+    let amount_on_limit =
+        CreateOrderRequest::new("PERP_ETH_USDC".to_string(), OrderType::Limit, Side::Sell)
+            .with_price(2000.0)
+            .with_amount(1000.0);
+    match client.create_order(&creds, amount_on_limit).await {
+        Err(OrderlyError::ValidationError(msg)) => {
+            assert!(msg.contains("only accepted for MARKET, BID and ASK"))
+        }
+        other => panic!("Expected ValidationError, got {:?}", other),
+    }
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_create_gtd_order_sends_expiry() {
+    let mut server = Server::new_async().await;
+    let expire_time = chrono::Utc::now().timestamp_millis() as u64 + 3_600_000;
+
+    let mock = server
+        .mock("POST", "/v1/order")
+        .match_body(mockito::Matcher::PartialJson(json!({
+            "symbol": "PERP_ETH_USDC",
+            "order_type": "LIMIT",
+            "time_in_force": "GTD",
+            "expire_time": expire_time
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(created_body(777))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let order = limit_order(2000.0, 0.5).with_expire_time(expire_time);
+    let response = client
+        .create_order(&common::test_credentials(), order)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.data.order_id, 777);
+    assert_eq!(
+        serde_json::to_value(limit_order(2000.0, 0.5).with_time_in_force(OrderlyTimeInForce::Gtc))
+            .unwrap()["time_in_force"],
+        "GTC"
+    );
+    // The enum keeps its plain string form
+    assert_eq!(
+        serde_json::from_value::<OrderlyTimeInForce>(json!("GTD")).unwrap(),
+        OrderlyTimeInForce::Gtd
+    );
+}
+
+#[tokio::test]
+async fn test_create_order_rejects_expired_gtd() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/order")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let expired = chrono::Utc::now().timestamp_millis() as u64 - 1_000;
+    let order = limit_order(2000.0, 0.5).with_expire_time(expired);
+    assert!(order.validate().is_err());
+    // GTD without an expiry, and an expiry without GTD, are rejected as well
+    assert!(limit_order(2000.0, 0.5)
+        .with_time_in_force(OrderlyTimeInForce::Gtd)
+        .validate_time_in_force()
+        .is_err());
+    let mut gtc = limit_order(2000.0, 0.5).with_time_in_force(OrderlyTimeInForce::Gtc);
+    gtc.expire_time = Some(expired + 60_000_000);
+    assert!(gtc.validate_time_in_force().is_err());
+    match client
+        .create_order(&common::test_credentials(), order)
+        .await
+    {
+        Err(OrderlyError::ValidationError(msg)) => assert!(msg.contains("not in the future")),
+        other => panic!("Expected ValidationError, got {:?}", other),
+    }
+
+    mock.assert_async().await;
+}
+
+fn open_limit_order_body() -> String {
+    json!({
+        "success": true,
+        "timestamp": 1677721600123_u64,
+        "data": {
+            "order_id": 12345,
+            "client_order_id": "mm-1",
+            "symbol": "PERP_ETH_USDC",
+            "side": "BUY",
+            "type": "LIMIT",
+            "order_price": 1990.0,
+            "order_quantity": 1.0,
+            "order_amount": null,
+            "status": "PARTIAL_FILLED",
+            "executed_quantity": 0.25,
+            "reduce_only": true,
+            "created_time": 1677721600000_u64,
+            "updated_time": 1677721600000_u64
+        }
+    })
+    .to_string()
+}
+
+fn cancel_body() -> String {
+    common::success_body(json!({"status": "CANCEL_SENT"}))
+}
+
+fn created_body(order_id: u64) -> String {
+    common::success_body(json!({"order_id": order_id, "client_order_id": "mm-1"}))
+}
+
+#[tokio::test]
+async fn test_replace_order() {
+    let mut server = Server::new_async().await;
+
+    let _get = server
+        .mock("GET", "/v1/order/12345")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(open_limit_order_body())
+        .create_async()
+        .await;
+    let cancel = server
+        .mock("DELETE", "/v1/order")
+        .match_query(mockito::Matcher::UrlEncoded(
+            "order_id".into(),
+            "12345".into(),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(cancel_body())
+        .create_async()
+        .await;
+    let create = server
+        .mock("POST", "/v1/order")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({"order_price": 1995.0}),
+        ))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(created_body(12346))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let outcome = client
+        .replace_order(
+            &common::test_credentials(),
+            12345,
+            "PERP_ETH_USDC",
+            limit_order(1995.0, 0.75),
+        )
+        .await
+        .unwrap();
+
+    cancel.assert_async().await;
+    create.assert_async().await;
+    assert_eq!(outcome.cancel.data.status, "CANCEL_SENT");
+    assert_eq!(outcome.create.unwrap().data.order_id, 12346);
+    assert!(outcome.rollback.is_none());
+}
+
+#[tokio::test]
+async fn test_replace_order_restores_old_order_when_create_fails() {
+    let mut server = Server::new_async().await;
+
+    let _get = server
+        .mock("GET", "/v1/order/12345")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(open_limit_order_body())
+        .create_async()
+        .await;
+    let _cancel = server
+        .mock("DELETE", "/v1/order")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(cancel_body())
+        .create_async()
+        .await;
+    let _rejected = server
+        .mock("POST", "/v1/order")
+        .match_body(mockito::Matcher::PartialJson(
+            json!({"order_price": 1995.0}),
+        ))
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"success": false, "code": -1102, "message": "Price out of range"}).to_string(),
+        )
+        .create_async()
+        .await;
+    // Only the unfilled 0.75 of the old order is restored, at its original price, still
+    // reduce-only and without the client_order_id the pending cancel still holds
+    let restore = server
+        .mock("POST", "/v1/order")
+        .match_body(mockito::Matcher::Json(json!({
+            "symbol": "PERP_ETH_USDC",
+            "order_type": "LIMIT",
+            "side": "BUY",
+            "order_price": 1990.0,
+            "order_quantity": 0.75,
+            "reduce_only": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(created_body(12347))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let outcome = client
+        .replace_order(
+            &common::test_credentials(),
+            12345,
+            "PERP_ETH_USDC",
+            limit_order(1995.0, 0.75),
+        )
+        .await
+        .unwrap();
+
+    restore.assert_async().await;
+    assert!(outcome.create.is_err());
+    assert_eq!(
+        outcome
+            .rollback
+            .expect("expected a rollback")
+            .unwrap()
+            .data
+            .order_id,
+        12347
+    );
+}
+
+#[tokio::test]
+async fn test_replace_order_reports_unknown_outcome_on_server_error() {
+    let mut server = Server::new_async().await;
+
+    let _get = server
+        .mock("GET", "/v1/order/12345")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(open_limit_order_body())
+        .create_async()
+        .await;
+    let _cancel = server
+        .mock("DELETE", "/v1/order")
+        .match_query(mockito::Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(cancel_body())
+        .create_async()
+        .await;
+    // The replacement may have been placed before the gateway failed, so nothing is restored
+    let create = server
+        .mock("POST", "/v1/order")
+        .with_status(502)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"success": false, "code": -1000, "message": "Bad gateway"}).to_string())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let mut order = limit_order(1995.0, 0.75);
+    order.client_order_id = Some("mm-2".to_string());
+    let result = client
+        .replace_order(&common::test_credentials(), 12345, "PERP_ETH_USDC", order)
+        .await;
+
+    create.assert_async().await;
+    match result {
+        Err(OrderlyError::ReplaceOutcomeUnknown {
+            old_order_id,
+            client_order_id,
+            source,
+        }) => {
+            assert_eq!(old_order_id, 12345);
+            assert_eq!(client_order_id.as_deref(), Some("mm-2"));
+            assert!(matches!(*source, OrderlyError::ServerError { .. }));
+        }
+        other => panic!("Expected ReplaceOutcomeUnknown, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_replace_order_rejects_reused_client_order_id() {
+    let mut server = Server::new_async().await;
+
+    let _get = server
+        .mock("GET", "/v1/order/12345")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(open_limit_order_body())
+        .create_async()
+        .await;
+    let cancel = server
+        .mock("DELETE", "/v1/order")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let mut order = limit_order(1995.0, 0.75);
+    order.client_order_id = Some("mm-1".to_string());
+    let result = client
+        .replace_order(&common::test_credentials(), 12345, "PERP_ETH_USDC", order)
+        .await;
+
+    cancel.assert_async().await;
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_replace_order_rejects_symbol_mismatch_before_cancelling() {
+    let mut server = Server::new_async().await;
+
+    let cancel = server
+        .mock("DELETE", "/v1/order")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let result = client
+        .replace_order(
+            &common::test_credentials(),
+            12345,
+            "PERP_BTC_USDC",
+            limit_order(1995.0, 0.75),
+        )
+        .await;
+
+    cancel.assert_async().await;
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "blocking")]
+
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{error::OrderlyError, rest::BlockingOrderlyService};
+use serde_json::json;
+
+#[test]
+fn test_blocking_get_positions() {
+    let mut server = Server::new();
+
+    let mock = server
+        .mock("GET", "/v1/positions")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({"rows": []})))
+        .create();
+
+    let service = BlockingOrderlyService::with_base_url(&server.url(), None).unwrap();
+    let response = service.get_positions(&common::test_credentials()).unwrap();
+
+    mock.assert();
+    assert!(response.success);
+    assert!(response.data.rows.is_empty());
+}
+
+#[test]
+fn test_blocking_error_is_returned() {
+    let mut server = Server::new();
+
+    let mock = server
+        .mock("GET", "/v1/order/12345")
+        .with_status(400)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({"success": false, "code": -1006, "message": "Order not found"}).to_string(),
+        )
+        .create();
+
+    let service = BlockingOrderlyService::with_base_url(&server.url(), None).unwrap();
+    let result = service.get_order(&common::test_credentials(), 12345);
+
+    mock.assert();
+    assert!(matches!(
+        result,
+        Err(OrderlyError::ClientError { code: -1006, .. })
+    ));
+}
@@ -0,0 +1,54 @@
+use orderly_connector_rs::types::{AssetHistoryEntry, ChainId, WithdrawRequest};
+use serde_json::json;
+
+#[test]
+fn test_chain_id_deserializes_from_number_and_string() {
+    let from_number: ChainId = serde_json::from_value(json!(900900900)).unwrap();
+    let from_string: ChainId = serde_json::from_value(json!("900900900")).unwrap();
+    assert_eq!(from_number, ChainId::SOLANA_MAINNET);
+    assert_eq!(from_string, ChainId::SOLANA_MAINNET);
+    assert!(from_number.is_solana());
+
+    assert!(serde_json::from_value::<ChainId>(json!("arbitrum")).is_err());
+}
+
+#[test]
+fn test_chain_id_conversions() {
+    let chain_id = ChainId::from(42161);
+    assert_eq!(chain_id, ChainId::ARBITRUM);
+    assert_eq!(u64::from(chain_id), 42161);
+    assert_eq!(chain_id.to_string(), "42161");
+    assert_eq!("42161".parse::<ChainId>().unwrap(), ChainId::ARBITRUM);
+    assert_eq!(serde_json::to_value(chain_id).unwrap(), json!(42161));
+}
+
+#[test]
+fn test_asset_history_entry_chain_id() {
+    let entry: AssetHistoryEntry = serde_json::from_value(json!({
+        "id": "abc",
+        "token": "USDC",
+        "side": "DEPOSIT",
+        "amount": 100.0,
+        "fee": null,
+        "transaction_hash": null,
+        "chain_id": "421614",
+        "chain_name": "Arbitrum Sepolia",
+        "created_time": 1677721600000_u64,
+        "updated_time": 1677721600000_u64
+    }))
+    .unwrap();
+    assert_eq!(entry.chain_id, Some(ChainId::ARBITRUM_SEPOLIA));
+}
+
+#[test]
+fn test_withdraw_request_keeps_string_chain_id() {
+    let request = WithdrawRequest {
+        chain_id: ChainId::ARBITRUM,
+        token: "USDC",
+        amount: 10.0,
+        withdraw_address: "0xabc",
+        message: None,
+    };
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["chain_id"], json!("42161"));
+}
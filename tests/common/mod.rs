@@ -1,10 +1,13 @@
-// tests/common.rs
+// tests/common/mod.rs
+use orderly_connector_rs::rest::client::Credentials;
+use serde_json::{json, Value};
 use std::sync::Once;
 
 static INIT: Once = Once::new();
 
 // Initializes environment variables from .env for testing.
 // Uses std::sync::Once to ensure it only runs once across all tests.
+#[allow(dead_code)]
 pub fn setup() {
     INIT.call_once(|| {
         // Explicitly try loading .env from the current directory
@@ -29,9 +32,31 @@ pub fn get_env_var(name: &str) -> String {
     std::env::var(name).unwrap_or_else(|_| panic!("{} environment variable not set", name))
 }
 
+#[allow(dead_code)]
 pub fn get_testnet_flag() -> bool {
     std::env::var("ORDERLY_TESTNET")
         .unwrap_or_else(|_| "true".to_string()) // Default to testnet if not set
         .parse::<bool>()
         .expect("ORDERLY_TESTNET must be true or false")
 }
+
+// Credentials accepted by the mock servers; the secret is a valid base58 key.
+#[allow(dead_code)]
+pub fn test_credentials() -> Credentials<'static> {
+    Credentials {
+        orderly_key: "test_key",
+        orderly_secret: "11111111111111111111111111111111",
+        orderly_account_id: "test_account",
+    }
+}
+
+// Wraps `data` in the standard `{"success": true, "timestamp", "data"}` envelope.
+#[allow(dead_code)]
+pub fn success_body(data: Value) -> String {
+    json!({
+        "success": true,
+        "timestamp": 1677721600123_u64,
+        "data": data
+    })
+    .to_string()
+}
@@ -0,0 +1,94 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{error::OrderlyError, rest::OrderlyService};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_get_convert_quote_and_execute() {
+    let mut server = Server::new_async().await;
+
+    let quote_mock = server
+        .mock("GET", "/v1/convert/quote")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("from_token".into(), "USDC".into()),
+            Matcher::UrlEncoded("to_token".into(), "ETH".into()),
+            Matcher::UrlEncoded("amount".into(), "1000".into()),
+        ]))
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-account-id", "test_account")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "quote_id": "q-123",
+            "from_token": "USDC",
+            "to_token": "ETH",
+            "from_amount": 1000.0,
+            "to_amount": 0.5,
+            "rate": 0.0005,
+            "expire_time": 1677721610000_u64
+        })))
+        .create_async()
+        .await;
+
+    let execute_mock = server
+        .mock("POST", "/v1/convert")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .match_body(Matcher::Json(json!({"quote_id": "q-123"})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "timestamp": 1677721601123_u64,
+                "data": {
+                    "convert_id": 7,
+                    "quote_id": "q-123",
+                    "from_token": "USDC",
+                    "to_token": "ETH",
+                    "from_amount": 1000.0,
+                    "to_amount": 0.5,
+                    "status": "COMPLETED"
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+
+    let quote = client
+        .get_convert_quote(&creds, "USDC", "ETH", 1000.0)
+        .await
+        .unwrap()
+        .data;
+    assert_eq!(quote.rate, 0.0005);
+    assert!(!quote.is_expired_at(1677721600123));
+    assert!(quote.is_expired_at(1677721610000));
+
+    let result = client
+        .execute_convert(&creds, &quote.quote_id)
+        .await
+        .unwrap();
+    assert_eq!(result.data.convert_id, 7);
+    assert_eq!(result.data.to_amount, 0.5);
+
+    quote_mock.assert_async().await;
+    execute_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_convert_quote_validation() {
+    let client = OrderlyService::with_base_url("http://localhost", None).unwrap();
+    let creds = common::test_credentials();
+
+    let result = client.get_convert_quote(&creds, "USDC", "ETH", 0.0).await;
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+
+    let result = client.get_convert_quote(&creds, "USDC", "usdc", 10.0).await;
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+}
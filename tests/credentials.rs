@@ -0,0 +1,28 @@
+use orderly_connector_rs::{error::OrderlyError, rest::client::Credentials};
+
+// A single test, as the environment is shared by every test in this binary
+#[test]
+fn test_credentials_from_env() {
+    std::env::set_var("ORDERLY_API_KEY", "ed25519:test_key");
+    std::env::set_var("ORDERLY_SECRET", "test_secret");
+    std::env::set_var("ORDERLY_ACCOUNT_ID", "test_account");
+
+    let owned = Credentials::from_env().unwrap();
+    let creds = owned.as_credentials();
+    assert_eq!(creds.orderly_key, "ed25519:test_key");
+    assert_eq!(creds.orderly_secret, "test_secret");
+    assert_eq!(creds.orderly_account_id, "test_account");
+
+    std::env::set_var("ORDERLY_SECRET", "  ");
+    match Credentials::from_env() {
+        Err(OrderlyError::ValidationError(msg)) => assert!(msg.contains("ORDERLY_SECRET")),
+        other => panic!("Expected ValidationError, got {:?}", other),
+    }
+
+    std::env::remove_var("ORDERLY_ACCOUNT_ID");
+    std::env::set_var("ORDERLY_SECRET", "test_secret");
+    match Credentials::from_env() {
+        Err(OrderlyError::ValidationError(msg)) => assert!(msg.contains("ORDERLY_ACCOUNT_ID")),
+        other => panic!("Expected ValidationError, got {:?}", other),
+    }
+}
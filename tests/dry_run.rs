@@ -0,0 +1,93 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{
+    error::OrderlyError,
+    rest::{OrderlyService, OrderlyServiceOptions},
+    types::{CreateOrderRequest, EditAlgoOrderRequest, OrderType, Side},
+};
+use serde_json::json;
+
+fn dry_run_service(url: String) -> OrderlyService {
+    OrderlyService::with_options(OrderlyServiceOptions {
+        base_url: Some(url),
+        dry_run: true,
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_dry_run_suppresses_mutating_requests() {
+    let mut server = Server::new_async().await;
+    let mutating = server
+        .mock("POST", Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+    let deleting = server
+        .mock("DELETE", Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = dry_run_service(server.url());
+    let creds = common::test_credentials();
+    assert!(client.is_dry_run());
+
+    let order = CreateOrderRequest {
+        symbol: "PERP_ETH_USDC".to_string(),
+        order_type: OrderType::Limit,
+        side: Side::Buy,
+        order_price: Some(2000.0),
+        order_quantity: Some(0.01),
+        order_amount: None,
+        client_order_id: Some("dry_1".to_string()),
+        visible_quantity: None,
+        reduce_only: None,
+        time_in_force: None,
+        expire_time: None,
+    };
+    let created = client.create_order(&creds, order).await.unwrap();
+    assert!(created.success);
+    assert!(created.data.order_id > 0);
+    assert_eq!(created.data.client_order_id.as_deref(), Some("dry_1"));
+
+    let cancelled = client
+        .cancel_order(&creds, created.data.order_id, "PERP_ETH_USDC")
+        .await
+        .unwrap();
+    assert_eq!(cancelled.data.status, "CANCEL_SENT");
+
+    // Mutating methods without a synthetic response are refused rather than sent
+    let edit = EditAlgoOrderRequest {
+        trigger_price: Some(2100.0),
+        ..Default::default()
+    };
+    let result = client.edit_algo_order(&creds, "123", edit).await;
+    assert!(matches!(result, Err(OrderlyError::DryRun(_))));
+
+    mutating.assert_async().await;
+    deleting.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_dry_run_still_sends_reads() {
+    let mut server = Server::new_async().await;
+    let read = server
+        .mock("GET", "/v1/client/holding")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({"holding": []})))
+        .create_async()
+        .await;
+
+    let client = dry_run_service(server.url());
+    client
+        .get_holding(&common::test_credentials())
+        .await
+        .unwrap();
+
+    read.assert_async().await;
+}
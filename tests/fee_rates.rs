@@ -0,0 +1,118 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{rest::OrderlyService, types::GetFeeRatesResponse};
+use serde_json::json;
+use std::time::Duration;
+
+fn fee_rates_body() -> String {
+    common::success_body(json!({
+        "fee_rates": [
+            {
+                "symbol": "PERP_ETH_USDC",
+                "maker_fee_rate": 0.0002,
+                "taker_fee_rate": 0.0005,
+                "rebate_rate": null,
+                "source": "DEFAULT",
+                "updated_time": 1677721600000_u64
+            }
+        ],
+        "taker_fee_rate_30_d": 0.0005,
+        "maker_fee_rate_30_d": 0.0002,
+        "volume_30_d": 1000000.0,
+        "vip_level": 1
+    }))
+}
+
+#[tokio::test]
+async fn test_get_fee_rates_cached_reuses_response_within_ttl() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/client/fee_rates")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-account-id", "test_account")
+        .match_header("orderly-timestamp", Matcher::Any)
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fee_rates_body())
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+    let ttl = Duration::from_secs(60);
+
+    let first = client.get_fee_rates_cached(&creds, ttl).await.unwrap();
+    // Clones share the cache
+    let second = client
+        .clone()
+        .get_fee_rates_cached(&creds, ttl)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(first.data.fee_rates[0].taker_fee_rate, 0.0005);
+    assert_eq!(second.data.fee_rates[0].taker_fee_rate, 0.0005);
+}
+
+#[tokio::test]
+async fn test_get_fee_rates_cached_refetches_after_ttl() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/client/fee_rates")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(fee_rates_body())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+
+    client
+        .get_fee_rates_cached(&creds, Duration::ZERO)
+        .await
+        .unwrap();
+    client
+        .get_fee_rates_cached(&creds, Duration::ZERO)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[test]
+fn test_fee_rate_lookup_falls_back_to_account_rates() {
+    let response: GetFeeRatesResponse = serde_json::from_str(&fee_rates_body()).unwrap();
+    let data = response.data;
+
+    assert_eq!(
+        data.for_symbol("PERP_ETH_USDC").unwrap().maker_fee_rate,
+        0.0002
+    );
+    assert!(data.for_symbol("PERP_BTC_USDC").is_none());
+
+    // No per-symbol entry, so the account-wide 30 day rates apply
+    let btc = data.effective_rate("PERP_BTC_USDC").unwrap();
+    assert_eq!(btc, data.default_rate().unwrap());
+    assert_eq!(btc.taker_fee_rate, 0.0005);
+
+    let mut without_defaults = data.clone();
+    without_defaults.maker_fee_rate_30_d = None;
+    assert!(without_defaults.default_rate().is_none());
+    assert!(without_defaults.effective_rate("PERP_BTC_USDC").is_none());
+    assert_eq!(
+        without_defaults
+            .effective_rate("PERP_ETH_USDC")
+            .unwrap()
+            .taker_fee_rate,
+        0.0005
+    );
+}
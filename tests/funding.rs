@@ -0,0 +1,66 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{
+    error::OrderlyError, rest::OrderlyService, types::GetSymbolFundingHistoryParams,
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_get_symbol_funding_history_is_time_ordered() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/public/funding_rate_history")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("symbol".into(), "PERP_ETH_USDC".into()),
+            Matcher::UrlEncoded("start_t".into(), "1677700000000".into()),
+            Matcher::UrlEncoded("page".into(), "2".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "rows": [
+                {
+                    "symbol": "PERP_ETH_USDC",
+                    "funding_rate": 0.0002,
+                    "funding_rate_timestamp": 1677736800000_u64,
+                    "next_funding_time": 1677765600000_u64
+                },
+                {
+                    "symbol": "PERP_ETH_USDC",
+                    "funding_rate": -0.0001,
+                    "funding_rate_timestamp": 1677708000000_u64,
+                    "next_funding_time": 1677736800000_u64
+                }
+            ],
+            "meta": {"total": 27, "current_page": 2, "records_per_page": 25}
+        })))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let params = GetSymbolFundingHistoryParams {
+        start_t: Some(1677700000000),
+        page: Some(2),
+        ..Default::default()
+    };
+    let response = client
+        .get_symbol_funding_history("PERP_ETH_USDC", Some(params))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    let rows = &response.data.rows;
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].funding_rate, -0.0001);
+    assert!(rows[0].funding_rate_timestamp < rows[1].funding_rate_timestamp);
+    assert!(!response.data.meta.unwrap().has_more());
+}
+
+#[tokio::test]
+async fn test_get_symbol_funding_history_requires_symbol() {
+    let client = OrderlyService::with_base_url("http://localhost", None).unwrap();
+    let result = client.get_symbol_funding_history(" ", None).await;
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+}
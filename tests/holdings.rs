@@ -0,0 +1,73 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{
+    rest::OrderlyService,
+    types::{value_holdings, GetHoldingResponseData},
+};
+use serde_json::json;
+
+fn holding_rows() -> serde_json::Value {
+    json!([
+        {"token": "USDC", "holding": 1500.0, "frozen": 100.0, "pending_short_qty": 0.0, "updated_time": 1677721600000_u64},
+        {"token": "ETH", "holding": 2.0, "frozen": 0.0, "pending_short_qty": 0.0, "updated_time": 1677721600000_u64},
+        {"token": "DOGE", "holding": 1000.0, "frozen": 0.0, "pending_short_qty": 0.0, "updated_time": 1677721600000_u64}
+    ])
+}
+
+#[test]
+fn test_value_holdings_with_injected_prices() {
+    let data: GetHoldingResponseData =
+        serde_json::from_value(json!({ "holding": holding_rows() })).unwrap();
+
+    let valuation = value_holdings(data.holding, |token| match token {
+        "USDC" => Some(1.0),
+        "ETH" => Some(2500.0),
+        _ => None,
+    });
+
+    assert_eq!(valuation.holdings[0].usd_value, Some(1500.0));
+    assert_eq!(valuation.holdings[1].usd_price, Some(2500.0));
+    assert_eq!(valuation.holdings[1].usd_value, Some(5000.0));
+    assert_eq!(valuation.holdings[2].usd_value, None);
+    assert_eq!(valuation.total_usd_value, 6500.0);
+    assert_eq!(valuation.unpriced_tokens(), vec!["DOGE"]);
+}
+
+#[tokio::test]
+async fn test_get_holdings_with_valuation() {
+    let mut server = Server::new_async().await;
+
+    let holding = server
+        .mock("GET", "/v1/client/holding")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({"holding": holding_rows()})))
+        .create_async()
+        .await;
+    let futures = server
+        .mock("GET", "/v1/public/futures")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({"rows": [
+            {"symbol": "PERP_ETH_USDC", "index_price": 2400.0, "mark_price": 2401.5},
+            {"symbol": "PERP_BTC_USDC", "index_price": 60000.0, "mark_price": 60010.0}
+        ]})))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let valuation = client
+        .get_holdings_with_valuation(&common::test_credentials())
+        .await
+        .unwrap();
+
+    holding.assert_async().await;
+    futures.assert_async().await;
+    assert_eq!(valuation.holdings[0].usd_price, Some(1.0));
+    assert_eq!(valuation.holdings[1].usd_value, Some(4800.0));
+    assert_eq!(valuation.total_usd_value, 6300.0);
+    assert_eq!(valuation.unpriced_tokens(), vec!["DOGE"]);
+}
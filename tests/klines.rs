@@ -0,0 +1,72 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{rest::OrderlyService, types::KlineInterval};
+use serde_json::json;
+
+#[test]
+fn test_kline_interval_round_trips_through_string_form() {
+    for interval in KlineInterval::ALL {
+        let token = interval.as_str();
+        assert_eq!(interval.to_string(), token);
+        assert_eq!(token.parse::<KlineInterval>().unwrap(), interval);
+        assert_eq!(serde_json::to_value(interval).unwrap(), json!(token));
+        assert_eq!(
+            serde_json::from_value::<KlineInterval>(json!(token)).unwrap(),
+            interval
+        );
+    }
+
+    assert_eq!(KlineInterval::OneMonth.as_str(), "1mon");
+    assert!("2m".parse::<KlineInterval>().is_err());
+}
+
+#[tokio::test]
+async fn test_get_kline() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/kline")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("symbol".into(), "PERP_ETH_USDC".into()),
+            Matcher::UrlEncoded("type".into(), "1h".into()),
+            Matcher::UrlEncoded("limit".into(), "2".into()),
+        ]))
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "rows": [{
+                "symbol": "PERP_ETH_USDC",
+                "type": "1h",
+                "open": 2000.0,
+                "close": 2010.5,
+                "high": 2020.0,
+                "low": 1995.0,
+                "volume": 12.5,
+                "amount": 25100.0,
+                "start_timestamp": 1677718000000_u64,
+                "end_timestamp": 1677721600000_u64
+            }]
+        })))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let response = client
+        .get_kline(
+            &common::test_credentials(),
+            "PERP_ETH_USDC",
+            KlineInterval::OneHour,
+            Some(2),
+        )
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    let kline = &response.data.rows[0];
+    assert_eq!(kline.interval, KlineInterval::OneHour);
+    assert_eq!(kline.close, 2010.5);
+    assert_eq!(kline.end_timestamp, 1677721600000);
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{rest::OrderlyService, types::MarginMode};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_get_symbol_leverage() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/v1/client/leverage")
+        .match_query(Matcher::UrlEncoded("symbol".into(), "PERP_ETH_USDC".into()))
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(
+            json!({"symbol": "PERP_ETH_USDC", "leverage": 10.0, "margin_mode": "ISOLATED"}),
+        ))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let response = client
+        .get_symbol_leverage(&common::test_credentials(), "PERP_ETH_USDC")
+        .await
+        .unwrap();
+
+    assert_eq!(response.data.leverage, 10.0);
+    assert_eq!(response.data.margin_mode, MarginMode::Isolated);
+}
+
+#[tokio::test]
+async fn test_set_symbol_margin_mode() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/client/margin_mode")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .match_body(Matcher::Json(json!({
+            "symbol": "PERP_BTC_USDC",
+            "margin_mode": "CROSS"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(
+            json!({"symbol": "PERP_BTC_USDC", "leverage": 5.0, "margin_mode": "CROSS"}),
+        ))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let response = client
+        .set_symbol_margin_mode(
+            &common::test_credentials(),
+            "PERP_BTC_USDC",
+            MarginMode::Cross,
+        )
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.data.margin_mode, MarginMode::Cross);
+    assert_eq!(response.data.leverage, 5.0);
+}
@@ -0,0 +1,32 @@
+mod common;
+
+use mockito::Server;
+use orderly_connector_rs::rest::OrderlyService;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_get_maintenance_info() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/v1/public/maintenance_config")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "start_time": 1677800000000_u64,
+            "end_time": 1677803600000_u64,
+            "components": ["trading", "withdrawal"],
+            "status": "scheduled"
+        })))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let info = client.get_maintenance_info().await.unwrap().data;
+
+    assert_eq!(info.status, "scheduled");
+    assert_eq!(info.components, vec!["trading", "withdrawal"]);
+    assert!(!info.is_active_at(1677721600123));
+    assert!(info.is_active_at(1677800000000));
+    assert!(!info.is_active_at(1677803600000));
+}
@@ -0,0 +1,302 @@
+mod common;
+
+use orderly_connector_rs::{
+    auth::{generate_signature, get_timestamp_ms},
+    error::OrderlyError,
+    rest::{
+        client::SignedRequestParts, transport::TransportFuture, MockTransport, OrderlyService,
+        OrderlyServiceOptions, RetryPolicy, Transport,
+    },
+    types::{CreateOrderRequest, EditAlgoOrderRequest, OrderType, Side},
+};
+use reqwest::{Method, Request};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+
+fn mock_service() -> (OrderlyService, Arc<MockTransport>) {
+    let transport = Arc::new(MockTransport::new());
+    let service = OrderlyService::with_base_url("https://api.example.com", None)
+        .unwrap()
+        .with_transport(transport.clone());
+    (service, transport)
+}
+
+#[tokio::test]
+async fn test_create_order_is_signed_over_mock_transport() {
+    let (service, transport) = mock_service();
+    transport.push_json(json!({
+        "success": true,
+        "timestamp": 1677721600123_u64,
+        "data": {"order_id": 12345, "client_order_id": null}
+    }));
+
+    let order = CreateOrderRequest::new("PERP_ETH_USDC".to_string(), OrderType::Market, Side::Buy)
+        .with_quantity(0.01);
+    let response = service
+        .create_order(&common::test_credentials(), order)
+        .await
+        .unwrap();
+    assert_eq!(response.data.order_id, 12345);
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    let sent = &requests[0];
+    assert_eq!(sent.method, Method::POST);
+    assert_eq!(sent.url.path(), "/v1/order");
+    assert_eq!(sent.headers["orderly-key"], "test_key");
+    assert_eq!(sent.headers["orderly-account-id"], "test_account");
+
+    // The signature covers timestamp + method + path + body
+    let body = sent.body.as_deref().expect("body should be set");
+    let timestamp = sent.headers["orderly-timestamp"].to_str().unwrap();
+    let expected = generate_signature(
+        "11111111111111111111111111111111",
+        &format!("{}POST/v1/order{}", timestamp, body),
+    )
+    .unwrap();
+    assert_eq!(sent.headers["orderly-signature"], expected.as_str());
+}
+
+#[tokio::test]
+async fn test_error_response_over_mock_transport() {
+    let (service, transport) = mock_service();
+    transport.push_response(
+        400,
+        json!({"success": false, "code": -1102, "message": "Invalid order quantity"}).to_string(),
+    );
+
+    let err = service
+        .get_order(&common::test_credentials(), 12345)
+        .await
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("Client Error: GET /v1/order/12345: "));
+    match err {
+        OrderlyError::ClientError {
+            method,
+            path,
+            code,
+            message,
+            ..
+        } => {
+            assert_eq!(code, -1102);
+            assert_eq!(message, "Invalid order quantity");
+            assert_eq!(method, Method::GET);
+            assert_eq!(path, "/v1/order/12345");
+        }
+        other => panic!("Expected ClientError, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_empty_mock_queue_returns_error() {
+    let (service, transport) = mock_service();
+
+    let err = service.get_system_status().await.unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("no mock response queued for GET /v1/public/system_info"),
+        "unexpected error: {}",
+        err
+    );
+    assert_eq!(transport.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn test_invalid_signature_resyncs_clock_and_retries_once() {
+    let (service, transport) = mock_service();
+    transport.push_response(
+        401,
+        json!({"success": false, "code": -1001, "message": "Signature invalid"}).to_string(),
+    );
+    let server_time = get_timestamp_ms().unwrap() + 60_000;
+    transport.push_json(json!({
+        "success": true,
+        "timestamp": server_time,
+        "data": {"status": 0, "msg": "ok"}
+    }));
+    transport.push_json(json!({
+        "success": true,
+        "timestamp": server_time,
+        "data": {"order_id": 12345, "client_order_id": null}
+    }));
+
+    let order = CreateOrderRequest::new("PERP_ETH_USDC".to_string(), OrderType::Market, Side::Buy)
+        .with_quantity(0.01);
+    let response = service
+        .create_order(&common::test_credentials(), order)
+        .await
+        .unwrap();
+    assert_eq!(response.data.order_id, 12345);
+
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 3);
+    assert_eq!(requests[1].url.path(), "/v1/public/system_info");
+    let (first, retry) = (&requests[0], &requests[2]);
+    assert_eq!(retry.url.path(), "/v1/order");
+    assert_eq!(retry.body, first.body);
+
+    // The retry is re-signed with the server-adjusted timestamp
+    let timestamp = |parts: &SignedRequestParts| {
+        parts.headers["orderly-timestamp"]
+            .to_str()
+            .unwrap()
+            .parse::<u64>()
+            .unwrap()
+    };
+    assert!(timestamp(retry) >= timestamp(first) + 59_000);
+    assert_ne!(
+        retry.headers["orderly-signature"],
+        first.headers["orderly-signature"]
+    );
+    assert!((service.clock_offset_ms() - 60_000).abs() < 1_000);
+}
+
+#[tokio::test]
+async fn test_other_auth_errors_are_not_retried() {
+    let (service, transport) = mock_service();
+    transport.push_response(
+        401,
+        json!({"success": false, "code": -1002, "message": "Unauthorized"}).to_string(),
+    );
+
+    let err = service
+        .get_order(&common::test_credentials(), 12345)
+        .await
+        .unwrap_err();
+    assert_eq!(err.api_code().map(|c| c.code()), Some(-1002));
+    assert_eq!(transport.requests().len(), 1);
+    assert_eq!(service.clock_offset_ms(), 0);
+}
+
+#[tokio::test]
+async fn test_edit_algo_order_sends_wire_field_names() {
+    let (service, transport) = mock_service();
+    transport.push_json(json!({
+        "success": true,
+        "timestamp": 1677721600123_u64,
+        "data": {
+            "algo_order_id": "123456",
+            "client_order_id": null,
+            "symbol": "PERP_BTC_USDC",
+            "order_type": "STOP_LIMIT",
+            "side": "SELL",
+            "quantity": 0.1,
+            "trigger_price": 51000.0,
+            "status": "NEW",
+            "reduce_only": true,
+            "created_time": 1677721600000_i64,
+            "updated_time": 1677721700000_i64
+        }
+    }));
+
+    let request = EditAlgoOrderRequest {
+        trigger_price: Some(51000.0),
+        limit_price: Some(50900.0),
+        ..Default::default()
+    };
+    service
+        .edit_algo_order(&common::test_credentials(), "123456", request)
+        .await
+        .unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(requests[0].method, Method::PUT);
+    let body: serde_json::Value =
+        serde_json::from_str(requests[0].body.as_deref().unwrap()).unwrap();
+    assert_eq!(
+        body,
+        json!({"order_id": 123456, "trigger_price": 51000.0, "price": 50900.0})
+    );
+}
+
+#[tokio::test]
+async fn test_edit_algo_order_rejects_non_numeric_id() {
+    let (service, transport) = mock_service();
+    let request = EditAlgoOrderRequest {
+        trigger_price: Some(51000.0),
+        ..Default::default()
+    };
+    let result = service
+        .edit_algo_order(&common::test_credentials(), "abc", request)
+        .await;
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+    assert!(transport.requests().is_empty());
+}
+
+/// Fails the first request with a real connection error, then defers to a `MockTransport`.
+struct FailFirstTransport {
+    inner: MockTransport,
+    first: std::sync::Mutex<Option<SignedRequestParts>>,
+}
+
+impl Transport for FailFirstTransport {
+    fn execute(&self, request: Request) -> TransportFuture<'_> {
+        let mut first = self.first.lock().unwrap();
+        if first.is_some() {
+            return self.inner.execute(request);
+        }
+        *first = Some(SignedRequestParts {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            headers: request.headers().clone(),
+            body: None,
+        });
+        // Nothing listens on port 1, so this fails with a connection error
+        Box::pin(async {
+            reqwest::Client::new()
+                .get("http://127.0.0.1:1")
+                .send()
+                .await
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_retried_get_is_signed_with_fresh_timestamp() {
+    let transport = Arc::new(FailFirstTransport {
+        inner: MockTransport::new(),
+        first: std::sync::Mutex::new(None),
+    });
+    transport.inner.push_json(json!({
+        "success": true,
+        "timestamp": 1677721600123_u64,
+        "data": {"holding": []}
+    }));
+    let service = OrderlyService::with_options(OrderlyServiceOptions {
+        base_url: Some("https://api.example.com".to_string()),
+        retry_policy: Some(RetryPolicy {
+            max_retries: 1,
+            initial_backoff: Duration::from_millis(20),
+        }),
+        ..Default::default()
+    })
+    .unwrap()
+    .with_transport(transport.clone());
+
+    service
+        .get_holding(&common::test_credentials())
+        .await
+        .unwrap();
+
+    let first = transport.first.lock().unwrap().clone().unwrap();
+    let requests = transport.inner.requests();
+    assert_eq!(requests.len(), 1);
+    let retry = &requests[0];
+    assert_eq!(retry.url.path(), "/v1/client/holding");
+
+    let timestamp = |parts: &SignedRequestParts| {
+        parts.headers["orderly-timestamp"]
+            .to_str()
+            .unwrap()
+            .parse::<u64>()
+            .unwrap()
+    };
+    assert!(timestamp(retry) >= timestamp(&first) + 20);
+    assert_ne!(
+        retry.headers["orderly-signature"],
+        first.headers["orderly-signature"]
+    );
+}
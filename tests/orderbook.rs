@@ -0,0 +1,116 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{
+    error::OrderlyError, rest::OrderlyService, types::OrderbookSnapshotData,
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_get_orderbook_with_aggregation() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/orderbook/PERP_ETH_USDC")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("max_level".into(), "10".into()),
+            Matcher::UrlEncoded("aggregation".into(), "1".into()),
+        ]))
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "asks": [{"price": 2001.0, "quantity": 3.5}],
+            "bids": [{"price": 2000.0, "quantity": 1.25}],
+            "timestamp": 1677721600000_u64
+        })))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let response = client
+        .get_orderbook(
+            &common::test_credentials(),
+            "PERP_ETH_USDC",
+            Some(10),
+            Some(1.0),
+        )
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.data.asks[0].price, 2001.0);
+    assert_eq!(response.data.bids[0].quantity, 1.25);
+}
+
+#[tokio::test]
+async fn test_get_orderbook_rejects_non_positive_aggregation() {
+    let client = OrderlyService::with_base_url("http://localhost", None).unwrap();
+    let result = client
+        .get_orderbook(
+            &common::test_credentials(),
+            "PERP_ETH_USDC",
+            None,
+            Some(0.0),
+        )
+        .await;
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_get_normalized_orderbook_snapshot() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/orderbook/PERP_ETH_USDC")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "asks": [[2003.0, 1.0], [2001.0, 0.0], [2002.0, 2.0], [2003.0, 4.0]],
+            "bids": [[1998.0, 1.0], [2000.0, 0.5], [1999.0, 0.0]],
+            "timestamp": 1677721600000_u64
+        })))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let book = client
+        .get_normalized_orderbook_snapshot(&common::test_credentials(), "PERP_ETH_USDC", None)
+        .await
+        .unwrap()
+        .data;
+
+    mock.assert_async().await;
+    let asks: Vec<(f64, f64)> = book.asks.iter().map(|l| (l.price, l.quantity)).collect();
+    let bids: Vec<(f64, f64)> = book.bids.iter().map(|l| (l.price, l.quantity)).collect();
+    assert_eq!(asks, vec![(2002.0, 2.0), (2003.0, 4.0)]);
+    assert_eq!(bids, vec![(2000.0, 0.5), (1998.0, 1.0)]);
+    assert_eq!(book.spread(), Some(2.0));
+    assert_eq!(book.mid_price(), Some(2001.0));
+}
+
+#[test]
+fn test_spread_and_mid_price_ignore_level_order() {
+    let book: OrderbookSnapshotData = serde_json::from_value(json!({
+        "asks": [[101.0, 1.0], [100.5, 0.0], [100.0, 2.0]],
+        "bids": [[98.0, 1.0], [99.0, 3.0]],
+        "timestamp": 1677721600000_u64
+    }))
+    .unwrap();
+    assert_eq!(book.best_ask(), Some(100.0));
+    assert_eq!(book.best_bid(), Some(99.0));
+    assert_eq!(book.spread(), Some(1.0));
+    assert_eq!(book.mid_price(), Some(99.5));
+
+    let one_sided: OrderbookSnapshotData = serde_json::from_value(json!({
+        "asks": [],
+        "bids": [[99.0, 3.0]],
+        "timestamp": 1677721600000_u64
+    }))
+    .unwrap();
+    assert_eq!(one_sided.spread(), None);
+    assert_eq!(one_sided.mid_price(), None);
+}
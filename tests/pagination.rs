@@ -0,0 +1,177 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{
+    rest::OrderlyService,
+    types::{GetTradesParams, OrderStatus},
+};
+use serde_json::json;
+
+fn order_row(order_id: u64, status: &str) -> serde_json::Value {
+    json!({
+        "order_id": order_id,
+        "client_order_id": null,
+        "symbol": "PERP_ETH_USDC",
+        "side": "BUY",
+        "type": "LIMIT",
+        "order_price": 1990.0,
+        "order_quantity": 1.0,
+        "order_amount": null,
+        "status": status,
+        "executed_quantity": 0.0,
+        "created_time": 1677721600000_u64,
+        "updated_time": 1677721600000_u64
+    })
+}
+
+fn trade_row(id: u64) -> serde_json::Value {
+    json!({
+        "id": id,
+        "symbol": "PERP_ETH_USDC",
+        "side": "BUY",
+        "order_id": 1000 + id,
+        "order_source": null,
+        "executed_price": 2000.0,
+        "executed_quantity": 0.1,
+        "fee": 0.01,
+        "fee_asset": "USDC",
+        "is_maker": false,
+        "executed_timestamp": 1677721600000_u64
+    })
+}
+
+#[tokio::test]
+async fn test_get_all_trades_follows_pagination() {
+    let mut server = Server::new_async().await;
+
+    let page_1 = server
+        .mock("GET", "/v1/trades")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("page".into(), "1".into()),
+            Matcher::UrlEncoded("size".into(), "2".into()),
+        ]))
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "rows": [trade_row(1), trade_row(2)],
+            "meta": {"total": 3, "current_page": 1, "records_per_page": 2}
+        })))
+        .create_async()
+        .await;
+
+    let page_2 = server
+        .mock("GET", "/v1/trades")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("page".into(), "2".into()),
+            Matcher::UrlEncoded("size".into(), "2".into()),
+        ]))
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "rows": [trade_row(3)],
+            "meta": {"total": 3, "current_page": 2, "records_per_page": 2}
+        })))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+
+    let params = GetTradesParams {
+        size: Some(2),
+        ..Default::default()
+    };
+    let trades = client.get_all_trades(&creds, Some(params)).await.unwrap();
+
+    page_1.assert_async().await;
+    page_2.assert_async().await;
+    assert_eq!(trades.len(), 3);
+    assert_eq!(
+        trades.iter().map(|t| t.id).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+}
+
+#[tokio::test]
+async fn test_get_trades_filters_by_order_id() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/trades")
+        .match_query(Matcher::UrlEncoded("order_id".into(), "1002".into()))
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "rows": [trade_row(2)],
+            "meta": {"total": 1, "current_page": 1, "records_per_page": 25}
+        })))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let params = GetTradesParams {
+        order_id: Some(1002),
+        ..Default::default()
+    };
+    let response = client
+        .get_trades(&common::test_credentials(), Some(params))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.data.rows.len(), 1);
+    assert_eq!(response.data.rows[0].order_id, 1002);
+}
+
+#[tokio::test]
+async fn test_get_open_orders_pages_through_incomplete_orders() {
+    let mut server = Server::new_async().await;
+
+    let pages = [
+        (1, vec![order_row(1, "NEW"), order_row(2, "PARTIAL_FILLED")]),
+        (2, vec![order_row(3, "ACCEPTED")]),
+    ];
+    let mut mocks = Vec::new();
+    for (page, rows) in pages {
+        let mock = server
+            .mock("GET", "/v1/orders")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("symbol".into(), "PERP_ETH_USDC".into()),
+                Matcher::UrlEncoded("status".into(), "INCOMPLETE".into()),
+                Matcher::UrlEncoded("page".into(), page.to_string()),
+            ]))
+            .match_header("orderly-key", "test_key")
+            .match_header("orderly-signature", Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(common::success_body(json!({
+                "rows": rows,
+                "meta": {"total": 3, "current_page": page, "records_per_page": 2}
+            })))
+            .expect(1)
+            .create_async()
+            .await;
+        mocks.push(mock);
+    }
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let orders = client
+        .get_open_orders(&common::test_credentials(), Some("PERP_ETH_USDC"))
+        .await
+        .unwrap();
+
+    for mock in &mocks {
+        mock.assert_async().await;
+    }
+    assert_eq!(
+        orders.iter().map(|o| o.order_id).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert_eq!(orders[1].status, OrderStatus::PartialFilled);
+}
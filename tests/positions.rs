@@ -0,0 +1,284 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{
+    error::OrderlyError,
+    rest::OrderlyService,
+    types::{
+        pnl_by_symbol, GetPositionHistoryParams, GetPositionHistoryResponseData,
+        GetPositionsResponseData, PnlSummary,
+    },
+};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_get_position_history() {
+    let mut server = Server::new_async().await;
+
+    let mock_response = json!({
+        "success": true,
+        "timestamp": 1677721600123_u64,
+        "data": {
+            "rows": [
+                {
+                    "position_id": 42,
+                    "symbol": "PERP_ETH_USDC",
+                    "side": "LONG",
+                    "position_status": "closed",
+                    "avg_open_price": 2000.0,
+                    "avg_close_price": 2100.0,
+                    "max_position_qty": 1.0,
+                    "closed_position_qty": 1.0,
+                    "realized_pnl": 100.0,
+                    "trading_fee": 1.2,
+                    "accumulated_funding_fee": 0.3,
+                    "open_timestamp": 1677700000000_u64,
+                    "close_timestamp": 1677721600000_u64,
+                    "last_update_time": 1677721600000_u64
+                }
+            ],
+            "meta": {"total": 1, "current_page": 1, "records_per_page": 25}
+        }
+    });
+
+    let _m = server
+        .mock("GET", "/v1/position_history")
+        .match_query(Matcher::UrlEncoded("symbol".into(), "PERP_ETH_USDC".into()))
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-account-id", "test_account")
+        .match_header("orderly-timestamp", Matcher::Any)
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response.to_string())
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+
+    let params = GetPositionHistoryParams {
+        symbol: Some("PERP_ETH_USDC".to_string()),
+        ..Default::default()
+    };
+    let response = client
+        .get_position_history(&creds, Some(params))
+        .await
+        .unwrap();
+
+    assert!(response.success);
+    let entry = &response.data.rows[0];
+    assert_eq!(entry.position_id, 42);
+    assert_eq!(entry.side, "LONG");
+    assert_eq!(entry.realized_pnl, 100.0);
+    assert_eq!(entry.avg_close_price, Some(2100.0));
+    assert_eq!(entry.close_timestamp, Some(1677721600000));
+}
+
+fn history_row(symbol: &str, realized_pnl: f64, close_timestamp: Option<u64>) -> serde_json::Value {
+    json!({
+        "position_id": 1,
+        "symbol": symbol,
+        "side": "LONG",
+        "position_status": if close_timestamp.is_some() { "closed" } else { "partial_closed" },
+        "realized_pnl": realized_pnl,
+        "trading_fee": 1.0,
+        "accumulated_funding_fee": 0.25,
+        "open_timestamp": 1000_u64,
+        "close_timestamp": close_timestamp,
+        "last_update_time": 2500_u64
+    })
+}
+
+#[test]
+fn test_pnl_by_symbol() {
+    let data: GetPositionHistoryResponseData = serde_json::from_value(json!({
+        "rows": [
+            history_row("PERP_ETH_USDC", 100.0, Some(2000)),
+            history_row("PERP_ETH_USDC", -40.0, Some(3000)),
+            history_row("PERP_BTC_USDC", 15.0, None),
+            history_row("PERP_SOL_USDC", 7.0, Some(9000))
+        ]
+    }))
+    .unwrap();
+
+    let all = data.pnl_by_symbol(None, None);
+    assert_eq!(all.len(), 3);
+    assert_eq!(
+        all["PERP_ETH_USDC"],
+        PnlSummary {
+            realized_pnl: 60.0,
+            trading_fee: 2.0,
+            funding_fee: 0.5,
+            positions: 2,
+        }
+    );
+
+    // The partially open BTC row is dated by its last_update_time (2500)
+    let ranged = pnl_by_symbol(&data.rows, Some(2500), Some(3000));
+    assert_eq!(ranged["PERP_ETH_USDC"].realized_pnl, -40.0);
+    assert_eq!(ranged["PERP_BTC_USDC"].positions, 1);
+    assert!(!ranged.contains_key("PERP_SOL_USDC"));
+}
+
+fn position_row(symbol: &str, position_qty: f64) -> serde_json::Value {
+    json!({
+        "symbol": symbol,
+        "position_qty": position_qty,
+        "cost_position": -3000.0,
+        "last_sum_unitary_funding": 0.0,
+        "pending_long_qty": 0.0,
+        "pending_short_qty": 0.0,
+        "unsettled_pnl": 5.0,
+        "mark_price": 2000.0,
+        "average_open_price": 2000.0,
+        "timestamp": 1677721600000_u64,
+        "fee_24_h": 0.0,
+        "est_liq_price": 0.0,
+        "seq": 1,
+        "imr": 0.1,
+        "mmr": 0.05,
+        "IMR_withdraw_orders": 0.1,
+        "MMR_with_orders": 0.05,
+        "pnl_24_h": 0.0,
+        "settle_price": 2000.0
+    })
+}
+
+fn position_body(position_qty: f64) -> String {
+    json!({
+        "success": true,
+        "timestamp": 1677721600123_u64,
+        "data": position_row("PERP_ETH_USDC", position_qty)
+    })
+    .to_string()
+}
+
+#[test]
+fn test_positions_by_symbol_and_nonzero() {
+    let data: GetPositionsResponseData = serde_json::from_value(json!({
+        "rows": [
+            position_row("PERP_ETH_USDC", -1.5),
+            position_row("PERP_BTC_USDC", 0.0),
+            position_row("PERP_SOL_USDC", 20.0)
+        ]
+    }))
+    .unwrap();
+
+    assert_eq!(
+        data.by_symbol("PERP_BTC_USDC").map(|p| p.position_qty),
+        Some(0.0)
+    );
+    assert!(data.by_symbol("PERP_DOGE_USDC").is_none());
+
+    let open: Vec<&str> = data
+        .nonzero_positions()
+        .iter()
+        .map(|p| p.symbol.as_str())
+        .collect();
+    assert_eq!(open, vec!["PERP_ETH_USDC", "PERP_SOL_USDC"]);
+}
+
+#[tokio::test]
+async fn test_create_reduce_only_close_short_position() {
+    let mut server = Server::new_async().await;
+
+    let _position = server
+        .mock("GET", "/v1/position/PERP_ETH_USDC")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(position_body(-1.5))
+        .create_async()
+        .await;
+
+    let order = server
+        .mock("POST", "/v1/order")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .match_body(Matcher::PartialJson(json!({
+            "symbol": "PERP_ETH_USDC",
+            "order_type": "MARKET",
+            "side": "BUY",
+            "order_quantity": 1.5,
+            "reduce_only": true
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(
+            json!({"order_id": 99, "client_order_id": null}),
+        ))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let response = client
+        .create_reduce_only_close(&common::test_credentials(), "PERP_ETH_USDC")
+        .await
+        .unwrap();
+
+    order.assert_async().await;
+    assert_eq!(response.data.order_id, 99);
+}
+
+#[tokio::test]
+async fn test_create_reduce_only_close_without_position() {
+    let mut server = Server::new_async().await;
+
+    let _position = server
+        .mock("GET", "/v1/position/PERP_ETH_USDC")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(position_body(0.0))
+        .create_async()
+        .await;
+    let order = server
+        .mock("POST", "/v1/order")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let result = client
+        .create_reduce_only_close(&common::test_credentials(), "PERP_ETH_USDC")
+        .await;
+
+    order.assert_async().await;
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn test_get_positions_all_subaccounts() {
+    let mut server = Server::new_async().await;
+
+    let mut mocks = Vec::new();
+    for (sub_account_id, qty) in [("sub_1", 1.0), ("sub_2", -2.0)] {
+        let mock = server
+            .mock("GET", "/v1/positions")
+            .match_header("orderly-key", "test_key")
+            .match_header("orderly-account-id", sub_account_id)
+            .match_header("orderly-signature", Matcher::Any)
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(common::success_body(
+                json!({"rows": [position_row("PERP_ETH_USDC", qty)]}),
+            ))
+            .create_async()
+            .await;
+        mocks.push(mock);
+    }
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let positions = client
+        .get_positions_all_subaccounts(&common::test_credentials(), &["sub_1", "sub_2"])
+        .await
+        .unwrap();
+
+    for mock in &mocks {
+        mock.assert_async().await;
+    }
+    assert_eq!(positions.len(), 2);
+    assert_eq!(positions["sub_1"].data.rows[0].position_qty, 1.0);
+    assert_eq!(positions["sub_2"].data.rows[0].position_qty, -2.0);
+}
@@ -0,0 +1,66 @@
+use orderly_connector_rs::rest::client::{Credentials, OwnedCredentials};
+use orderly_connector_rs::websocket::WebsocketClientConfig;
+
+#[test]
+fn test_credentials_debug_redacts_key_and_secret() {
+    let creds = Credentials {
+        orderly_key: "ed25519:public_key_value",
+        orderly_secret: "secret_value",
+        orderly_account_id: "test_account",
+    };
+    let output = format!("{:?}", creds);
+    assert!(!output.contains("public_key_value"));
+    assert!(!output.contains("secret_value"));
+    assert!(output.contains("test_account"));
+    assert!(output.contains("***"));
+}
+
+#[test]
+fn test_websocket_config_debug_redacts_key_and_secret() {
+    let config = WebsocketClientConfig {
+        base_url: "wss://example.com/ws".to_string(),
+        orderly_key: Some("ed25519:public_key_value".to_string()),
+        orderly_secret: Some("secret_value".to_string()),
+        orderly_account_id: "test_account".to_string(),
+        ..Default::default()
+    };
+    let output = format!("{:?}", config);
+    assert!(!output.contains("public_key_value"));
+    assert!(!output.contains("secret_value"));
+    assert!(output.contains("test_account"));
+    assert!(output.contains("wss://example.com/ws"));
+
+    // Absent credentials are shown as such rather than as redacted values
+    let output = format!("{:?}", WebsocketClientConfig::default());
+    assert!(output.contains("orderly_secret: None"));
+}
+
+#[test]
+fn test_websocket_config_serialization_omits_secret() {
+    let config = WebsocketClientConfig {
+        base_url: "wss://example.com/ws".to_string(),
+        orderly_key: Some("ed25519:public_key_value".to_string()),
+        orderly_secret: Some("secret_value".to_string()),
+        orderly_account_id: "test_account".to_string(),
+        ..Default::default()
+    };
+    let output = serde_json::to_string(&config).unwrap();
+    assert!(!output.contains("secret_value"));
+    assert!(!output.contains("orderly_secret"));
+    assert!(output.contains("test_account"));
+
+    // The secret is still read from a stored config
+    let mut value: serde_json::Value = serde_json::from_str(&output).unwrap();
+    value["orderly_secret"] = "secret_value".into();
+    let parsed: WebsocketClientConfig = serde_json::from_value(value).unwrap();
+    assert_eq!(parsed.orderly_secret.as_deref(), Some("secret_value"));
+}
+
+#[test]
+fn test_owned_credentials_debug_redacts_key_and_secret() {
+    let owned = OwnedCredentials::new("ed25519:public_key_value", "secret_value", "test_account");
+    let output = format!("{:?}", owned);
+    assert!(!output.contains("public_key_value"));
+    assert!(!output.contains("secret_value"));
+    assert!(output.contains("test_account"));
+}
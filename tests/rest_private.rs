@@ -3,7 +3,7 @@
 mod common;
 
 use chrono::Utc;
-use orderly_connector_rs::rest::client::Credentials;
+use orderly_connector_rs::rest::client::OwnedCredentials;
 use orderly_connector_rs::rest::OrderlyService;
 use orderly_connector_rs::types::GetBrokerVolumeParams;
 use orderly_connector_rs::types::{
@@ -12,7 +12,7 @@ use orderly_connector_rs::types::{
 };
 use tokio::time::{sleep, Duration};
 
-fn setup_client() -> (OrderlyService, Credentials<'static>) {
+fn setup_client() -> (OrderlyService, OwnedCredentials) {
     common::setup();
     let api_key = common::get_env_var("ORDERLY_API_KEY");
     let secret = common::get_env_var("ORDERLY_SECRET");
@@ -28,11 +28,7 @@ fn setup_client() -> (OrderlyService, Credentials<'static>) {
     let service = OrderlyService::new(is_testnet, None).expect("Failed to create REST client");
 
     // Create credentials that will be owned by the test
-    let creds = Credentials {
-        orderly_key: Box::leak(api_key.into_boxed_str()),
-        orderly_secret: Box::leak(secret.into_boxed_str()),
-        orderly_account_id: Box::leak(account_id.into_boxed_str()),
-    };
+    let creds = OwnedCredentials::new(api_key, secret, account_id);
 
     (service, creds)
 }
@@ -56,6 +52,8 @@ async fn test_create_get_cancel_order() {
         client_order_id: Some("my_order_id".to_string()),
         visible_quantity: None,
         reduce_only: None,
+        time_in_force: None,
+        expire_time: None,
     };
 
     let created_order_id = match client.create_order(&creds, order_req).await {
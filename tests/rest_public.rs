@@ -512,11 +512,7 @@ async fn test_get_market_trades() {
         first_trade.symbol, symbol,
         "Trade symbol should match request"
     );
-    // Check the side field as a string
-    assert!(
-        first_trade.side == "BUY" || first_trade.side == "SELL",
-        "Trade side should be BUY or SELL string"
-    );
+    // The side is typed, so a BUY/SELL value is guaranteed by deserialization
     // Check price, quantity, and timestamp fields from PublicTradeData
     assert!(
         first_trade.executed_price > 0.0,
@@ -0,0 +1,104 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{rest::OrderlyService, types::GetRewardHistoryParams};
+use serde_json::json;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[tokio::test]
+async fn test_get_reward_history() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/client/distribution_history")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("page".into(), "2".into()),
+            Matcher::UrlEncoded("size".into(), "10".into()),
+        ]))
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "rows": [{
+                "epoch_id": 7,
+                "token": "ORDER",
+                "amount": 125.5,
+                "type": "TRADING_REWARDS",
+                "status": "COMPLETED",
+                "created_time": 1677721600000_u64,
+                "updated_time": 1677721600000_u64
+            }],
+            "meta": {"total": 11, "current_page": 2, "records_per_page": 10}
+        })))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let params = GetRewardHistoryParams {
+        page: Some(2),
+        size: Some(10),
+        ..Default::default()
+    };
+    let response = client
+        .get_reward_history(&common::test_credentials(), Some(params))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    let entry = &response.data.rows[0];
+    assert_eq!(entry.epoch_id, Some(7));
+    assert_eq!(entry.amount, 125.5);
+    assert_eq!(entry.reward_type.as_deref(), Some("TRADING_REWARDS"));
+}
+
+#[tokio::test]
+async fn test_get_current_epoch() {
+    let mut server = Server::new_async().await;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_millis() as u64;
+    let week = 7 * 24 * 60 * 60 * 1000;
+    let epoch = |id: u64, start: u64| {
+        json!({
+            "epoch_id": id,
+            "start_time": start,
+            "end_time": start + week,
+            "max_reward_amount": 1000000.0,
+            "token": "ORDER"
+        })
+    };
+
+    let _m = server
+        .mock("GET", "/v1/public/trading_rewards/epoch_info")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "timestamp": now,
+                "data": {
+                    "rows": [
+                        epoch(1, now - 2 * week),
+                        epoch(2, now - week / 2),
+                        epoch(3, now + week / 2)
+                    ]
+                }
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let current = client
+        .get_current_epoch()
+        .await
+        .unwrap()
+        .expect("expected a running epoch");
+
+    assert_eq!(current.epoch_id, 2);
+    assert!(current.contains(now));
+}
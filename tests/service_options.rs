@@ -0,0 +1,311 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::rest::{
+    MetricsObserver, OrderlyService, OrderlyServiceOptions, RateLimitStatus, RetryPolicy,
+};
+use orderly_connector_rs::types::{CreateOrderRequest, OrderType, Side};
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+#[tokio::test]
+async fn test_with_options_sends_custom_user_agent() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/v1/public/system_info")
+        .match_header("user-agent", "my-trading-bot/1.0")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"success": true, "data": {"status": 0, "msg": "System is functioning properly."}}).to_string())
+        .create_async()
+        .await;
+
+    let service = OrderlyService::with_options(OrderlyServiceOptions {
+        base_url: Some(server.url()),
+        user_agent: Some("my-trading-bot/1.0".to_string()),
+        ..Default::default()
+    })
+    .unwrap();
+
+    let status = service.get_system_status().await.unwrap();
+    assert_eq!(status["success"], true);
+}
+
+#[test]
+fn test_with_options_rejects_invalid_proxy() {
+    let result = OrderlyService::with_options(OrderlyServiceOptions {
+        is_testnet: true,
+        proxy: Some("not a proxy url".to_string()),
+        ..Default::default()
+    });
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_with_options_uses_injected_http_client() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/v1/public/system_info")
+        .match_header("x-shared-client", "yes")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"success": true, "data": {"status": 0}}).to_string())
+        .create_async()
+        .await;
+
+    let mut headers = reqwest::header::HeaderMap::new();
+    headers.insert("x-shared-client", "yes".parse().unwrap());
+    let http_client = reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .unwrap();
+
+    let service = OrderlyService::with_options(OrderlyServiceOptions {
+        base_url: Some(server.url()),
+        http_client: Some(http_client),
+        ..Default::default()
+    })
+    .unwrap();
+
+    let status = service.get_system_status().await.unwrap();
+    assert_eq!(status["success"], true);
+}
+
+fn holdings_body() -> String {
+    common::success_body(json!({"holding": []}))
+}
+
+#[tokio::test]
+async fn test_recv_window_header_sent_when_configured() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/client/holding")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-recv-window", "10000")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(holdings_body())
+        .create_async()
+        .await;
+
+    let service = OrderlyService::with_base_url(&server.url(), None)
+        .unwrap()
+        .with_recv_window_ms(10_000);
+    service
+        .get_holding(&common::test_credentials())
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_recv_window_header_omitted_by_default() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/client/holding")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-recv-window", Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(holdings_body())
+        .create_async()
+        .await;
+
+    let service = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    service
+        .get_holding(&common::test_credentials())
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+}
+
+#[derive(Default)]
+struct RecordingMetrics {
+    events: Mutex<Vec<(String, Option<u16>)>>,
+}
+
+impl MetricsObserver for RecordingMetrics {
+    fn on_request_start(&self, path: &str) {
+        self.events
+            .lock()
+            .unwrap()
+            .push((format!("start {}", path), None));
+    }
+
+    fn on_request_end(&self, path: &str, status: Option<u16>, _duration: Duration) {
+        self.events
+            .lock()
+            .unwrap()
+            .push((format!("end {}", path), status));
+    }
+}
+
+#[tokio::test]
+async fn test_metrics_observer_sees_requests() {
+    let mut server = Server::new_async().await;
+
+    let _ok = server
+        .mock("GET", "/v1/public/system_info")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(json!({"success": true, "data": {"status": 0}}).to_string())
+        .create_async()
+        .await;
+    let _err = server
+        .mock("GET", "/v1/client/holding")
+        .with_status(500)
+        .with_body("internal error")
+        .create_async()
+        .await;
+
+    let metrics = Arc::new(RecordingMetrics::default());
+    let service = OrderlyService::with_base_url(&server.url(), None)
+        .unwrap()
+        .with_metrics_observer(metrics.clone());
+
+    service.get_system_status().await.unwrap();
+    assert!(service
+        .get_holding(&common::test_credentials())
+        .await
+        .is_err());
+
+    let events = metrics.events.lock().unwrap().clone();
+    assert_eq!(
+        events,
+        vec![
+            ("start /v1/public/system_info".to_string(), None),
+            ("end /v1/public/system_info".to_string(), Some(200)),
+            ("start /v1/client/holding".to_string(), None),
+            ("end /v1/client/holding".to_string(), Some(500)),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_last_rate_limit_tracks_success_and_error_responses() {
+    let mut server = Server::new_async().await;
+
+    let _ok = server
+        .mock("GET", "/v1/public/system_info")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_header("x-ratelimit-limit", "10")
+        .with_header("x-ratelimit-remaining", "9")
+        .with_header("x-ratelimit-reset", "1677721601000")
+        .with_body(json!({"success": true, "data": {"status": 0}}).to_string())
+        .create_async()
+        .await;
+    let _limited = server
+        .mock("GET", "/v1/client/holding")
+        .with_status(429)
+        .with_header("x-ratelimit-limit", "10")
+        .with_header("x-ratelimit-remaining", "0")
+        .create_async()
+        .await;
+
+    let service = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    assert_eq!(service.last_rate_limit(), None);
+
+    service.get_system_status().await.unwrap();
+    assert_eq!(
+        service.last_rate_limit(),
+        Some(RateLimitStatus {
+            limit: Some(10),
+            remaining: Some(9),
+            reset: Some(1677721601000),
+        })
+    );
+
+    assert!(service
+        .get_holding(&common::test_credentials())
+        .await
+        .is_err());
+    let status = service.last_rate_limit().unwrap();
+    assert_eq!(status.remaining, Some(0));
+    assert_eq!(status.reset, None);
+}
+
+/// Spawns an HTTP server that never answers its first `stalled` connections and answers
+/// later ones with `body`. Returns the server URL and a count of accepted connections.
+async fn spawn_flaky_server(stalled: usize, body: String) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let connections = Arc::new(AtomicUsize::new(0));
+
+    tokio::spawn({
+        let connections = Arc::clone(&connections);
+        async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let n = connections.fetch_add(1, Ordering::SeqCst);
+                let body = body.clone();
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 4096];
+                    let _ = socket.read(&mut buf).await;
+                    if n < stalled {
+                        tokio::time::sleep(Duration::from_secs(10)).await;
+                        return;
+                    }
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        }
+    });
+
+    (format!("http://{}", addr), connections)
+}
+
+fn retrying_service(base_url: String) -> OrderlyService {
+    OrderlyService::with_options(OrderlyServiceOptions {
+        base_url: Some(base_url),
+        timeout_sec: Some(1),
+        retry_policy: Some(RetryPolicy {
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(10),
+        }),
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_get_is_retried_after_timeout() {
+    let (url, connections) =
+        spawn_flaky_server(1, json!({"success": true, "data": {}}).to_string()).await;
+
+    let service = retrying_service(url);
+    let status = service.get_system_status().await.unwrap();
+
+    assert_eq!(status["success"], true);
+    assert_eq!(connections.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_post_is_not_retried_after_timeout() {
+    let (url, connections) = spawn_flaky_server(1, holdings_body()).await;
+
+    let service = retrying_service(url);
+    let order = CreateOrderRequest::new("PERP_ETH_USDC".to_string(), OrderType::Market, Side::Buy)
+        .with_quantity(0.1);
+    let result = service
+        .create_order(&common::test_credentials(), order)
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,122 @@
+use orderly_connector_rs::{
+    auth::{generate_signature, AuthHeaderNames, AuthVersion},
+    rest::{
+        client::{Credentials, OwnedCredentials},
+        OrderlyService,
+    },
+};
+use reqwest::{header::HeaderName, Method};
+use serde_json::json;
+
+#[test]
+fn test_build_signed_request_parts() {
+    let service = OrderlyService::with_base_url("https://api.example.com", None).unwrap();
+    let creds = Credentials {
+        orderly_key: "test_key",
+        orderly_secret: "11111111111111111111111111111111",
+        orderly_account_id: "test_account",
+    };
+
+    let body = json!({"symbol": "PERP_ETH_USDC", "order_type": "MARKET", "side": "BUY"});
+    let parts = service
+        .build_signed_request_parts(&creds, Method::POST, "/v1/order", Some(&body))
+        .unwrap();
+
+    assert_eq!(parts.method, Method::POST);
+    assert_eq!(parts.url.as_str(), "https://api.example.com/v1/order");
+    assert_eq!(parts.headers["orderly-key"], "test_key");
+    assert_eq!(parts.headers["orderly-account-id"], "test_account");
+    assert_eq!(parts.headers["content-type"], "application/json");
+
+    let body_str = parts.body.expect("body should be set");
+    assert_eq!(body_str, body.to_string());
+
+    // The signature covers timestamp + method + path + body
+    let timestamp = parts.headers["orderly-timestamp"].to_str().unwrap();
+    let expected = generate_signature(
+        creds.orderly_secret,
+        &format!("{}POST/v1/order{}", timestamp, body_str),
+    )
+    .unwrap();
+    assert_eq!(parts.headers["orderly-signature"], expected.as_str());
+}
+
+#[test]
+fn test_build_signed_request_parts_without_body() {
+    let service = OrderlyService::with_base_url("https://api.example.com", None).unwrap();
+    let creds = Credentials {
+        orderly_key: "test_key",
+        orderly_secret: "11111111111111111111111111111111",
+        orderly_account_id: "test_account",
+    };
+
+    let parts = service
+        .build_signed_request_parts::<()>(
+            &creds,
+            Method::GET,
+            "/v1/orders?symbol=PERP_ETH_USDC",
+            None,
+        )
+        .unwrap();
+
+    assert_eq!(
+        parts.url.as_str(),
+        "https://api.example.com/v1/orders?symbol=PERP_ETH_USDC"
+    );
+    assert!(parts.body.is_none());
+}
+
+#[test]
+fn test_build_signed_request_parts_with_custom_auth_version() {
+    let names = AuthHeaderNames {
+        timestamp: HeaderName::from_static("x-orderly-timestamp"),
+        key: HeaderName::from_static("x-orderly-key"),
+        signature: HeaderName::from_static("x-orderly-signature"),
+        account_id: HeaderName::from_static("x-orderly-account-id"),
+        recv_window: HeaderName::from_static("x-orderly-recv-window"),
+    };
+    let service = OrderlyService::with_base_url("https://api.example.com", None)
+        .unwrap()
+        .with_recv_window_ms(5000)
+        .with_auth_version(AuthVersion::Custom(names));
+    let creds = Credentials {
+        orderly_key: "test_key",
+        orderly_secret: "11111111111111111111111111111111",
+        orderly_account_id: "test_account",
+    };
+
+    let parts = service
+        .build_signed_request_parts::<()>(&creds, Method::GET, "/v1/positions", None)
+        .unwrap();
+
+    assert_eq!(parts.headers["x-orderly-key"], "test_key");
+    assert_eq!(parts.headers["x-orderly-account-id"], "test_account");
+    assert_eq!(parts.headers["x-orderly-recv-window"], "5000");
+    assert!(parts.headers.contains_key("x-orderly-timestamp"));
+    assert!(parts.headers.contains_key("x-orderly-signature"));
+    assert!(!parts.headers.contains_key("orderly-key"));
+}
+
+#[test]
+fn test_build_signed_request_parts_with_owned_credentials() {
+    let service = OrderlyService::with_base_url("https://api.example.com", None).unwrap();
+    let owned = OwnedCredentials::new(
+        "test_key".to_string(),
+        "11111111111111111111111111111111".to_string(),
+        "test_account".to_string(),
+    );
+
+    let parts = service
+        .build_signed_request_parts::<()>(&owned, Method::GET, "/v1/positions", None)
+        .unwrap();
+
+    assert_eq!(parts.headers["orderly-key"], "test_key");
+    assert_eq!(parts.headers["orderly-account-id"], "test_account");
+    let timestamp = parts.headers["orderly-timestamp"].to_str().unwrap();
+    let expected = generate_signature(
+        &owned.orderly_secret,
+        &format!("{}GET/v1/positions", timestamp),
+    )
+    .unwrap();
+    assert_eq!(parts.headers["orderly-signature"], expected.as_str());
+}
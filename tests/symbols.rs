@@ -0,0 +1,56 @@
+mod common;
+
+use mockito::Server;
+use orderly_connector_rs::rest::OrderlyService;
+use serde_json::json;
+use std::time::Duration;
+
+fn symbol_row(symbol: &str, quote_tick: f64) -> serde_json::Value {
+    json!({
+        "symbol": symbol,
+        "quote_min": 0.0,
+        "quote_max": 100000.0,
+        "quote_tick": quote_tick,
+        "base_min": 0.001,
+        "base_max": 1000.0,
+        "base_tick": 0.001,
+        "min_notional": 10.0,
+        "price_range": 0.02,
+        "created_time": 1677721600000_u64,
+        "updated_time": 1677721600000_u64,
+        "imr_factor": null,
+        "liquidation_fee": null
+    })
+}
+
+#[tokio::test]
+async fn test_get_symbols_map_cached() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/public/info")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "rows": [
+                symbol_row("PERP_ETH_USDC", 0.01),
+                symbol_row("PERP_BTC_USDC", 0.1)
+            ]
+        })))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let ttl = Duration::from_secs(60);
+
+    let symbols = client.get_symbols_map_cached(ttl).await.unwrap();
+    assert_eq!(symbols.len(), 2);
+    assert_eq!(symbols["PERP_ETH_USDC"].quote_tick, 0.01);
+    assert_eq!(symbols["PERP_BTC_USDC"].quote_tick, 0.1);
+
+    let cached = client.get_symbols_map_cached(ttl).await.unwrap();
+    assert_eq!(cached.len(), 2);
+
+    mock.assert_async().await;
+}
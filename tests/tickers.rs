@@ -0,0 +1,56 @@
+mod common;
+
+use mockito::Server;
+use orderly_connector_rs::rest::OrderlyService;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_get_all_tickers() {
+    let mut server = Server::new_async().await;
+
+    let futures = server
+        .mock("GET", "/v1/public/futures")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({"rows": [
+            {
+                "symbol": "PERP_ETH_USDC",
+                "index_price": 2400.0,
+                "mark_price": 2401.5,
+                "est_funding_rate": 0.0001,
+                "open_interest": 1200.5,
+                "24h_open": 2350.0,
+                "24h_close": 2401.0,
+                "24h_high": 2420.0,
+                "24h_low": 2330.0,
+                "24h_volume": 5300.25,
+                "24h_amount": 12600000.0
+            },
+            {
+                "symbol": "PERP_BTC_USDC",
+                "24h_open": 59000.0,
+                "24h_close": 60000.0,
+                "24h_high": 60500.0,
+                "24h_low": 58800.0,
+                "24h_volume": 310.5,
+                "24h_amount": 18630000.0
+            }
+        ]})))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let tickers = client.get_all_tickers().await.unwrap();
+
+    futures.assert_async().await;
+    assert_eq!(tickers.len(), 2);
+    assert_eq!(tickers[0].symbol, "PERP_ETH_USDC");
+    assert_eq!(tickers[0].open_price, Some(2350.0));
+    assert_eq!(tickers[0].close_price, 2401.0);
+    assert_eq!(tickers[0].high_price, Some(2420.0));
+    assert_eq!(tickers[0].low_price, Some(2330.0));
+    assert_eq!(tickers[0].volume, Some(5300.25));
+    assert_eq!(tickers[0].amount, Some(12600000.0));
+    assert_eq!(tickers[0].trade_count, None);
+    assert_eq!(tickers[1].close_price, 60000.0);
+}
@@ -1,19 +1,14 @@
+mod common;
+
 use mockito::{self, Server};
 use orderly_connector_rs::{
-    rest::{client::Credentials, OrderlyService},
-    types::{AlgoOrderType, CreateAlgoOrderRequest, OrderStatus, Side},
+    rest::OrderlyService,
+    types::{
+        AlgoOrderType, BracketOrderRequest, CreateAlgoOrderRequest, OrderStatus, Side, TrailingType,
+    },
 };
 use serde_json::json;
 
-// Helper function to create test credentials
-fn test_credentials() -> Credentials<'static> {
-    Credentials {
-        orderly_key: "test_key",
-        orderly_secret: "11111111111111111111111111111111",
-        orderly_account_id: "test_account",
-    }
-}
-
 #[tokio::test]
 async fn test_take_profit_order() {
     let mut server = Server::new_async().await;
@@ -63,7 +58,7 @@ async fn test_take_profit_order() {
         .await;
 
     let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
-    let creds = test_credentials();
+    let creds = common::test_credentials();
 
     let tp_order = CreateAlgoOrderRequest {
         symbol: "PERP_BTC_USDC".to_string(),
@@ -73,6 +68,8 @@ async fn test_take_profit_order() {
         trigger_price: 52000.0,
         limit_price: Some(51900.0),
         trailing_delta: None,
+        trailing_type: None,
+        activation_price: None,
         client_order_id: Some("tp_btc_1".to_string()),
         reduce_only: Some(true),
     };
@@ -133,7 +130,7 @@ async fn test_stop_loss_order() {
         .await;
 
     let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
-    let creds = test_credentials();
+    let creds = common::test_credentials();
 
     let sl_order = CreateAlgoOrderRequest {
         symbol: "PERP_BTC_USDC".to_string(),
@@ -143,6 +140,8 @@ async fn test_stop_loss_order() {
         trigger_price: 48000.0,
         limit_price: None,
         trailing_delta: None,
+        trailing_type: None,
+        activation_price: None,
         client_order_id: Some("sl_btc_1".to_string()),
         reduce_only: Some(true),
     };
@@ -204,7 +203,7 @@ async fn test_trailing_stop_order() {
         .await;
 
     let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
-    let creds = test_credentials();
+    let creds = common::test_credentials();
 
     let trailing_stop = CreateAlgoOrderRequest {
         symbol: "PERP_BTC_USDC".to_string(),
@@ -214,6 +213,8 @@ async fn test_trailing_stop_order() {
         trigger_price: 47500.0,
         limit_price: None,
         trailing_delta: Some(500.0),
+        trailing_type: None,
+        activation_price: None,
         client_order_id: Some("trailing_stop_1".to_string()),
         reduce_only: Some(true),
     };
@@ -225,3 +226,156 @@ async fn test_trailing_stop_order() {
     assert_eq!(response.data.algo_order_id, "ts_123");
     assert_eq!(response.data.status, OrderStatus::New);
 }
+
+#[tokio::test]
+async fn test_bracket_order() {
+    let mut server = Server::new_async().await;
+
+    let mock_response = json!({
+        "success": true,
+        "timestamp": 1677721600123_u64,
+        "data": {
+            "rows": [
+                {"algo_order_id": "parent_1", "client_order_id": "bracket_btc_1", "order_type": "TP_SL"},
+                {"algo_order_id": "tp_1", "client_order_id": null, "order_type": "TAKE_PROFIT_MARKET"},
+                {"algo_order_id": "sl_1", "client_order_id": null, "order_type": "STOP_LIMIT"}
+            ]
+        }
+    });
+
+    let _m = server
+        .mock("POST", "/v1/algo-order")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-account-id", "test_account")
+        .match_header("orderly-timestamp", mockito::Matcher::Any)
+        .match_header("orderly-signature", mockito::Matcher::Any)
+        .match_body(mockito::Matcher::Json(json!({
+            "symbol": "PERP_BTC_USDC",
+            "order_type": "TP_SL",
+            "quantity": 0.01,
+            "client_order_id": "bracket_btc_1",
+            "child_orders": [
+                {
+                    "symbol": "PERP_BTC_USDC",
+                    "order_type": "TAKE_PROFIT_MARKET",
+                    "side": "SELL",
+                    "trigger_price": 52000.0,
+                    "reduce_only": true
+                },
+                {
+                    "symbol": "PERP_BTC_USDC",
+                    "order_type": "STOP_LIMIT",
+                    "side": "SELL",
+                    "trigger_price": 48000.0,
+                    "limit_price": 47900.0,
+                    "reduce_only": true
+                }
+            ]
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(mock_response.to_string())
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+
+    let request = BracketOrderRequest {
+        symbol: "PERP_BTC_USDC".to_string(),
+        side: Side::Sell,
+        quantity: 0.01,
+        take_profit_trigger: 52000.0,
+        take_profit_limit: None,
+        stop_loss_trigger: 48000.0,
+        stop_loss_limit: Some(47900.0),
+        client_order_id: Some("bracket_btc_1".to_string()),
+    };
+    let response = client.create_bracket_order(&creds, request).await.unwrap();
+
+    assert!(response.success);
+    assert_eq!(response.data.parent_order_id(), Some("parent_1"));
+    assert_eq!(response.data.take_profit_order_id(), Some("tp_1"));
+    assert_eq!(response.data.stop_loss_order_id(), Some("sl_1"));
+}
+
+#[tokio::test]
+async fn test_bracket_order_rejects_inverted_triggers() {
+    let client = OrderlyService::with_base_url("http://localhost", None).unwrap();
+    let creds = common::test_credentials();
+
+    // For a long (closing side SELL) the take profit must trigger above the stop loss
+    let request = BracketOrderRequest {
+        symbol: "PERP_BTC_USDC".to_string(),
+        side: Side::Sell,
+        quantity: 0.01,
+        take_profit_trigger: 48000.0,
+        take_profit_limit: None,
+        stop_loss_trigger: 52000.0,
+        stop_loss_limit: None,
+        client_order_id: None,
+    };
+    let result = client.create_bracket_order(&creds, request).await;
+    assert!(matches!(
+        result,
+        Err(orderly_connector_rs::error::OrderlyError::ValidationError(
+            _
+        ))
+    ));
+}
+
+fn trailing_stop_request(
+    trailing_delta: Option<f64>,
+    trailing_type: Option<TrailingType>,
+) -> CreateAlgoOrderRequest {
+    CreateAlgoOrderRequest {
+        symbol: "PERP_BTC_USDC".to_string(),
+        order_type: AlgoOrderType::TrailingStop,
+        side: Side::Sell,
+        quantity: 0.01,
+        trigger_price: 47500.0,
+        limit_price: None,
+        trailing_delta,
+        trailing_type,
+        activation_price: None,
+        client_order_id: None,
+        reduce_only: Some(true),
+    }
+}
+
+#[test]
+fn test_trailing_stop_activation_price_serialization() {
+    let mut request = trailing_stop_request(Some(1.5), Some(TrailingType::Percentage));
+    request.activation_price = Some(52000.0);
+
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["trailing_type"], "PERCENTAGE");
+    assert_eq!(value["activated_price"], 52000.0);
+    assert!(value.get("activation_price").is_none());
+
+    // Unset fields are omitted entirely
+    let value = serde_json::to_value(trailing_stop_request(Some(500.0), None)).unwrap();
+    assert!(value.get("trailing_type").is_none());
+    assert!(value.get("activated_price").is_none());
+}
+
+#[tokio::test]
+async fn test_trailing_stop_validation() {
+    let client = OrderlyService::with_base_url("http://localhost", None).unwrap();
+    let creds = common::test_credentials();
+
+    let invalid = [
+        trailing_stop_request(None, None),
+        trailing_stop_request(Some(150.0), Some(TrailingType::Percentage)),
+        trailing_stop_request(Some(-1.0), Some(TrailingType::Absolute)),
+    ];
+    for request in invalid {
+        let result = client.create_algo_order(&creds, request).await;
+        assert!(matches!(
+            result,
+            Err(orderly_connector_rs::error::OrderlyError::ValidationError(
+                _
+            ))
+        ));
+    }
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{
+    rest::OrderlyService,
+    types::{Side, Trade},
+};
+use serde_json::json;
+
+fn trade_json(is_maker: serde_json::Value) -> serde_json::Value {
+    json!({
+        "id": 5,
+        "symbol": "PERP_ETH_USDC",
+        "side": "SELL",
+        "order_id": 211,
+        "executed_price": 2000.0,
+        "executed_quantity": 0.1,
+        "fee": 0.01,
+        "fee_asset": "USDC",
+        "is_maker": is_maker,
+        "executed_timestamp": 1677721600000_u64
+    })
+}
+
+#[test]
+fn test_trade_is_maker_accepts_integer_and_boolean() {
+    for (wire, expected) in [
+        (json!(1), true),
+        (json!(0), false),
+        (json!(true), true),
+        (json!(false), false),
+    ] {
+        let trade: Trade = serde_json::from_value(trade_json(wire)).unwrap();
+        assert_eq!(trade.is_maker, expected);
+        assert_eq!(trade.side, Side::Sell);
+    }
+
+    assert!(serde_json::from_value::<Trade>(trade_json(json!(2))).is_err());
+}
+
+#[tokio::test]
+async fn test_get_trade_is_typed() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/trade/5")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!(trade_json(json!(1)))))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let response = client
+        .get_trade(&common::test_credentials(), 5)
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.data.id, 5);
+    assert_eq!(response.data.side, Side::Sell);
+    assert!(response.data.is_maker);
+    assert_eq!(response.data.order_source, None);
+}
@@ -0,0 +1,47 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{error::OrderlyError, rest::OrderlyService};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_get_volume_stats() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/v1/volume/user/daily")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("start_date".into(), "2023-03-02".into()),
+            Matcher::UrlEncoded("end_date".into(), "2023-03-04".into()),
+        ]))
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!([
+            {"date": "2023-03-02", "perp_volume": 1500.5},
+            {"date": "2023-03-03", "perp_volume": null},
+            {"date": "2023-03-04", "perp_volume": 499.5}
+        ])))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let stats = client
+        .get_volume_stats(&common::test_credentials(), 1677721600000, 1677900000000)
+        .await
+        .unwrap();
+
+    assert_eq!(stats.daily.len(), 3);
+    assert_eq!(stats.total_perp_volume, 2000.0);
+    assert_eq!(stats.start_t, 1677721600000);
+}
+
+#[tokio::test]
+async fn test_get_volume_stats_rejects_inverted_window() {
+    let client = OrderlyService::with_base_url("http://localhost", None).unwrap();
+    let result = client
+        .get_volume_stats(&common::test_credentials(), 1677900000000, 1677721600000)
+        .await;
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+}
@@ -7,7 +7,10 @@ use std::sync::Arc;
 use tokio::sync::mpsc;
 use tokio::time::{sleep, Duration};
 // Use the library crate name to import types in integration tests
-use orderly_connector_rs::types::{GetPublicTradesResponse, PublicTradeData, WebSocketTradeData};
+use orderly_connector_rs::types::{
+    GetPublicTradesResponse, OrderbookLevel, PublicTradeData, Side, WebSocketMessage,
+    WebSocketTradeData,
+};
 
 /// Tests the WebSocket connection and basic subscription functionality.
 ///
@@ -38,7 +41,7 @@ async fn test_websocket_connection() {
         });
     });
 
-    let close_handler = Arc::new(move || {
+    let close_handler = Arc::new(move |_reason| {
         connected_clone.store(false, Ordering::SeqCst);
         println!("Connection closed");
     });
@@ -70,7 +73,7 @@ async fn test_websocket_connection() {
     }
 
     // Test should pass if we got here without errors
-    client.stop().await;
+    client.stop().await.expect("Failed to stop client");
 }
 
 /// Tests the open interest WebSocket subscription.
@@ -103,7 +106,7 @@ async fn test_subscribe_open_interest() {
         });
     });
 
-    let close_handler = Arc::new(move || {
+    let close_handler = Arc::new(move |_reason| {
         connected_clone.store(false, Ordering::SeqCst);
         println!("Connection closed");
     });
@@ -146,7 +149,7 @@ async fn test_subscribe_open_interest() {
     }
 
     // Test should pass if we got here without errors
-    client.stop().await;
+    client.stop().await.expect("Failed to stop client");
 }
 
 /// Tests subscribing to trade updates.
@@ -179,7 +182,7 @@ async fn test_subscribe_trades() {
         });
     });
 
-    let close_handler = Arc::new(move || {
+    let close_handler = Arc::new(move |_reason| {
         connected_clone.store(false, Ordering::SeqCst);
         println!("Connection closed");
     });
@@ -222,7 +225,7 @@ async fn test_subscribe_trades() {
     }
 
     // Test should pass if we got here without errors
-    client.stop().await;
+    client.stop().await.expect("Failed to stop client");
 }
 
 #[test]
@@ -247,6 +250,7 @@ fn test_public_trades_response_deserialization() {
     assert!(response.success);
     assert_eq!(response.data.rows.len(), 1);
     assert_eq!(response.data.rows[0].symbol, "BTC_USD");
+    assert_eq!(response.data.rows[0].side, Side::Buy);
 }
 
 #[test]
@@ -260,10 +264,34 @@ fn test_trade_data_deserialization() {
     });
 
     let trade: PublicTradeData = serde_json::from_value(json_data).unwrap();
-    assert_eq!(trade.side, "SELL");
+    assert_eq!(trade.side, Side::Sell);
     assert_eq!(trade.executed_price, 49000.0);
 }
 
+#[test]
+fn test_trade_side_wire_strings() {
+    for (wire, side) in [("BUY", Side::Buy), ("SELL", Side::Sell)] {
+        let trade: PublicTradeData = serde_json::from_value(json!({
+            "symbol": "PERP_ETH_USDC",
+            "side": wire,
+            "executed_price": 2500.0,
+            "executed_quantity": 0.5,
+            "executed_timestamp": 1622548800000u64
+        }))
+        .unwrap();
+        assert_eq!(trade.side, side);
+    }
+
+    let unknown = serde_json::from_value::<PublicTradeData>(json!({
+        "symbol": "PERP_ETH_USDC",
+        "side": "HOLD",
+        "executed_price": 2500.0,
+        "executed_quantity": 0.5,
+        "executed_timestamp": 1622548800000u64
+    }));
+    assert!(unknown.is_err());
+}
+
 #[test]
 fn test_websocket_trade_data_deserialization() {
     let json_data = json!({
@@ -282,5 +310,92 @@ fn test_websocket_trade_data_deserialization() {
     assert_eq!(ws_data.data.symbol, "PERP_ETH_USDC");
     assert_eq!(ws_data.data.price, 2500.0);
     assert_eq!(ws_data.data.size, 1.0);
-    assert_eq!(ws_data.data.side, "BUY");
+    assert_eq!(ws_data.data.side, Side::Buy);
+}
+
+#[test]
+fn test_orderbook_level_serialization_round_trip() {
+    let level = OrderbookLevel {
+        price: 2500.5,
+        quantity: 1.25,
+    };
+
+    let serialized = serde_json::to_value(&level).unwrap();
+    assert_eq!(serialized, json!([2500.5, 1.25]));
+
+    let round_tripped: OrderbookLevel = serde_json::from_value(serialized).unwrap();
+    assert_eq!(round_tripped, level);
+
+    // The map form is still accepted and serializes back to the array form
+    let from_map: OrderbookLevel =
+        serde_json::from_value(json!({"price": 2500.5, "quantity": 1.25})).unwrap();
+    assert_eq!(
+        serde_json::to_value(&from_map).unwrap(),
+        json!([2500.5, 1.25])
+    );
+}
+
+#[test]
+fn test_per_symbol_ticker_message_deserialization() {
+    let frame = json!({
+        "topic": "PERP_ETH_USDC@ticker",
+        "ts": 1618820615000u64,
+        "data": {
+            "symbol": "PERP_ETH_USDC",
+            "open": 1850.5,
+            "close": 1872.25,
+            "high": 1890.0,
+            "low": 1841.75,
+            "volume": 2934.65,
+            "amount": 5493201.12,
+            "count": 4621
+        }
+    });
+
+    let msg: WebSocketMessage = serde_json::from_value(frame).unwrap();
+    match msg {
+        WebSocketMessage::Ticker(ticker) => {
+            assert_eq!(ticker.symbol, "PERP_ETH_USDC");
+            assert_eq!(ticker.close_price, 1872.25);
+            assert_eq!(ticker.open_price, Some(1850.5));
+            assert_eq!(ticker.trade_count, Some(4621));
+        }
+        other => panic!("Expected Ticker, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_liquidation_message_keeps_every_event() {
+    let frame = json!({
+        "topic": "liquidation",
+        "ts": 1618820615000u64,
+        "data": [
+            {
+                "liquidation_id": 1728,
+                "timestamp": 1618820614000u64,
+                "type": "liquidated",
+                "positions_by_perp": [
+                    {"symbol": "PERP_ETH_USDC", "position_qty": 2.5, "liquidator_fee": 0.015}
+                ]
+            },
+            {
+                "liquidation_id": 1729,
+                "timestamp": 1618820614500u64,
+                "type": "claimed",
+                "positions_by_perp": []
+            }
+        ]
+    });
+
+    let msg: WebSocketMessage = serde_json::from_value(frame).unwrap();
+    match msg {
+        WebSocketMessage::Liquidation(events) => {
+            assert_eq!(events.len(), 2);
+            assert_eq!(events[0].liquidation_id, 1728);
+            assert_eq!(events[0].positions_by_perp[0].symbol, "PERP_ETH_USDC");
+            assert_eq!(events[0].positions_by_perp[0].position_qty, 2.5);
+            assert_eq!(events[1].event_type, "claimed");
+        }
+        other => panic!("Expected Liquidation, got {:?}", other),
+    }
 }
@@ -0,0 +1,366 @@
+mod common;
+
+use mockito::{Matcher, Server};
+use orderly_connector_rs::{
+    error::OrderlyError,
+    eth::{abi::ORDERLY_TESTNET_VERIFYING_CONTRACT, signing::EvmSigner},
+    rest::OrderlyService,
+    types::{AssetTxStatus, ChainId, EvmWithdrawParams, GetAssetHistoryResponse, WithdrawRequest},
+};
+use serde_json::json;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_withdraw_evm() {
+    let mut server = Server::new_async().await;
+
+    let nonce_mock = server
+        .mock("GET", "/v1/withdraw_nonce")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            json!({
+                "success": true,
+                "status": "ok",
+                "data": {"withdrawNonce": "42"},
+                "timestamp": 1677721600123_u64
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let withdraw_mock = server
+        .mock("POST", "/v1/withdraw_request")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-account-id", "test_account")
+        .match_header("orderly-timestamp", Matcher::Any)
+        .match_header("orderly-signature", Matcher::Any)
+        .match_body(Matcher::PartialJson(json!({
+            "message": {
+                "brokerId": "woofi_pro",
+                "chainId": 421614,
+                "receiver": "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+                "token": "USDC",
+                "amount": 1_000_000,
+                "withdrawNonce": 42
+            },
+            "userAddress": "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23",
+            "verifyingContract": ORDERLY_TESTNET_VERIFYING_CONTRACT
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({"withdraw_id": 7})))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+    let signer = EvmSigner::from_private_key(
+        "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318",
+    )
+    .unwrap();
+    let receiver = signer.address();
+
+    let params = EvmWithdrawParams {
+        broker_id: "woofi_pro",
+        chain_id: ChainId::ARBITRUM_SEPOLIA,
+        receiver: &receiver,
+        token: "USDC",
+        amount: 1_000_000,
+        verifying_contract: ORDERLY_TESTNET_VERIFYING_CONTRACT,
+    };
+    let response = client.withdraw_evm(&creds, &signer, params).await.unwrap();
+
+    nonce_mock.assert_async().await;
+    withdraw_mock.assert_async().await;
+    assert_eq!(response.data.withdraw_id, 7);
+}
+
+fn withdrawal_row(id: &str, trans_status: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "token": "USDC",
+        "side": "WITHDRAW",
+        "amount": 100.0,
+        "fee": 1.0,
+        "transaction_hash": null,
+        "chain_id": "421614",
+        "chain_name": "Arbitrum Sepolia",
+        "trans_status": trans_status,
+        "created_time": 1677721600000_u64,
+        "updated_time": 1677721660000_u64
+    })
+}
+
+fn withdrawal_history(trans_status: &str) -> String {
+    common::success_body(json!({
+        "rows": [withdrawal_row("7", trans_status)],
+        "meta": {"total": 1, "current_page": 1, "records_per_page": 25}
+    }))
+}
+
+#[test]
+fn test_asset_history_status_deserialization() {
+    let response: GetAssetHistoryResponse =
+        serde_json::from_str(&withdrawal_history("PENDING_REBALANCE")).unwrap();
+    let entry = &response.data.rows[0];
+    assert_eq!(entry.trans_status, Some(AssetTxStatus::PendingRebalance));
+    assert!(!entry.is_terminal());
+
+    let response: GetAssetHistoryResponse =
+        serde_json::from_str(&withdrawal_history("FAILED")).unwrap();
+    assert!(response.data.rows[0].is_terminal());
+
+    let response: GetAssetHistoryResponse =
+        serde_json::from_str(&withdrawal_history("REFUNDED")).unwrap();
+    let status = response.data.rows[0].trans_status.clone().unwrap();
+    assert_eq!(status, AssetTxStatus::Unknown("REFUNDED".to_string()));
+    assert_eq!(serde_json::to_value(&status).unwrap(), json!("REFUNDED"));
+}
+
+#[tokio::test]
+async fn test_wait_for_withdrawal_returns_terminal_entry() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("GET", "/v1/asset/history")
+        .match_query(Matcher::UrlEncoded("side".into(), "WITHDRAW".into()))
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(withdrawal_history("COMPLETED"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let entry = client
+        .wait_for_withdrawal(&common::test_credentials(), 7, Duration::from_secs(30))
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(entry.id, "7");
+    assert_eq!(entry.trans_status, Some(AssetTxStatus::Completed));
+}
+
+#[tokio::test]
+async fn test_wait_for_withdrawal_pages_through_history() {
+    let mut server = Server::new_async().await;
+
+    // A newer withdrawal fills the first page
+    let page1 = server
+        .mock("GET", "/v1/asset/history")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("side".into(), "WITHDRAW".into()),
+            Matcher::UrlEncoded("page".into(), "1".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "rows": [withdrawal_row("8", "PROCESSING")],
+            "meta": {"total": 2, "current_page": 1, "records_per_page": 1}
+        })))
+        .expect(1)
+        .create_async()
+        .await;
+    let page2 = server
+        .mock("GET", "/v1/asset/history")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("side".into(), "WITHDRAW".into()),
+            Matcher::UrlEncoded("page".into(), "2".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "rows": [withdrawal_row("7", "COMPLETED")],
+            "meta": {"total": 2, "current_page": 2, "records_per_page": 1}
+        })))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let entry = client
+        .wait_for_withdrawal(&common::test_credentials(), 7, Duration::from_secs(30))
+        .await
+        .unwrap();
+
+    page1.assert_async().await;
+    page2.assert_async().await;
+    assert_eq!(entry.id, "7");
+    assert_eq!(entry.trans_status, Some(AssetTxStatus::Completed));
+}
+
+#[tokio::test]
+async fn test_wait_for_withdrawal_stops_paging_once_found() {
+    let mut server = Server::new_async().await;
+
+    // Only page 1 without start_t is served; fetching page 2 would fail the call
+    let first_poll = server
+        .mock("GET", "/v1/asset/history")
+        .match_query(Matcher::Regex("^side=WITHDRAW&page=1$".into()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({
+            "rows": [withdrawal_row("7", "PROCESSING")],
+            "meta": {"total": 2, "current_page": 1, "records_per_page": 1}
+        })))
+        .expect(1)
+        .create_async()
+        .await;
+    let later_poll = server
+        .mock("GET", "/v1/asset/history")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("side".into(), "WITHDRAW".into()),
+            Matcher::UrlEncoded("start_t".into(), "1677721600000".into()),
+            Matcher::UrlEncoded("page".into(), "1".into()),
+        ]))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(withdrawal_history("COMPLETED"))
+        .expect(1)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let entry = client
+        .wait_for_withdrawal(&common::test_credentials(), 7, Duration::from_secs(30))
+        .await
+        .unwrap();
+
+    first_poll.assert_async().await;
+    later_poll.assert_async().await;
+    assert_eq!(entry.trans_status, Some(AssetTxStatus::Completed));
+}
+
+#[tokio::test]
+async fn test_wait_for_withdrawal_times_out() {
+    let mut server = Server::new_async().await;
+
+    let _m = server
+        .mock("GET", "/v1/asset/history")
+        .match_query(Matcher::Any)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(withdrawal_history("PROCESSING"))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let result = client
+        .wait_for_withdrawal(&common::test_credentials(), 7, Duration::ZERO)
+        .await;
+    assert!(matches!(result, Err(OrderlyError::Timeout(_))));
+}
+
+fn withdraw_request(
+    chain_id: ChainId,
+    amount: f64,
+    withdraw_address: &'static str,
+) -> WithdrawRequest<'static> {
+    WithdrawRequest {
+        chain_id,
+        token: "USDC",
+        amount,
+        withdraw_address,
+        message: None,
+    }
+}
+
+#[tokio::test]
+async fn test_request_withdrawal_rejects_invalid_requests() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/withdraw_request")
+        .expect(0)
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let creds = common::test_credentials();
+
+    let invalid = [
+        withdraw_request(ChainId::ARBITRUM, 0.0, "0xabc"),
+        withdraw_request(ChainId::ARBITRUM, -5.0, "0xabc"),
+        withdraw_request(ChainId::ARBITRUM, f64::NAN, "0xabc"),
+        withdraw_request(ChainId::ARBITRUM, 10.0, "  "),
+    ];
+    for request in invalid {
+        let result = client.request_withdrawal(&creds, request).await;
+        assert!(
+            matches!(result, Err(OrderlyError::ValidationError(_))),
+            "expected ValidationError, got {:?}",
+            result
+        );
+    }
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_request_withdrawal_submits_valid_request() {
+    let mut server = Server::new_async().await;
+
+    let mock = server
+        .mock("POST", "/v1/withdraw_request")
+        .match_header("orderly-key", "test_key")
+        .match_header("orderly-signature", Matcher::Any)
+        .match_body(Matcher::PartialJson(json!({
+            "chain_id": "42161",
+            "token": "USDC",
+            "amount": 10.5
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({"withdraw_id": 7})))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let response = client
+        .request_withdrawal(
+            &common::test_credentials(),
+            withdraw_request(ChainId::ARBITRUM, 10.5, "0xabc"),
+        )
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.data.withdraw_id, 7);
+}
+
+#[tokio::test]
+async fn test_request_withdrawal_leaves_chain_support_to_server() {
+    let mut server = Server::new_async().await;
+
+    // A chain without a named constant is still sent; the server decides if it is supported
+    let mock = server
+        .mock("POST", "/v1/withdraw_request")
+        .match_body(Matcher::PartialJson(json!({
+            "chain_id": "12345",
+            "amount": 1.0000001
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(common::success_body(json!({"withdraw_id": 8})))
+        .create_async()
+        .await;
+
+    let client = OrderlyService::with_base_url(&server.url(), None).unwrap();
+    let response = client
+        .request_withdrawal(
+            &common::test_credentials(),
+            withdraw_request(ChainId::new(12345), 1.0000001, "0xabc"),
+        )
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.data.withdraw_id, 8);
+}
@@ -0,0 +1,941 @@
+use futures_util::{SinkExt, StreamExt};
+use orderly_connector_rs::error::OrderlyError;
+use orderly_connector_rs::websocket::{
+    ClientHandlers, CloseReason, WebsocketClientConfig, WebsocketHub, WebsocketPrivateClient,
+    WebsocketPublicClient,
+};
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio::time::{sleep, timeout, Duration};
+use tokio_tungstenite::{
+    accept_async,
+    tungstenite::{
+        protocol::{frame::coding::CloseCode, CloseFrame},
+        Message,
+    },
+};
+
+/// Spawns a single-connection WebSocket server that pushes one ticker message and
+/// forwards every text frame it receives to the returned channel.
+async fn spawn_mock_server() -> (String, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (server_tx, server_rx) = mpsc::channel::<String>(8);
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+        ws.send(Message::Text(
+            json!({"topic": "tickers", "ts": 1618820361552u64, "data": []}).to_string(),
+        ))
+        .await
+        .unwrap();
+        while let Some(Ok(msg)) = ws.next().await {
+            if let Message::Text(text) = msg {
+                let _ = server_tx.send(text).await;
+            }
+        }
+    });
+
+    (format!("ws://{}/ws/stream", addr), server_rx)
+}
+
+#[tokio::test]
+async fn test_public_client_against_local_server() {
+    let (url, mut server_rx) = spawn_mock_server().await;
+
+    let (msg_tx, mut msg_rx) = mpsc::channel::<String>(8);
+    let message_handler = Arc::new(move |msg: String| {
+        let _ = msg_tx.try_send(msg);
+    });
+
+    let client = WebsocketPublicClient::connect_with_url(
+        &url,
+        "test_account".to_string(),
+        message_handler,
+        Arc::new(|_| {}),
+    )
+    .await
+    .expect("Failed to connect");
+
+    // Message pushed by the server reaches the callback
+    let received = timeout(Duration::from_secs(5), msg_rx.recv())
+        .await
+        .expect("Timed out waiting for server message")
+        .unwrap();
+    assert!(received.contains("tickers"));
+
+    // Wait for the manager to publish the connection before subscribing
+    timeout(Duration::from_secs(5), async {
+        while !client.is_connected().await {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Client never reported connected");
+
+    client
+        .subscribe_tickers()
+        .await
+        .expect("Failed to subscribe");
+    let subscribe = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for subscribe message")
+        .unwrap();
+    assert!(subscribe.contains("\"topic\":\"tickers\""));
+    assert!(subscribe.contains("\"event\":\"subscribe\""));
+
+    // Each subscription carries a unique id that maps back to its topic
+    let id = serde_json::from_str::<serde_json::Value>(&subscribe).unwrap()["id"]
+        .as_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(
+        client.subscription_topic(&id).await.as_deref(),
+        Some("tickers")
+    );
+
+    client
+        .subscribe_tickers()
+        .await
+        .expect("Failed to resubscribe");
+    let resubscribe = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for second subscribe message")
+        .unwrap();
+    assert!(!resubscribe.contains(&id));
+    assert_eq!(client.active_subscriptions().await.len(), 1);
+    assert!(client.subscription_topic(&id).await.is_none());
+
+    client.stop().await.expect("Failed to stop client");
+    assert!(!client.is_connected().await);
+}
+
+#[tokio::test]
+async fn test_subscribe_orderbooks_sends_one_message_per_symbol() {
+    let (url, mut server_rx) = spawn_mock_server().await;
+
+    let client = WebsocketPublicClient::connect_with_url(
+        &url,
+        "test_account".to_string(),
+        Arc::new(|_msg: String| {}),
+        Arc::new(|_| {}),
+    )
+    .await
+    .expect("Failed to connect");
+
+    timeout(Duration::from_secs(5), async {
+        while !client.is_connected().await {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Client never reported connected");
+
+    let results = client
+        .subscribe_orderbooks(&["PERP_ETH_USDC", "PERP_BTC_USDC"])
+        .await;
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|r| r.is_ok()));
+
+    for symbol in ["PERP_ETH_USDC", "PERP_BTC_USDC"] {
+        let msg = timeout(Duration::from_secs(5), server_rx.recv())
+            .await
+            .expect("Timed out waiting for subscribe message")
+            .unwrap();
+        assert!(msg.contains(&format!("{}@orderbookupdate", symbol)));
+    }
+    assert_eq!(client.active_subscriptions().await.len(), 2);
+
+    client.stop().await.expect("Failed to stop client");
+}
+
+#[tokio::test]
+async fn test_subscribe_tickers_for_reports_per_symbol_results() {
+    let (url, mut server_rx) = spawn_mock_server().await;
+
+    let config = WebsocketClientConfig {
+        base_url: format!("{}/test_account", url),
+        orderly_account_id: "test_account".to_string(),
+        max_subscriptions: Some(2),
+        ..Default::default()
+    };
+    let client = WebsocketPublicClient::connect_with_config(
+        config,
+        Arc::new(|_msg: String| {}),
+        Arc::new(|_| {}),
+    )
+    .await
+    .expect("Failed to connect");
+
+    timeout(Duration::from_secs(5), async {
+        while !client.is_connected().await {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Client never reported connected");
+
+    let results = client
+        .subscribe_tickers_for(&["PERP_ETH_USDC", "PERP_BTC_USDC", "PERP_SOL_USDC"])
+        .await;
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok() && results[1].is_ok());
+    assert!(matches!(results[2], Err(OrderlyError::ValidationError(_))));
+
+    for symbol in ["PERP_ETH_USDC", "PERP_BTC_USDC"] {
+        let msg = timeout(Duration::from_secs(5), server_rx.recv())
+            .await
+            .expect("Timed out waiting for subscribe message")
+            .unwrap();
+        assert!(msg.contains(&format!("{}@ticker", symbol)));
+    }
+    assert_eq!(client.active_subscriptions().await.len(), 2);
+
+    client.stop().await.expect("Failed to stop client");
+}
+
+#[tokio::test]
+async fn test_private_client_calls_on_resync_after_connect() {
+    let (url, mut server_rx) = spawn_mock_server().await;
+
+    let resyncs = Arc::new(AtomicUsize::new(0));
+    let config = WebsocketClientConfig {
+        base_url: url,
+        orderly_key: Some("test_key".to_string()),
+        orderly_secret: Some("11111111111111111111111111111111".to_string()),
+        orderly_account_id: "test_account".to_string(),
+        ..Default::default()
+    };
+    let handlers = ClientHandlers {
+        on_resync: Some(Arc::new({
+            let resyncs = Arc::clone(&resyncs);
+            move || {
+                resyncs.fetch_add(1, Ordering::SeqCst);
+            }
+        })),
+        ..Default::default()
+    };
+    let client = WebsocketPrivateClient::connect_with(
+        config,
+        Arc::new(|_msg: String| {}),
+        Arc::new(|_| {}),
+        handlers,
+    )
+    .await
+    .expect("Failed to connect");
+
+    let auth = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for auth message")
+        .unwrap();
+    assert!(auth.contains("\"event\":\"auth\""));
+
+    timeout(Duration::from_secs(5), async {
+        while resyncs.load(Ordering::SeqCst) == 0 {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("on_resync was never called");
+    assert!(client.is_connected().await);
+    assert_eq!(resyncs.load(Ordering::SeqCst), 1);
+
+    client.stop().await.expect("Failed to stop client");
+}
+
+#[tokio::test]
+async fn test_on_error_reports_connection_failure() {
+    // Reserve a port, then free it so nothing is listening there
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+
+    let (err_tx, mut err_rx) = mpsc::channel::<OrderlyError>(8);
+    let config = WebsocketClientConfig {
+        base_url: format!("ws://{}/ws/stream/test_account", addr),
+        orderly_account_id: "test_account".to_string(),
+        ..Default::default()
+    };
+    let handlers = ClientHandlers {
+        on_error: Some(Arc::new(move |e: OrderlyError| {
+            let _ = err_tx.try_send(e);
+        })),
+        ..Default::default()
+    };
+    let client = WebsocketPublicClient::connect_with(
+        config,
+        Arc::new(|_msg: String| {}),
+        Arc::new(|_| {}),
+        handlers,
+    )
+    .await
+    .expect("Failed to start client");
+
+    let error = timeout(Duration::from_secs(5), err_rx.recv())
+        .await
+        .expect("Timed out waiting for error callback")
+        .unwrap();
+    assert!(matches!(error, OrderlyError::NetworkError(_)));
+    assert!(!client.is_connected().await);
+
+    let _ = client.stop().await;
+}
+
+#[tokio::test]
+async fn test_on_error_reports_private_auth_rejection() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+        while let Some(Ok(msg)) = ws.next().await {
+            if let Message::Text(text) = msg {
+                if text.contains("\"event\":\"auth\"") {
+                    let reply = json!({
+                        "id": "auth",
+                        "event": "auth",
+                        "success": false,
+                        "ts": 1618820361552u64,
+                        "errorMsg": "orderly key error"
+                    });
+                    ws.send(Message::Text(reply.to_string())).await.unwrap();
+                }
+            }
+        }
+    });
+
+    let (err_tx, mut err_rx) = mpsc::channel::<OrderlyError>(8);
+    let config = WebsocketClientConfig {
+        base_url: format!("ws://{}/ws/private/stream", addr),
+        orderly_key: Some("test_key".to_string()),
+        orderly_secret: Some("11111111111111111111111111111111".to_string()),
+        orderly_account_id: "test_account".to_string(),
+        ..Default::default()
+    };
+    let handlers = ClientHandlers {
+        on_error: Some(Arc::new(move |e: OrderlyError| {
+            let _ = err_tx.try_send(e);
+        })),
+        ..Default::default()
+    };
+    let client = WebsocketPrivateClient::connect_with(
+        config,
+        Arc::new(|_msg: String| {}),
+        Arc::new(|_| {}),
+        handlers,
+    )
+    .await
+    .expect("Failed to start client");
+
+    let error = timeout(Duration::from_secs(5), err_rx.recv())
+        .await
+        .expect("Timed out waiting for error callback")
+        .unwrap();
+    match error {
+        OrderlyError::AuthenticationError(message) => assert_eq!(message, "orderly key error"),
+        other => panic!("Expected AuthenticationError, got {:?}", other),
+    }
+
+    client.stop().await.expect("Failed to stop client");
+}
+
+#[tokio::test]
+async fn test_application_ping_is_answered_with_pong() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (server_tx, mut server_rx) = mpsc::channel::<String>(8);
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+        ws.send(Message::Text(
+            json!({"event": "ping", "ts": 1618820361552u64}).to_string(),
+        ))
+        .await
+        .unwrap();
+        while let Some(Ok(msg)) = ws.next().await {
+            if let Message::Text(text) = msg {
+                let _ = server_tx.send(text).await;
+            }
+        }
+    });
+
+    let client = WebsocketPublicClient::connect_with_url(
+        &format!("ws://{}/ws/stream", addr),
+        "test_account".to_string(),
+        Arc::new(|_msg: String| {}),
+        Arc::new(|_| {}),
+    )
+    .await
+    .expect("Failed to connect");
+
+    let pong = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for pong")
+        .unwrap();
+    let pong: serde_json::Value = serde_json::from_str(&pong).unwrap();
+    assert_eq!(pong["event"], "pong");
+    assert_eq!(pong["ts"], 1618820361552u64);
+
+    client.stop().await.expect("Failed to stop client");
+}
+
+#[tokio::test]
+async fn test_on_close_reports_server_close_frame() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+        ws.send(Message::Close(Some(CloseFrame {
+            code: CloseCode::Away,
+            reason: "maintenance".into(),
+        })))
+        .await
+        .unwrap();
+        while let Some(Ok(_)) = ws.next().await {}
+    });
+
+    let (close_tx, mut close_rx) = mpsc::channel::<CloseReason>(8);
+    let client = WebsocketPublicClient::connect_with_url(
+        &format!("ws://{}/ws/stream", addr),
+        "test_account".to_string(),
+        Arc::new(|_msg: String| {}),
+        Arc::new(move |reason: CloseReason| {
+            let _ = close_tx.try_send(reason);
+        }),
+    )
+    .await
+    .expect("Failed to connect");
+
+    let reason = timeout(Duration::from_secs(5), close_rx.recv())
+        .await
+        .expect("Timed out waiting for close callback")
+        .unwrap();
+    assert_eq!(
+        reason,
+        CloseReason::Frame {
+            code: 1001,
+            reason: "maintenance".to_string()
+        }
+    );
+
+    let _ = client.stop().await;
+}
+
+#[tokio::test]
+async fn test_on_close_reports_stopped() {
+    let (url, _server_rx) = spawn_mock_server().await;
+
+    let (close_tx, mut close_rx) = mpsc::channel::<CloseReason>(8);
+    let client = WebsocketPublicClient::connect_with_url(
+        &url,
+        "test_account".to_string(),
+        Arc::new(|_msg: String| {}),
+        Arc::new(move |reason: CloseReason| {
+            let _ = close_tx.try_send(reason);
+        }),
+    )
+    .await
+    .expect("Failed to connect");
+
+    timeout(Duration::from_secs(5), async {
+        while !client.is_connected().await {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Client never reported connected");
+
+    client.stop().await.expect("Failed to stop client");
+    let reason = timeout(Duration::from_secs(5), close_rx.recv())
+        .await
+        .expect("Timed out waiting for close callback")
+        .unwrap();
+    assert_eq!(reason, CloseReason::Stopped);
+}
+
+#[tokio::test]
+async fn test_subscribe_beyond_max_subscriptions_fails_locally() {
+    let (url, mut server_rx) = spawn_mock_server().await;
+
+    let config = WebsocketClientConfig {
+        base_url: format!("{}/test_account", url),
+        orderly_account_id: "test_account".to_string(),
+        max_subscriptions: Some(2),
+        ..Default::default()
+    };
+    let client = WebsocketPublicClient::connect_with_config(
+        config,
+        Arc::new(|_msg: String| {}),
+        Arc::new(|_| {}),
+    )
+    .await
+    .expect("Failed to connect");
+
+    timeout(Duration::from_secs(5), async {
+        while !client.is_connected().await {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Client never reported connected");
+
+    client.subscribe_tickers().await.unwrap();
+    client.subscribe_trades("PERP_ETH_USDC").await.unwrap();
+    for _ in 0..2 {
+        timeout(Duration::from_secs(5), server_rx.recv())
+            .await
+            .expect("Timed out waiting for subscribe message")
+            .unwrap();
+    }
+
+    let result = client.subscribe_trades("PERP_BTC_USDC").await;
+    assert!(matches!(result, Err(OrderlyError::ValidationError(_))));
+    assert_eq!(client.active_subscriptions().await.len(), 2);
+
+    // Re-subscribing to a tracked topic does not count against the limit
+    client.subscribe_tickers().await.unwrap();
+    let msg = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for resubscribe message")
+        .unwrap();
+    assert!(msg.contains("\"topic\":\"tickers\""));
+
+    client.stop().await.expect("Failed to stop client");
+}
+
+#[tokio::test]
+async fn test_spawned_client_shared_across_tasks() {
+    let (url, mut server_rx) = spawn_mock_server().await;
+
+    let config = WebsocketClientConfig {
+        base_url: format!("{}/test_account", url),
+        orderly_account_id: "test_account".to_string(),
+        ..Default::default()
+    };
+    let (client, handle) =
+        WebsocketPublicClient::spawn(config, Arc::new(|_msg: String| {}), Arc::new(|_| {}))
+            .await
+            .expect("Failed to connect");
+
+    timeout(Duration::from_secs(5), async {
+        while !client.is_connected().await {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Client never reported connected");
+
+    let tasks: Vec<_> = ["PERP_ETH_USDC", "PERP_BTC_USDC", "PERP_SOL_USDC"]
+        .into_iter()
+        .map(|symbol| {
+            let client = Arc::clone(&client);
+            tokio::spawn(async move { client.subscribe_trades(symbol).await })
+        })
+        .collect();
+    for task in tasks {
+        task.await.unwrap().expect("Failed to subscribe");
+    }
+    for _ in 0..3 {
+        timeout(Duration::from_secs(5), server_rx.recv())
+            .await
+            .expect("Timed out waiting for subscribe message")
+            .unwrap();
+    }
+    assert_eq!(client.active_subscriptions().await.len(), 3);
+    assert!(!handle.is_finished());
+
+    client.stop().await.expect("Failed to stop client");
+    timeout(Duration::from_secs(5), handle)
+        .await
+        .expect("Handle did not complete after stop")
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_configured_ping_interval_sends_pings() {
+    let (url, mut server_rx) = spawn_mock_server().await;
+
+    let config = WebsocketClientConfig {
+        base_url: format!("{}/test_account", url),
+        orderly_account_id: "test_account".to_string(),
+        ping_interval_secs: Some(1),
+        ..Default::default()
+    };
+    let client = WebsocketPublicClient::connect_with_config(
+        config,
+        Arc::new(|_msg: String| {}),
+        Arc::new(|_| {}),
+    )
+    .await
+    .expect("Failed to connect");
+
+    // With the default 10s interval nothing would arrive within this window
+    let msg = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for ping")
+        .unwrap();
+    let value: serde_json::Value = serde_json::from_str(&msg).unwrap();
+    assert_eq!(value["event"], "ping");
+    assert!(value["ts"].as_u64().is_some());
+
+    client.stop().await.expect("Failed to stop client");
+}
+
+#[tokio::test]
+async fn test_reconnect_count_and_connected_since() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    // Close the first connection right away, then keep the second one open
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+        ws.send(Message::Close(None)).await.unwrap();
+        while let Some(Ok(_)) = ws.next().await {}
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+        while let Some(Ok(_)) = ws.next().await {}
+    });
+
+    let (close_tx, mut close_rx) = mpsc::channel::<CloseReason>(8);
+    let client = WebsocketPublicClient::connect_with_url(
+        &format!("ws://{}/ws/stream", addr),
+        "test_account".to_string(),
+        Arc::new(|_msg: String| {}),
+        Arc::new(move |reason: CloseReason| {
+            let _ = close_tx.try_send(reason);
+        }),
+    )
+    .await
+    .expect("Failed to connect");
+
+    timeout(Duration::from_secs(5), close_rx.recv())
+        .await
+        .expect("Timed out waiting for the first close")
+        .unwrap();
+    assert_eq!(client.reconnect_count(), 0);
+    assert!(client.connected_since().is_none());
+
+    // The manager retries after its fixed delay
+    timeout(Duration::from_secs(15), async {
+        while client.reconnect_count() < 1 {
+            sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("Client never reconnected");
+    let since = client
+        .connected_since()
+        .expect("connected_since should be set while connected");
+    assert!(since.elapsed() < Duration::from_secs(5));
+
+    client.stop().await.expect("Failed to stop client");
+    assert!(client.connected_since().is_none());
+    assert_eq!(client.reconnect_count(), 1);
+}
+
+/// Spawns a single-connection server that answers each subscribe frame with one message
+/// on the subscribed topic and forwards every text frame it receives to the returned channel.
+async fn spawn_topic_echo_server() -> (String, mpsc::Receiver<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (server_tx, server_rx) = mpsc::channel::<String>(8);
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+        while let Some(Ok(msg)) = ws.next().await {
+            if let Message::Text(text) = msg {
+                let frame: serde_json::Value = serde_json::from_str(&text).unwrap();
+                if frame["event"] == "subscribe" {
+                    let reply =
+                        json!({"topic": frame["topic"], "ts": 1618820361552u64, "data": {}});
+                    ws.send(Message::Text(reply.to_string())).await.unwrap();
+                }
+                let _ = server_tx.send(text).await;
+            }
+        }
+    });
+
+    (format!("ws://{}/ws/stream", addr), server_rx)
+}
+
+#[tokio::test]
+async fn test_hub_reference_counts_topics_and_routes_messages() {
+    let (url, mut server_rx) = spawn_topic_echo_server().await;
+
+    let config = WebsocketClientConfig {
+        base_url: format!("{}/test_account", url),
+        orderly_account_id: "test_account".to_string(),
+        ..Default::default()
+    };
+    let hub = WebsocketHub::connect_with_config(config, Arc::new(|_| {}))
+        .await
+        .expect("Failed to connect");
+
+    timeout(Duration::from_secs(5), async {
+        while !hub.is_connected().await {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Hub never reported connected");
+
+    let (trade_tx, mut trade_rx) = mpsc::channel::<String>(8);
+    let trade_handler = Arc::new(move |msg: String| {
+        let _ = trade_tx.try_send(msg);
+    });
+    let ticker_hits = Arc::new(AtomicUsize::new(0));
+    let ticker_handler = {
+        let ticker_hits = Arc::clone(&ticker_hits);
+        Arc::new(move |_msg: String| {
+            ticker_hits.fetch_add(1, Ordering::SeqCst);
+        })
+    };
+
+    let first = hub
+        .subscribe("PERP_ETH_USDC@trade", trade_handler.clone())
+        .await
+        .unwrap();
+    let second = hub
+        .subscribe("PERP_ETH_USDC@trade", trade_handler)
+        .await
+        .unwrap();
+    let ticker = hub.subscribe("tickers", ticker_handler).await.unwrap();
+    assert_eq!(hub.handler_count("PERP_ETH_USDC@trade"), 2);
+
+    // Only the first handler of each topic reaches the server
+    for topic in ["PERP_ETH_USDC@trade", "tickers"] {
+        let msg = timeout(Duration::from_secs(5), server_rx.recv())
+            .await
+            .expect("Timed out waiting for subscribe message")
+            .unwrap();
+        assert!(msg.contains("subscribe") && msg.contains(topic));
+    }
+
+    // The trade reply is delivered to the trade handler only
+    let routed = timeout(Duration::from_secs(5), trade_rx.recv())
+        .await
+        .expect("Timed out waiting for routed message")
+        .unwrap();
+    assert!(routed.contains("PERP_ETH_USDC@trade"));
+    timeout(Duration::from_secs(5), async {
+        while ticker_hits.load(Ordering::SeqCst) == 0 {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Ticker handler never called");
+    assert_eq!(ticker_hits.load(Ordering::SeqCst), 1);
+
+    // Removing one of two handlers keeps the server subscription
+    hub.unsubscribe(first).await.unwrap();
+    assert!(
+        timeout(Duration::from_millis(200), server_rx.recv())
+            .await
+            .is_err(),
+        "unsubscribe sent while a handler remained"
+    );
+
+    hub.unsubscribe(second).await.unwrap();
+    let msg = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for unsubscribe message")
+        .unwrap();
+    assert!(msg.contains("unsubscribe") && msg.contains("PERP_ETH_USDC@trade"));
+    assert_eq!(hub.topics(), vec!["tickers".to_string()]);
+
+    hub.unsubscribe(ticker).await.unwrap();
+    hub.stop().await.expect("Failed to stop hub");
+}
+
+#[tokio::test]
+async fn test_dropping_subscription_handle_unsubscribes() {
+    let (url, mut server_rx) = spawn_mock_server().await;
+
+    let client = WebsocketPublicClient::connect_with_url(
+        &url,
+        "test_account".to_string(),
+        Arc::new(|_msg: String| {}),
+        Arc::new(|_| {}),
+    )
+    .await
+    .expect("Failed to connect");
+
+    timeout(Duration::from_secs(5), async {
+        while !client.is_connected().await {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Client never reported connected");
+
+    let handle = client
+        .subscribe_with_handle("PERP_ETH_USDC@trade")
+        .await
+        .expect("Failed to subscribe");
+    assert_eq!(handle.topic(), "PERP_ETH_USDC@trade");
+    let kept = client
+        .subscribe_with_handle("PERP_BTC_USDC@trade")
+        .await
+        .expect("Failed to subscribe");
+    for _ in 0..2 {
+        let msg = timeout(Duration::from_secs(5), server_rx.recv())
+            .await
+            .expect("Timed out waiting for subscribe message")
+            .unwrap();
+        assert!(msg.contains("\"subscribe\""));
+    }
+
+    drop(handle);
+    let msg = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for unsubscribe message")
+        .unwrap();
+    assert!(msg.contains("unsubscribe") && msg.contains("PERP_ETH_USDC@trade"));
+
+    // A detached handle leaves its subscription in place
+    kept.detach();
+    let active = client.active_subscriptions().await;
+    assert_eq!(active.len(), 1);
+    assert!(active[0].contains("PERP_BTC_USDC@trade"));
+
+    client.stop().await.expect("Failed to stop client");
+}
+
+#[tokio::test]
+async fn test_subscription_handles_share_a_topic() {
+    let (url, mut server_rx) = spawn_mock_server().await;
+
+    let client = WebsocketPublicClient::connect_with_url(
+        &url,
+        "test_account".to_string(),
+        Arc::new(|_msg: String| {}),
+        Arc::new(|_| {}),
+    )
+    .await
+    .expect("Failed to connect");
+
+    timeout(Duration::from_secs(5), async {
+        while !client.is_connected().await {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Client never reported connected");
+
+    let first = client
+        .subscribe_with_handle("PERP_ETH_USDC@trade")
+        .await
+        .expect("Failed to subscribe");
+    let second = client
+        .subscribe_with_handle("PERP_ETH_USDC@trade")
+        .await
+        .expect("Failed to subscribe");
+    let msg = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for subscribe message")
+        .unwrap();
+    assert!(msg.contains("\"subscribe\"") && msg.contains("PERP_ETH_USDC@trade"));
+
+    // Dropping one of two handles keeps the server subscription
+    drop(first);
+    assert!(
+        timeout(Duration::from_millis(200), server_rx.recv())
+            .await
+            .is_err(),
+        "second subscribe or early unsubscribe sent"
+    );
+    assert_eq!(client.active_subscriptions().await.len(), 1);
+
+    second.unsubscribe().await.expect("Failed to unsubscribe");
+    let msg = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for unsubscribe message")
+        .unwrap();
+    assert!(msg.contains("unsubscribe") && msg.contains("PERP_ETH_USDC@trade"));
+    assert!(client.active_subscriptions().await.is_empty());
+
+    client.stop().await.expect("Failed to stop client");
+}
+
+#[tokio::test]
+async fn test_unsubscribe_raw_releases_subscription_handles() {
+    let (url, mut server_rx) = spawn_mock_server().await;
+
+    let client = WebsocketPublicClient::connect_with_url(
+        &url,
+        "test_account".to_string(),
+        Arc::new(|_msg: String| {}),
+        Arc::new(|_| {}),
+    )
+    .await
+    .expect("Failed to connect");
+
+    timeout(Duration::from_secs(5), async {
+        while !client.is_connected().await {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Client never reported connected");
+
+    let stale = client
+        .subscribe_with_handle("PERP_ETH_USDC@trade")
+        .await
+        .expect("Failed to subscribe");
+    let msg = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for message")
+        .unwrap();
+    assert!(msg.contains("\"subscribe\""));
+
+    client
+        .unsubscribe_raw("PERP_ETH_USDC@trade")
+        .await
+        .expect("Failed to unsubscribe");
+    let msg = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for message")
+        .unwrap();
+    assert!(msg.contains("unsubscribe"));
+
+    // A new handle subscribes again instead of relying on the released one
+    let fresh = client
+        .subscribe_with_handle("PERP_ETH_USDC@trade")
+        .await
+        .expect("Failed to subscribe");
+    let msg = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for message")
+        .unwrap();
+    assert!(msg.contains("\"subscribe\"") && msg.contains("PERP_ETH_USDC@trade"));
+
+    // Dropping the released handle leaves the new subscription alone
+    drop(stale);
+    assert!(
+        timeout(Duration::from_millis(200), server_rx.recv())
+            .await
+            .is_err(),
+        "released handle sent an unsubscribe"
+    );
+    assert_eq!(client.active_subscriptions().await.len(), 1);
+
+    fresh.unsubscribe().await.expect("Failed to unsubscribe");
+    let msg = timeout(Duration::from_secs(5), server_rx.recv())
+        .await
+        .expect("Timed out waiting for message")
+        .unwrap();
+    assert!(msg.contains("unsubscribe") && msg.contains("PERP_ETH_USDC@trade"));
+
+    client.stop().await.expect("Failed to stop client");
+}
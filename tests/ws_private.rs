@@ -2,7 +2,9 @@
 
 mod common;
 
+use orderly_connector_rs::types::{OrderStatus, Side, WebSocketMessage};
 use orderly_connector_rs::websocket::WebsocketPrivateClient;
+use serde_json::json;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::sync::mpsc;
@@ -35,7 +37,7 @@ async fn test_private_ws_connect_subscribe_unsubscribe() {
         }
     });
 
-    let close_handler = Arc::new(move || {
+    let close_handler = Arc::new(move |_reason| {
         closed_clone.store(true, Ordering::SeqCst);
         println!("Private WS Connection Closed (test handler).");
     });
@@ -105,7 +107,7 @@ async fn test_private_ws_connect_subscribe_unsubscribe() {
     tokio::time::sleep(Duration::from_secs(1)).await;
 
     // Stop
-    client.stop().await;
+    client.stop().await.expect("Failed to stop client");
     println!("Private WS Client stopped.");
 
     // Check if close handler was called (allow some time)
@@ -113,3 +115,158 @@ async fn test_private_ws_connect_subscribe_unsubscribe() {
     // assert!(closed.load(Ordering::SeqCst), "Close handler was not called after stop");
     // See note in ws_public test regarding this assertion.
 }
+
+#[test]
+fn test_execution_report_deserialization() {
+    let json_data = json!({
+        "topic": "executionreport",
+        "ts": 1657515256140u64,
+        "data": {
+            "symbol": "PERP_BTC_USDC",
+            "clientOrderId": "",
+            "orderId": 1,
+            "type": "LIMIT",
+            "side": "BUY",
+            "quantity": 0.1,
+            "price": 21000.0,
+            "tradeId": 0,
+            "executedPrice": 0.0,
+            "executedQuantity": 0.0,
+            "fee": 0.0,
+            "feeAsset": "USDC",
+            "totalExecutedQuantity": 0.0,
+            "avgPrice": 0.0,
+            "status": "NEW",
+            "reason": "",
+            "orderTag": "default",
+            "totalFee": 0.0,
+            "visible": 0.1,
+            "timestamp": 1657515256140u64,
+            "reduceOnly": false,
+            "maker": false
+        }
+    });
+
+    let msg: WebSocketMessage = serde_json::from_value(json_data).unwrap();
+    match msg {
+        WebSocketMessage::ExecutionReport(report) => {
+            assert_eq!(report.symbol, "PERP_BTC_USDC");
+            assert_eq!(report.order_id, 1);
+            assert_eq!(report.side, Side::Buy);
+            assert_eq!(report.status, OrderStatus::New);
+            assert_eq!(report.price, Some(21000.0));
+        }
+        other => panic!("Expected ExecutionReport, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_execution_report_replaced_status() {
+    let json_data = json!({
+        "topic": "executionreport",
+        "ts": 1657515256140u64,
+        "data": {
+            "symbol": "PERP_ETH_USDC",
+            "clientOrderId": "edit-1",
+            "orderId": 42,
+            "type": "LIMIT",
+            "side": "SELL",
+            "quantity": 0.5,
+            "price": 2050.0,
+            "tradeId": 0,
+            "executedPrice": 0.0,
+            "executedQuantity": 0.0,
+            "fee": 0.0,
+            "feeAsset": "USDC",
+            "totalExecutedQuantity": 0.0,
+            "avgPrice": 0.0,
+            "status": "REPLACED",
+            "reason": "",
+            "orderTag": "default",
+            "totalFee": 0.0,
+            "visible": 0.5,
+            "timestamp": 1657515256140u64,
+            "reduceOnly": false,
+            "maker": false
+        }
+    });
+
+    let msg: WebSocketMessage = serde_json::from_value(json_data).unwrap();
+    match msg {
+        WebSocketMessage::ExecutionReport(report) => {
+            assert_eq!(report.order_id, 42);
+            assert_eq!(report.status, OrderStatus::Replaced);
+        }
+        other => panic!("Expected ExecutionReport, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_unknown_order_status_deserializes() {
+    let status: OrderStatus = serde_json::from_value(json!("PENDING_CANCEL")).unwrap();
+    assert_eq!(status, OrderStatus::Unknown);
+    let status: OrderStatus = serde_json::from_value(json!("FILLED")).unwrap();
+    assert_eq!(status, OrderStatus::Filled);
+}
+
+#[test]
+fn test_position_update_deserialization() {
+    let json_data = json!({
+        "topic": "position",
+        "ts": 1657515256140u64,
+        "data": {
+            "positions": [
+                {
+                    "symbol": "PERP_ETH_USDC",
+                    "positionQty": 1.5,
+                    "costPosition": 3000.0,
+                    "averageOpenPrice": 2000.0,
+                    "markPrice": 2010.0,
+                    "IMRwithOrders": 0.1,
+                    "pnl24H": 15.0
+                }
+            ]
+        }
+    });
+
+    let msg: WebSocketMessage = serde_json::from_value(json_data).unwrap();
+    match msg {
+        WebSocketMessage::PositionUpdate(update) => {
+            assert_eq!(update.positions.len(), 1);
+            assert_eq!(update.positions[0].symbol, "PERP_ETH_USDC");
+            assert_eq!(update.positions[0].position_qty, 1.5);
+            assert_eq!(update.positions[0].imr_with_orders, Some(0.1));
+            assert_eq!(update.positions[0].pnl_24_h, Some(15.0));
+        }
+        other => panic!("Expected PositionUpdate, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_balance_update_deserialization() {
+    let json_data = json!({
+        "topic": "balance",
+        "ts": 1657515256140u64,
+        "data": {
+            "balances": {
+                "USDC": {
+                    "holding": 1000.0,
+                    "frozen": 50.0,
+                    "pendingShortQty": 0.0,
+                    "version": 3
+                }
+            }
+        }
+    });
+
+    let msg: WebSocketMessage = serde_json::from_value(json_data).unwrap();
+    match msg {
+        WebSocketMessage::BalanceUpdate(update) => {
+            let usdc = update.balances.get("USDC").expect("missing USDC balance");
+            assert_eq!(usdc.holding, 1000.0);
+            assert_eq!(usdc.frozen, Some(50.0));
+            assert_eq!(usdc.version, Some(3));
+        }
+        other => panic!("Expected BalanceUpdate, got {:?}", other),
+    }
+}
@@ -27,7 +27,7 @@ async fn test_public_ws_connect_subscribe_unsubscribe() {
         let _ = tx.try_send(msg);
     });
 
-    let close_handler = Arc::new(move || {
+    let close_handler = Arc::new(move |_reason| {
         closed_clone.store(true, Ordering::SeqCst);
         println!("Public WS Connection Closed (test handler).");
     });
@@ -71,7 +71,7 @@ async fn test_public_ws_connect_subscribe_unsubscribe() {
     tokio::time::sleep(Duration::from_secs(1)).await;
 
     // Stop
-    client.stop().await;
+    client.stop().await.expect("Failed to stop client");
     println!("Public WS Client stopped.");
 
     // Check if close handler was called (allow some time)
@@ -0,0 +1,126 @@
+use futures_util::{SinkExt, StreamExt};
+use orderly_connector_rs::websocket::{CloseReason, WebsocketClientConfig, WebsocketPublicClient};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio::time::{sleep, timeout, Duration};
+use tokio_tungstenite::{accept_async, tungstenite::Message};
+
+/// Spawns a single-connection WebSocket server that greets the client with one text
+/// frame and reports whether it received a Close frame.
+async fn spawn_server() -> (String, mpsc::Receiver<bool>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (closed_tx, closed_rx) = mpsc::channel::<bool>(1);
+
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = accept_async(stream).await.unwrap();
+        ws.send(Message::Text("{\"event\":\"hello\"}".to_string()))
+            .await
+            .unwrap();
+        let mut got_close = false;
+        while let Some(Ok(msg)) = ws.next().await {
+            if let Message::Close(_) = msg {
+                got_close = true;
+                break;
+            }
+        }
+        let _ = closed_tx.send(got_close).await;
+    });
+
+    (format!("ws://{}/ws/stream/test_account", addr), closed_rx)
+}
+
+#[tokio::test]
+async fn test_stop_closes_connection_without_waiting_out_grace_period() {
+    let (url, mut closed_rx) = spawn_server().await;
+
+    let (msg_tx, mut msg_rx) = mpsc::channel::<String>(8);
+    let config = WebsocketClientConfig {
+        base_url: url,
+        orderly_account_id: "test_account".to_string(),
+        ..Default::default()
+    };
+    let client = WebsocketPublicClient::connect_with_config(
+        config,
+        Arc::new(move |msg: String| {
+            let _ = msg_tx.try_send(msg);
+        }),
+        Arc::new(|_| {}),
+    )
+    .await
+    .expect("Failed to connect");
+
+    timeout(Duration::from_secs(5), msg_rx.recv())
+        .await
+        .expect("Timed out waiting for server message");
+    // Give the manager a moment to publish the connection's sender
+    sleep(Duration::from_millis(100)).await;
+
+    // The grace period is 5 seconds; a working shutdown finishes well before it
+    timeout(Duration::from_secs(2), client.stop())
+        .await
+        .expect("stop() waited out its grace period")
+        .expect("Failed to stop client");
+    let got_close = timeout(Duration::from_secs(5), closed_rx.recv())
+        .await
+        .expect("Server never saw the connection end")
+        .unwrap();
+    assert!(got_close);
+}
+
+#[tokio::test]
+async fn test_stop_during_reconnect_backoff_cancels_reconnect() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let accepted = Arc::new(AtomicUsize::new(0));
+    tokio::spawn({
+        let accepted = Arc::clone(&accepted);
+        async move {
+            // Close every connection straight away so the client falls into backoff
+            while let Ok((stream, _)) = listener.accept().await {
+                accepted.fetch_add(1, Ordering::SeqCst);
+                let mut ws = accept_async(stream).await.unwrap();
+                let _ = ws.close(None).await;
+            }
+        }
+    });
+
+    let reasons = Arc::new(Mutex::new(Vec::new()));
+    let config = WebsocketClientConfig {
+        base_url: format!("ws://{}/ws/stream/test_account", addr),
+        orderly_account_id: "test_account".to_string(),
+        ..Default::default()
+    };
+    let client = WebsocketPublicClient::connect_with_config(config, Arc::new(|_: String| {}), {
+        let reasons = Arc::clone(&reasons);
+        Arc::new(move |reason: CloseReason| reasons.lock().unwrap().push(reason))
+    })
+    .await
+    .expect("Failed to connect");
+
+    timeout(Duration::from_secs(5), async {
+        while reasons.lock().unwrap().is_empty() {
+            sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("Connection never closed");
+
+    // No live connection: stop still succeeds, and a second call is a no-op
+    client.stop().await.expect("stop() failed during backoff");
+    client.stop().await.expect("second stop() failed");
+    let stopped = reasons
+        .lock()
+        .unwrap()
+        .iter()
+        .filter(|r| **r == CloseReason::Stopped)
+        .count();
+    assert_eq!(stopped, 1);
+
+    // The retry delay is 5 seconds; no reconnect may happen after stop()
+    sleep(Duration::from_secs(6)).await;
+    assert_eq!(accepted.load(Ordering::SeqCst), 1);
+}